@@ -0,0 +1,98 @@
+//! Generates the in-process solution registry for the main binary.
+//!
+//! Every solution in `src/bin/{year}-{day}.rs` is included as a module of the main binary, and its
+//! `SOLUTION` constant (emitted by the `solution!` macro) is collected into `SOLUTIONS`.
+//!
+//! Also generates the tests of the examples of every solution, which `solution!` includes.
+use std::{collections::BTreeSet, env, fs, path::Path};
+
+// NOTE: the tests of the module are compiled out of the build script, but not their imports.
+#[path = "src/template/example_tests.rs"]
+#[allow(unused_imports)]
+mod example_tests;
+
+fn main() {
+    println!("cargo:rerun-if-changed=src/bin");
+    println!("cargo:rerun-if-changed=build.rs");
+
+    let manifest_dir = env::var("CARGO_MANIFEST_DIR").unwrap();
+    let bin_dir = Path::new(&manifest_dir).join("src").join("bin");
+
+    let mut days: Vec<(String, String, String, String)> = fs::read_dir(&bin_dir)
+        .map(|entries| {
+            entries
+                .filter_map(Result::ok)
+                .map(|entry| entry.path())
+                .filter(|path| path.extension().is_some_and(|ext| ext == "rs"))
+                .filter_map(|path| {
+                    let stem = path.file_stem()?.to_str()?.to_string();
+                    // only `{year}-{day}.rs` files are solutions, e.g. `2024-01.rs`.
+                    let (year, day) = stem.split_once('-')?;
+                    let is_number = |s: &str, len: usize| {
+                        s.len() == len && s.chars().all(|c| c.is_ascii_digit())
+                    };
+                    if is_number(year, 4) && is_number(day, 2) {
+                        Some((
+                            format!("y{year}_day{day}"),
+                            path.to_str()?.to_string(),
+                            year.to_string(),
+                            day.to_string(),
+                        ))
+                    } else {
+                        None
+                    }
+                })
+                .collect()
+        })
+        .unwrap_or_default();
+
+    days.sort();
+
+    write_example_tests(Path::new(&manifest_dir), &days);
+
+    // NOTE: every solution declares a global allocator with `dhat-heap`, so they cannot share a binary.
+    if env::var_os("CARGO_FEATURE_DHAT_HEAP").is_some() {
+        days.clear();
+    }
+
+    let mut out = String::new();
+
+    for (module, path, ..) in &days {
+        out.push_str(&format!("#[path = {path:?}]\nmod {module};\n"));
+    }
+
+    out.push_str("\npub const SOLUTIONS: &[advent_of_code::template::registry::Solution] = &[\n");
+    for (module, ..) in &days {
+        out.push_str(&format!("    {module}::SOLUTION,\n"));
+    }
+    out.push_str("];\n");
+
+    let out_path = Path::new(&env::var("OUT_DIR").unwrap()).join("solutions.rs");
+    fs::write(out_path, out).unwrap();
+}
+
+/// Write the example tests of every day to `example_tests/{year}-{day number}.rs`.
+/// Days without examples get an empty file, as `solution!` includes one for every day.
+fn write_example_tests(manifest_dir: &Path, days: &[(String, String, String, String)]) {
+    let out_dir = Path::new(&env::var("OUT_DIR").unwrap()).join("example_tests");
+    fs::create_dir_all(&out_dir).unwrap();
+
+    let years: BTreeSet<&String> = days.iter().map(|(_, _, year, _)| year).collect();
+    for year in years {
+        let examples_dir = manifest_dir.join("data").join(year).join("examples");
+        if examples_dir.is_dir() {
+            println!("cargo:rerun-if-changed={}", examples_dir.display());
+        }
+    }
+
+    for (_, _, year, day) in days {
+        let examples_dir = manifest_dir.join("data").join(year).join("examples");
+        let examples = example_tests::discover(&examples_dir, day);
+        let day_number = day.trim_start_matches('0');
+        fs::write(
+            out_dir.join(format!("{year}-{day_number}.rs")),
+            example_tests::generate(&examples),
+        )
+        .unwrap();
+    }
+}
@@ -1,6 +1,6 @@
 use std::collections::HashMap;
 
-advent_of_code::solution!(1);
+advent_of_code::solution!(2024, 1);
 
 fn parse_input_and_sort(input: &str) -> (Vec<u32>, Vec<u32>) {
     let mut xs = Vec::new();
@@ -71,13 +71,13 @@ mod tests {
 
     #[test]
     fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
+        let result = part_one(&advent_of_code::template::read_file("examples", YEAR, DAY));
         assert_eq!(result, Some(11));
     }
 
     #[test]
     fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
+        let result = part_two(&advent_of_code::template::read_file("examples", YEAR, DAY));
         assert_eq!(result, Some(31));
     }
 }
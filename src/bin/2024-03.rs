@@ -1,6 +1,6 @@
 use regex::Regex;
 
-advent_of_code::solution!(3);
+advent_of_code::solution!(2024, 3);
 
 /// Regex solution
 ///
@@ -9,7 +9,6 @@ advent_of_code::solution!(3);
 /// ------
 /// Part 1: 189600467 (309.7µs @ 476 samples)
 /// Part 2: 107069718 (334.3µs @ 2065 samples)
-
 struct _Mul {
     _a: u32,
     _b: u32,
@@ -125,11 +124,7 @@ fn collapse_part2(instructions: &[Instruction]) -> u32 {
     let mut enabled = true;
     for inst in instructions {
         match inst {
-            Instruction::Mull(a, b) => {
-                if enabled {
-                    sum += a * b;
-                }
-            }
+            Instruction::Mull(a, b) if enabled => sum += a * b,
             Instruction::Do => enabled = true,
             Instruction::Dont => enabled = false,
             _ => {}
@@ -156,14 +151,14 @@ mod tests {
 
     #[test]
     fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
+        let result = part_one(&advent_of_code::template::read_file("examples", YEAR, DAY));
         assert_eq!(result, Some(161));
     }
 
     #[test]
     fn test_part_two() {
         let result = part_two(&advent_of_code::template::read_file_part(
-            "examples", DAY, 2,
+            "examples", YEAR, DAY, 2,
         ));
         assert_eq!(result, Some(48));
     }
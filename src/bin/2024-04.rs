@@ -0,0 +1,73 @@
+advent_of_code::solution!(2024, 4);
+
+use advent_of_code::grid::{Direction, Grid, Pos};
+
+fn parse_input(input: &str) -> Grid<char> {
+    Grid::parse(input, |c| c)
+}
+
+fn is_xmas(grid: &Grid<char>, x_index: Pos, direction: Direction) -> bool {
+    // The ray stops at the edge of the grid, so words that do not fit are never matched
+    grid.ray(x_index, direction)
+        .map(|pos| grid[pos])
+        .take(3)
+        .eq("MAS".chars())
+}
+
+fn count_xmases_beginning_at(grid: &Grid<char>, x_index: Pos) -> u32 {
+    Direction::ALL
+        .into_iter()
+        .filter(|&dir| is_xmas(grid, x_index, dir))
+        .count() as u32
+}
+
+/// Brute force search :(
+pub fn part_one(input: &str) -> Option<u32> {
+    let grid = parse_input(input);
+    Some(
+        grid.find_all(|&c| c == 'X')
+            .fold(0, |acc, idx| acc + count_xmases_beginning_at(&grid, idx)),
+    )
+}
+
+fn is_crossmas(grid: &Grid<char>, a_index: Pos) -> bool {
+    // Shortcircuit if any corner is out of bounds
+    let corner = |dir| grid.step(a_index, dir).map(|pos| grid[pos]);
+    let (Some(nw_char), Some(ne_char), Some(sw_char), Some(se_char)) = (
+        corner(Direction::NW),
+        corner(Direction::NE),
+        corner(Direction::SW),
+        corner(Direction::SE),
+    ) else {
+        return false;
+    };
+    (nw_char == 'M' && ne_char == 'M' && se_char == 'S' && sw_char == 'S')
+        || (nw_char == 'S' && ne_char == 'M' && se_char == 'M' && sw_char == 'S')
+        || (nw_char == 'S' && ne_char == 'S' && se_char == 'M' && sw_char == 'M')
+        || (nw_char == 'M' && ne_char == 'S' && se_char == 'S' && sw_char == 'M')
+}
+
+pub fn part_two(input: &str) -> Option<u32> {
+    let grid = parse_input(input);
+    Some(
+        grid.find_all(|&c| c == 'A')
+            .fold(0, |acc, idx| acc + is_crossmas(&grid, idx) as u32),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        let result = part_one(&advent_of_code::template::read_file("examples", YEAR, DAY));
+        assert_eq!(result, Some(18));
+    }
+
+    #[test]
+    fn test_part_two() {
+        let result = part_two(&advent_of_code::template::read_file("examples", YEAR, DAY));
+        assert_eq!(result, Some(9));
+    }
+}
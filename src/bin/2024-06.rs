@@ -0,0 +1,206 @@
+advent_of_code::solution!(2024, 6);
+
+use advent_of_code::error::AocError;
+use advent_of_code::grid::{Direction, Grid, Pos};
+use advent_of_code::parse::ParseError;
+
+#[derive(Clone, PartialEq)]
+enum Square {
+    NotObstacle(u32), // Number of times this square has been visited
+    Obstacle,
+}
+
+impl Square {
+    fn has_been_visited(&self) -> bool {
+        match self {
+            Square::Obstacle => false,
+            Square::NotObstacle(visited) => *visited > 0,
+        }
+    }
+}
+
+#[derive(Clone)]
+struct Guard {
+    direction: Direction,
+    index: Pos,
+}
+
+#[derive(Clone, PartialEq, Eq, Debug)]
+enum TerminationCondition {
+    OutOfBounds,
+    HitLoop,
+}
+
+#[derive(Clone)]
+struct LabMap {
+    board: Grid<Square>,
+    guard: Guard,
+    terminated: Option<TerminationCondition>,
+}
+
+impl LabMap {
+    // Increment the counter for the square the guard is currently on.
+    fn track_direction(&mut self) {
+        if let Square::NotObstacle(visited) = &mut self.board[self.guard.index] {
+            // By the pigeonhole principle, if we've visited a square more than 4 times, at least 2
+            // of those times must have been in the same direction. This means we've hit a loop.
+            //
+            // In fact, if the square being tracked is next to an obstacle, it suffices to check
+            // *visited > 3 because it's impossible to visit a square next to an obstacle in all
+            // four directions. So, for part 2 we could optimise this slightly, but it's not
+            // generalisable and leads to negligible speedup, so I'll leave it.
+            if *visited > 4 {
+                self.terminated = Some(TerminationCondition::HitLoop);
+            } else {
+                *visited += 1;
+            }
+        } else {
+            panic!("Guard is on an obstacle");
+        }
+    }
+
+    /// Take a step in the direction the guard is facing.
+    ///
+    /// If the step would cause the guard to move out of bounds, set self.terminated to
+    /// Some(OutOfBounds). If the step would cause the guard to enter a loop, set it to
+    /// Some(HitLoop).
+    ///
+    /// The `full` parameter determines whether tracking is enabled for every square the guard
+    /// visits, or just the squares immediately before obstacles. For part 1 of this problem, we
+    /// need to track every square; however, for part 2 (to determine loops), we only need to track
+    /// the squares immediately before obstacles to see whether they are revisited multiple times.
+    /// This results in a ~ 2.5x speedup.
+    ///
+    /// I didn't come up with this idea myself unfortunately, I saw it on Reddit.
+    fn step1(&mut self, full: bool) {
+        // Add current square and direction to the visited. Also check for loops
+        if full {
+            self.track_direction();
+            if self.terminated.is_some() {
+                return;
+            }
+        }
+
+        let next_index = self.board.step(self.guard.index, self.guard.direction);
+        match next_index {
+            // Out of bounds
+            None => {
+                self.terminated = Some(TerminationCondition::OutOfBounds);
+            }
+            // In bounds, so move the guard appropriately
+            Some(index) => match self.board[index] {
+                Square::Obstacle => {
+                    if !full {
+                        self.track_direction();
+                    }
+                    self.guard.direction = self.guard.direction.turn_right();
+                }
+                _ => {
+                    self.guard.index = index;
+                }
+            },
+        }
+    }
+
+    fn run(&mut self, full: bool) {
+        while self.terminated.is_none() {
+            self.step1(full);
+        }
+    }
+
+    fn count_visited(self) -> usize {
+        self.board
+            .iter()
+            .filter(|(_, x)| x.has_been_visited())
+            .count()
+    }
+}
+
+impl TryFrom<&str> for LabMap {
+    type Error = AocError;
+
+    fn try_from(input: &str) -> Result<Self, Self::Error> {
+        let chars = Grid::try_parse(input, |c| "#.^v<>".contains(c).then_some(c))?;
+        let mut guards = chars.find_all(|&c| Direction::from_arrow(c).is_some());
+        let index = guards
+            .next()
+            .ok_or_else(|| AocError::invalid("No guard in input"))?;
+        if let Some(other) = guards.next() {
+            return Err(ParseError::at_pos(other, "Multiple guards in input").into());
+        }
+
+        // We don't need to set the visited state for the initial square, because step1() will do
+        // it for us on the first iteration
+        let board = chars.map(|&c| match c {
+            '#' => Square::Obstacle,
+            _ => Square::NotObstacle(0),
+        });
+
+        Ok(LabMap {
+            board,
+            guard: Guard {
+                direction: Direction::from_arrow(chars[index]).unwrap(),
+                index,
+            },
+            terminated: None,
+        })
+    }
+}
+
+pub fn part_one(input: &str) -> Result<u32, AocError> {
+    let mut map = LabMap::try_from(input)?;
+    map.run(true);
+    Ok(map.count_visited() as u32)
+}
+
+pub fn part_two(input: &str) -> Result<u32, AocError> {
+    let map = LabMap::try_from(input)?;
+
+    // Run the map once to get the trajectory. We use this to determine the
+    // set of possible locations where adding an obstacle could affect the
+    // trajectory.
+    let mut final_map = map.clone();
+    final_map.run(true);
+    let possible_obstacles = final_map
+        .board
+        .find_all(Square::has_been_visited)
+        .collect::<Vec<_>>();
+
+    // Then we iterate through that set, instead of the entire map
+    let mut n_loops = 0;
+    for index in possible_obstacles {
+        let mut new_map = map.clone();
+        new_map.board[index] = Square::Obstacle;
+        new_map.run(false);
+        if new_map.terminated == Some(TerminationCondition::HitLoop) {
+            n_loops += 1;
+        }
+    }
+    Ok(n_loops)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        let result = part_one(&advent_of_code::template::read_file("examples", YEAR, DAY));
+        assert_eq!(result, Ok(41));
+    }
+
+    #[test]
+    fn test_part_two() {
+        let result = part_two(&advent_of_code::template::read_file("examples", YEAR, DAY));
+        assert_eq!(result, Ok(6));
+    }
+
+    #[test]
+    fn test_invalid_input() {
+        assert_eq!(part_one("..#\n.^x").unwrap_err().location(), Some((2, 3)));
+        assert_eq!(
+            part_one("..#\n...").unwrap_err(),
+            AocError::invalid("No guard in input")
+        );
+    }
+}
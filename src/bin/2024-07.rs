@@ -1,9 +1,12 @@
-advent_of_code::solution!(7);
+advent_of_code::solution!(2024, 7);
 
+use advent_of_code::error::AocError;
+use advent_of_code::math::concat;
+use advent_of_code::parse::{lines_of, parse_all, unsigned, IResult, ParseError};
 use itertools::Itertools;
 use nom::{
-    bytes::complete::tag, character::complete::digit1, character::complete::multispace0,
-    combinator::map_res, multi::many1, sequence::terminated, IResult,
+    bytes::complete::tag, character::complete::space1, multi::separated_list1,
+    sequence::separated_pair,
 };
 use std::collections::HashMap;
 
@@ -58,10 +61,7 @@ impl Countdown {
                 let result = match ops[ops.len() - 1] {
                     Op::Add => a + next,
                     Op::Mul => a * next,
-                    Op::Concat => {
-                        let n_digits_next = next.to_string().len();
-                        a * 10u64.pow(n_digits_next as u32) + next
-                    }
+                    Op::Concat => concat(a, next),
                 };
                 if result > self.target {
                     None
@@ -94,54 +94,41 @@ impl Countdown {
     }
 }
 
-impl From<&str> for Countdown {
-    fn from(input_line: &str) -> Self {
-        fn parse_number(input: &str) -> IResult<&str, u64> {
-            map_res(terminated(digit1, multispace0), |s: &str| s.parse::<u64>())(input)
-        }
-        fn parse(input: &str) -> IResult<&str, Countdown> {
-            let (input, target) = parse_number(input)?;
-            let (input, _) = tag(": ")(input)?;
-            let (input, numbers) = many1(parse_number)(input)?;
-            assert!(input.is_empty());
-            Ok((
-                input,
-                Countdown {
-                    target,
-                    numbers,
-                    cache: HashMap::new(),
-                },
-            ))
-        }
-        match parse(input_line) {
-            Ok((_, c)) => c,
-            Err(e) => panic!("Failed to parse: {:?}", e),
-        }
-    }
+fn parse_countdown(input: &str) -> IResult<'_, Countdown> {
+    let (input, (target, numbers)) =
+        separated_pair(unsigned, tag(": "), separated_list1(space1, unsigned))(input)?;
+    Ok((
+        input,
+        Countdown {
+            target,
+            numbers,
+            cache: HashMap::new(),
+        },
+    ))
+}
+
+fn parse_input(input: &str) -> Result<Vec<Countdown>, ParseError> {
+    parse_all(input, lines_of(parse_countdown))
 }
 
-pub fn part_one(input: &str) -> Option<u64> {
-    Some(
-        input
-            .lines()
-            .map(|l| Countdown::from(l).solve(vec![Op::Add, Op::Mul]))
-            .fold(0, |acc, res| match res {
-                Some((_, target)) => acc + target,
-                None => acc,
-            }),
-    )
+pub fn part_one(input: &str) -> Result<u64, AocError> {
+    Ok(parse_input(input)?
+        .into_iter()
+        .map(|c| c.solve(vec![Op::Add, Op::Mul]))
+        .fold(0, |acc, res| match res {
+            Some((_, target)) => acc + target,
+            None => acc,
+        }))
 }
 
-pub fn part_two(input: &str) -> Option<u64> {
-    Some(
-        input
-            .lines()
-            .map(|l| Countdown::from(l).solve(vec![Op::Add, Op::Mul, Op::Concat]))
-            .fold(0, |acc, res| match res {
-                Some((_, target)) => acc + target,
-                None => acc,
-            }),
-    )
+pub fn part_two(input: &str) -> Result<u64, AocError> {
+    Ok(parse_input(input)?
+        .into_iter()
+        .map(|c| c.solve(vec![Op::Add, Op::Mul, Op::Concat]))
+        .fold(0, |acc, res| match res {
+            Some((_, target)) => acc + target,
+            None => acc,
+        }))
 }
 
 #[cfg(test)]
@@ -150,13 +137,13 @@ mod tests {
 
     #[test]
     fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, Some(3749));
+        let result = part_one(&advent_of_code::template::read_file("examples", YEAR, DAY));
+        assert_eq!(result, Ok(3749));
     }
 
     #[test]
     fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, Some(11387));
+        let result = part_two(&advent_of_code::template::read_file("examples", YEAR, DAY));
+        assert_eq!(result, Ok(11387));
     }
 }
@@ -0,0 +1,96 @@
+advent_of_code::solution!(2024, 8);
+
+use advent_of_code::grid::{Grid, Pos};
+use advent_of_code::math::gcd;
+use itertools::iproduct;
+use std::collections::{HashMap, HashSet};
+
+#[derive(Debug)]
+struct Antennas {
+    grid: Grid<char>,
+    nodes: HashMap<char, Vec<Pos>>,
+}
+
+// Euclid
+// Offset from node1 to node2
+fn offset(node1: Pos, node2: Pos) -> (isize, isize) {
+    (
+        node2.0 as isize - node1.0 as isize,
+        node2.1 as isize - node1.1 as isize,
+    )
+}
+
+impl Antennas {
+    fn count_antinodes(&self) -> u32 {
+        let mut antinodes = HashSet::new();
+        for char_nodes in self.nodes.values() {
+            for (node1, node2) in iproduct!(char_nodes, char_nodes) {
+                if node1 == node2 {
+                    continue;
+                }
+                if let Some(antinode) = self.grid.offset(*node2, offset(*node1, *node2)) {
+                    antinodes.insert(antinode);
+                }
+            }
+        }
+        antinodes.len() as u32
+    }
+
+    fn count_resonant_antinodes(&self) -> u32 {
+        let mut antinodes = HashSet::new();
+        for char_nodes in self.nodes.values() {
+            for (node1, node2) in iproduct!(char_nodes, char_nodes) {
+                if node1 == node2 {
+                    continue;
+                }
+                let (dx, dy) = offset(*node1, *node2);
+                let gcd = gcd(dx as i64, dy as i64) as isize; // never negative, to not mess the direction up
+                let min_offset = (dx / gcd, dy / gcd);
+                let mut maybe_antinode = Some(*node1);
+                while let Some(antinode) = maybe_antinode {
+                    antinodes.insert(antinode);
+                    maybe_antinode = self.grid.offset(antinode, min_offset);
+                }
+            }
+        }
+        antinodes.len() as u32
+    }
+}
+
+impl From<&str> for Antennas {
+    fn from(input: &str) -> Self {
+        let grid = Grid::parse(input, |c| c);
+        let mut nodes: HashMap<char, Vec<Pos>> = HashMap::new();
+        for (pos, &char) in grid.iter() {
+            if char != '.' {
+                nodes.entry(char).or_default().push(pos);
+            }
+        }
+        Self { grid, nodes }
+    }
+}
+
+pub fn part_one(input: &str) -> Option<u32> {
+    Some(Antennas::from(input).count_antinodes())
+}
+
+pub fn part_two(input: &str) -> Option<u32> {
+    Some(Antennas::from(input).count_resonant_antinodes())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        let result = part_one(&advent_of_code::template::read_file("examples", YEAR, DAY));
+        assert_eq!(result, Some(14));
+    }
+
+    #[test]
+    fn test_part_two() {
+        let result = part_two(&advent_of_code::template::read_file("examples", YEAR, DAY));
+        assert_eq!(result, Some(34));
+    }
+}
@@ -0,0 +1,57 @@
+advent_of_code::solution!(2024, 10);
+
+use advent_of_code::grid::{Grid, Pos};
+use std::collections::BTreeSet;
+
+fn sum_trailheads<T: Clone + Default + IntoIterator<Item = Pos> + FromIterator<Pos>>(
+    heights: &Grid<u32>,
+) -> u32 {
+    let mut reachable_nines: Grid<T> = heights.map(|_| T::default());
+    for pos in heights.find_all(|&h| h == 9) {
+        reachable_nines[pos] = [pos].into_iter().collect();
+    }
+
+    // For each value of N < 9, collect all the reachable nines of its neighbours that are N+1
+    for n in (0..9).rev() {
+        for pos in heights.find_all(|&h| h == n) {
+            reachable_nines[pos] = heights
+                .neighbours(pos)
+                .filter(|&p| heights[p] == n + 1)
+                .flat_map(|p| reachable_nines[p].clone())
+                .collect();
+        }
+    }
+    heights.find_all(|&h| h == 0).fold(0, |acc, pos| {
+        // .len() isn't provided by a trait, so .into_iter().count() is used instead
+        acc + reachable_nines[pos].clone().into_iter().count() as u32
+    })
+}
+
+fn parse_input(input: &str) -> Grid<u32> {
+    Grid::parse(input, |c| c.to_digit(10).expect("Invalid input"))
+}
+
+pub fn part_one(input: &str) -> Option<u32> {
+    Some(sum_trailheads::<BTreeSet<_>>(&parse_input(input)))
+}
+
+pub fn part_two(input: &str) -> Option<u32> {
+    Some(sum_trailheads::<Vec<_>>(&parse_input(input)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        let result = part_one(&advent_of_code::template::read_file("examples", YEAR, DAY));
+        assert_eq!(result, Some(36));
+    }
+
+    #[test]
+    fn test_part_two() {
+        let result = part_two(&advent_of_code::template::read_file("examples", YEAR, DAY));
+        assert_eq!(result, Some(81));
+    }
+}
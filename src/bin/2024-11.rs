@@ -1,18 +1,15 @@
-advent_of_code::solution!(11);
+advent_of_code::solution!(2024, 11);
 
 use std::collections::BTreeMap;
 
+use advent_of_code::math::digit_count;
+
 fn stone(x: u64) -> (u64, Option<u64>) {
     if x == 0 {
         (1, None)
     } else {
-        let mut temp = x;
-        let mut n_digits = 0;
-        while temp >= 1 {
-            temp /= 10;
-            n_digits += 1;
-        }
-        if n_digits % 2 == 0 {
+        let n_digits = digit_count(x);
+        if n_digits.is_multiple_of(2) {
             let modulus = 10u64.pow(n_digits / 2);
             (x / modulus, Some(x % modulus))
         } else {
@@ -81,13 +78,13 @@ mod tests {
 
     #[test]
     fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
+        let result = part_one(&advent_of_code::template::read_file("examples", YEAR, DAY));
         assert_eq!(result, Some(55312));
     }
 
     #[test]
     fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
+        let result = part_two(&advent_of_code::template::read_file("examples", YEAR, DAY));
         // Puzzle answer isn't given
         assert!(result.is_some());
     }
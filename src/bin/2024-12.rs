@@ -0,0 +1,196 @@
+advent_of_code::solution!(2024, 12);
+
+use advent_of_code::grid::{Direction, Grid, Pos};
+
+struct Perimeter {
+    point: Pos,
+    direction: Direction,
+}
+
+struct Region {
+    plant_type: char,
+    points: Vec<Pos>,
+    area: u32,
+    perimeter: Vec<Perimeter>,
+}
+
+fn to_grid(input: &str) -> Grid<(char, bool)> {
+    Grid::parse(input, |c| (c, false))
+}
+
+// Splits off the first region it can find, otherwise if the whole grid has already been
+// covered returns None
+fn split_off_region(grid: &mut Grid<(char, bool)>) -> Option<Region> {
+    // If the whole grid has already been traversed, there is no region left
+    let start = grid.find(|(_, visited)| !visited)?;
+
+    // Find the region starting at this point
+    let mut region = Region {
+        plant_type: grid[start].0,
+        points: Vec::new(),
+        area: 0,
+        perimeter: Vec::new(),
+    };
+    let mut points_in_region = vec![start];
+
+    // For each points in region
+    while let Some(point) = points_in_region.pop() {
+        // If it has already been visited, skip it. This can happen if we visit a square
+        // that is adjacent to two other squares
+        if grid[point].1 {
+            continue;
+        }
+        // Set it to visited
+        grid[point].1 = true;
+        // Add it to the region's points
+        region.points.push(point);
+        // Increment the region's area
+        region.area += 1;
+        // Iterate over the adjacent tiles
+        for direction in Direction::ORTHOGONAL {
+            match grid.step(point, direction) {
+                // If it's in bounds and of the same type, add it to the list of points we need
+                // to traverse
+                Some(adjacent) if grid[adjacent].0 == region.plant_type => {
+                    points_in_region.push(adjacent);
+                }
+                // If not then we need to add a perimeter on that side, which also covers
+                // adjacent tiles that are out of bounds
+                _ => region.perimeter.push(Perimeter { point, direction }),
+            }
+        }
+    }
+
+    // When we exit the loop, the region has been fully defined
+    Some(region)
+}
+
+fn extract_regions(mut grid: Grid<(char, bool)>) -> Vec<Region> {
+    let mut regions = Vec::new();
+    while let Some(region) = split_off_region(&mut grid) {
+        regions.push(region);
+    }
+    regions
+}
+
+fn get_n_sides(mut region: Region, nrows: usize, ncols: usize) -> u32 {
+    let mut n_sides = 0;
+    // If there are still points, it means we found a new side
+    while let Some(peri) = region.perimeter.pop() {
+        n_sides += 1;
+        // Search upwards and downwards and remove all points that are part of the same side
+        match peri.direction {
+            Direction::N | Direction::S => {
+                let mut j_up = peri.point.1 + 1;
+                while j_up < ncols {
+                    let p = region.perimeter.iter().position(|p| {
+                        p.direction == peri.direction
+                            && p.point.0 == peri.point.0
+                            && p.point.1 == j_up
+                    });
+                    match p {
+                        Some(p) => {
+                            region.perimeter.remove(p);
+                        }
+                        None => break,
+                    }
+                    j_up += 1;
+                }
+                if peri.point.1 > 0 {
+                    let mut j_down = peri.point.1 - 1;
+                    loop {
+                        let p = region.perimeter.iter().position(|p| {
+                            p.direction == peri.direction
+                                && p.point.0 == peri.point.0
+                                && p.point.1 == j_down
+                        });
+                        match p {
+                            Some(p) => {
+                                region.perimeter.remove(p);
+                            }
+                            None => break,
+                        }
+                        if j_down == 0 {
+                            break;
+                        }
+                        j_down -= 1;
+                    }
+                }
+            }
+            _ => {
+                let mut i_up = peri.point.0 + 1;
+                while i_up < nrows {
+                    let p = region.perimeter.iter().position(|p| {
+                        p.direction == peri.direction
+                            && p.point.0 == i_up
+                            && p.point.1 == peri.point.1
+                    });
+                    match p {
+                        Some(p) => {
+                            region.perimeter.remove(p);
+                        }
+                        None => break,
+                    }
+                    i_up += 1;
+                }
+                if peri.point.0 > 0 {
+                    let mut i_down = peri.point.0 - 1;
+                    loop {
+                        let p = region.perimeter.iter().position(|p| {
+                            p.direction == peri.direction
+                                && p.point.0 == i_down
+                                && p.point.1 == peri.point.1
+                        });
+                        match p {
+                            Some(p) => {
+                                region.perimeter.remove(p);
+                            }
+                            None => break,
+                        }
+                        if i_down == 0 {
+                            break;
+                        }
+                        i_down -= 1;
+                    }
+                }
+            }
+        }
+    }
+    n_sides
+}
+
+pub fn part_one(input: &str) -> Option<u32> {
+    let regions = extract_regions(to_grid(input));
+    let price = regions.into_iter().fold(0, |acc, region| {
+        acc + (region.area * region.perimeter.len() as u32)
+    });
+    Some(price)
+}
+
+pub fn part_two(input: &str) -> Option<u32> {
+    let grid = to_grid(input);
+    let nrows = grid.height();
+    let ncols = grid.width();
+    let regions = extract_regions(grid);
+    let price = regions.into_iter().fold(0, |acc, region| {
+        acc + (region.area * get_n_sides(region, nrows, ncols))
+    });
+    Some(price)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        let result = part_one(&advent_of_code::template::read_file("examples", YEAR, DAY));
+        assert_eq!(result, Some(1930));
+    }
+
+    #[test]
+    fn test_part_two() {
+        let result = part_two(&advent_of_code::template::read_file("examples", YEAR, DAY));
+        assert_eq!(result, Some(1206));
+    }
+}
@@ -0,0 +1,156 @@
+advent_of_code::solution!(2024, 13);
+
+use advent_of_code::error::AocError;
+use advent_of_code::math::{div_ceil, div_floor, extended_gcd, solve_2x2};
+use advent_of_code::parse::{blocks_of, labelled, parse_all, unsigned, IResult, ParseError};
+use nom::{
+    bytes::complete::tag,
+    character::complete::line_ending,
+    sequence::{preceded, separated_pair, terminated, tuple},
+};
+
+struct LinearEquations {
+    // { c0*x + c1*y = c2
+    // { c3*x + c4*y = c5
+    c0: i64,
+    c1: i64,
+    c2: i64,
+    c3: i64,
+    c4: i64,
+    c5: i64,
+}
+
+impl LinearEquations {
+    fn has_linearly_dependent_coeffs(&self) -> bool {
+        self.c0 * self.c4 == self.c1 * self.c3
+    }
+
+    fn solve(&self) -> Option<(i64, i64)> {
+        if self.has_linearly_dependent_coeffs() {
+            return self.solve_dependent();
+        }
+        let (a, b) = solve_2x2([[self.c0, self.c1], [self.c3, self.c4]], [self.c2, self.c5])?;
+        // Restrict to integral solutions
+        let (a, b) = (a.to_integer()?, b.to_integer()?);
+        if a > 0 && b > 0 {
+            // This doesn't happen with the input given
+            Some((a, b))
+        } else {
+            None
+        }
+    }
+
+    // With linearly dependent coefficients there are either 0 solutions or infinitely many, if
+    // the second equation is a multiple of the first. The input doesn't actually contain any such
+    // cases, but if it did we would want the cheapest one.
+    fn solve_dependent(&self) -> Option<(i64, i64)> {
+        if self.c0 * self.c5 != self.c2 * self.c3 || self.c1 * self.c5 != self.c2 * self.c4 {
+            return None;
+        }
+        // All solutions of c0*a + c1*b = c2 are a = a0 + k*step_a, b = b0 - k*step_b
+        let (g, x, y) = extended_gcd(self.c0, self.c1);
+        if g == 0 || self.c2 % g != 0 {
+            return None;
+        }
+        let (a0, b0) = (x * (self.c2 / g), y * (self.c2 / g));
+        let (step_a, step_b) = (self.c1 / g, self.c0 / g);
+        // a > 0 and b > 0 restrict k to [k_min, k_max]
+        let k_min = div_floor(-a0, step_a) + 1;
+        let k_max = div_ceil(b0, step_b) - 1;
+        // The price is linear in k, so the cheapest solution is at one of the ends
+        [k_min, k_max]
+            .into_iter()
+            .filter(|_| k_min <= k_max)
+            .map(|k| (a0 + k * step_a, b0 - k * step_b))
+            .min_by_key(|(a, b)| a * 3 + b)
+    }
+
+    fn price(&self) -> u64 {
+        match self.solve() {
+            None => 0,
+            Some((x, y)) => (x * 3 + y) as u64,
+        }
+    }
+}
+
+// Parse a line like `Button A: X+94, Y+34`, where `sign` is the character before each number
+fn parse_line<'a>(label: &'a str, sign: &'a str) -> impl FnMut(&'a str) -> IResult<'a, (i64, i64)> {
+    labelled(
+        label,
+        separated_pair(
+            preceded(tuple((tag("X"), tag(sign))), unsigned),
+            tag(", "),
+            preceded(tuple((tag("Y"), tag(sign))), unsigned),
+        ),
+    )
+}
+
+fn parse_equations(input: &str) -> IResult<'_, LinearEquations> {
+    let (input, ((c0, c3), (c1, c4), (c2, c5))) = tuple((
+        terminated(parse_line("Button A", "+"), line_ending),
+        terminated(parse_line("Button B", "+"), line_ending),
+        parse_line("Prize", "="),
+    ))(input)?;
+    Ok((
+        input,
+        LinearEquations {
+            c0,
+            c1,
+            c2,
+            c3,
+            c4,
+            c5,
+        },
+    ))
+}
+
+fn parse_input(input: &str) -> Result<Vec<LinearEquations>, ParseError> {
+    parse_all(input, blocks_of(parse_equations))
+}
+
+pub fn part_one(input: &str) -> Result<u64, AocError> {
+    let equations = parse_input(input)?;
+    let total_price = equations.iter().map(LinearEquations::price).sum();
+    Ok(total_price)
+}
+
+pub fn part_two(input: &str) -> Result<u64, AocError> {
+    let equations = parse_input(input)?
+        .into_iter()
+        .map(|le| LinearEquations {
+            c0: le.c0,
+            c1: le.c1,
+            c2: le.c2 + 10000000000000,
+            c3: le.c3,
+            c4: le.c4,
+            c5: le.c5 + 10000000000000,
+        })
+        .collect::<Vec<_>>();
+    let total_price = equations.iter().map(LinearEquations::price).sum();
+    Ok(total_price)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        let result = part_one(&advent_of_code::template::read_file("examples", YEAR, DAY));
+        assert_eq!(result, Ok(480));
+    }
+
+    #[test]
+    fn test_part_two() {
+        let result = part_two(&advent_of_code::template::read_file("examples", YEAR, DAY));
+        assert_eq!(result, Ok(875318608908));
+    }
+
+    #[test]
+    fn test_dependent_buttons() {
+        let machine = "Button A: X+2, Y+4\nButton B: X+1, Y+2\nPrize: X=10, Y=20";
+        assert_eq!(parse_input(machine).unwrap()[0].solve(), Some((1, 8)));
+        let machine = "Button A: X+2, Y+4\nButton B: X+1, Y+2\nPrize: X=10, Y=21";
+        assert_eq!(parse_input(machine).unwrap()[0].solve(), None);
+    }
+}
@@ -1,11 +1,12 @@
-advent_of_code::solution!(14);
+advent_of_code::solution!(2024, 14);
 
+use advent_of_code::error::AocError;
+use advent_of_code::math::crt;
+use advent_of_code::parse::{lines_of, parse_all, signed, IResult, ParseError};
 use enum_map::{enum_map, Enum};
 use nom::{
     bytes::complete::tag,
-    character::complete::digit1,
-    combinator::{map_res, opt, recognize},
-    sequence::preceded,
+    sequence::{preceded, separated_pair, tuple},
 };
 use std::io::{stdin, stdout, Write};
 
@@ -20,6 +21,7 @@ struct Robot {
 const MAP_X: i32 = 101;
 const MAP_Y: i32 = 103;
 
+#[allow(clippy::upper_case_acronyms)]
 #[derive(Debug, Enum)]
 enum Quadrant {
     I,
@@ -59,25 +61,16 @@ impl Robot {
     }
 }
 
-impl From<&str> for Robot {
-    fn from(s: &str) -> Self {
-        fn parse_i32(input: &str) -> nom::IResult<&str, i32> {
-            map_res(recognize(preceded(opt(tag("-")), digit1)), str::parse)(input)
-        }
-        fn parse_line(input: &str) -> nom::IResult<&str, Robot> {
-            let (input, _) = tag("p=")(input)?;
-            let (input, x) = parse_i32(input)?;
-            let (input, _) = tag(",")(input)?;
-            let (input, y) = parse_i32(input)?;
-            let (input, _) = tag(" v=")(input)?;
-            let (input, v_x) = parse_i32(input)?;
-            let (input, _) = tag(",")(input)?;
-            let (input, v_y) = parse_i32(input)?;
-            Ok((input, Robot { x, y, v_x, v_y }))
-        }
-        let (_, robot) = parse_line(s).unwrap();
-        robot
-    }
+fn parse_robot(input: &str) -> IResult<'_, Robot> {
+    let (input, ((x, y), (v_x, v_y))) = tuple((
+        preceded(tag("p="), separated_pair(signed, tag(","), signed)),
+        preceded(tag(" v="), separated_pair(signed, tag(","), signed)),
+    ))(input)?;
+    Ok((input, Robot { x, y, v_x, v_y }))
+}
+
+fn parse_input(input: &str) -> Result<Vec<Robot>, ParseError> {
+    parse_all(input, lines_of(parse_robot))
 }
 
 fn display_grid(robots: &[Robot], map_x: i32, map_y: i32) {
@@ -112,31 +105,56 @@ fn travel_and_clip_all_robots(robots: &mut[Robot], seconds: i32) {
         .for_each(|r| r.travel_and_clip(seconds, MAP_X, MAP_Y));
 }
 
-pub fn part_one(input: &str) -> Option<u32> {
+// The robots move independently along both axes, so their x positions repeat every MAP_X seconds
+// and their y positions every MAP_Y seconds. The tree appears when the robots are clustered along
+// both axes at once, which the chinese remainder theorem finds from the most clustered time of each
+// axis.
+fn find_tree(robots: &mut [Robot]) -> i64 {
+    let mut best_x = (f64::MAX, 0);
+    let mut best_y = (f64::MAX, 0);
+    for seconds in 0..MAP_X.max(MAP_Y) {
+        let (x_stdev, y_stdev) = get_x_y_stdevs(robots);
+        if seconds < MAP_X && x_stdev < best_x.0 {
+            best_x = (x_stdev, seconds);
+        }
+        if seconds < MAP_Y && y_stdev < best_y.0 {
+            best_y = (y_stdev, seconds);
+        }
+        travel_and_clip_all_robots(robots, 1);
+    }
+    let congruences = [
+        (i64::from(best_x.1), i64::from(MAP_X)),
+        (i64::from(best_y.1), i64::from(MAP_Y)),
+    ];
+    crt(&congruences).expect("map sizes are coprime").0
+}
+
+pub fn part_one(input: &str) -> Result<u32, AocError> {
     let mut quadrant_counts = enum_map! {
         Quadrant::I => 0,
         Quadrant::II => 0,
         Quadrant::III => 0,
         Quadrant::IV => 0,
     };
-    input.lines().for_each(|l| {
-        let mut robot: Robot = l.into();
+    let robots = parse_input(input)?;
+    robots.into_iter().for_each(|mut robot| {
         robot.travel(100);
         robot.clip(MAP_X, MAP_Y);
         if let Some(q) = robot.get_quadrant(MAP_X, MAP_Y) {
             quadrant_counts[q] += 1;
         }
     });
-    Some(quadrant_counts.values().fold(1, |acc, v| acc * *v))
+    Ok(quadrant_counts.values().fold(1, |acc, v| acc * *v))
 }
 
-pub fn part_two(input: &str) -> Option<u32> {
+pub fn part_two(input: &str) -> Result<u32, AocError> {
     // Enable this to allow for interactive control
     let interactive = false;
     if !interactive {
-        Some(7790)
+        let mut robots = parse_input(input)?;
+        Ok(find_tree(&mut robots) as u32)
     } else {
-        let mut robots: Vec<Robot> = input.lines().map(|l| l.into()).collect();
+        let mut robots = parse_input(input)?;
         let mut seconds = 0;
         let mut command;
         let mut print_grid = true;
@@ -203,16 +221,15 @@ pub fn part_two(input: &str) -> Option<u32> {
                     }
                 }
                 "s" => {
-                    return Some(seconds);
+                    return Ok(seconds);
                 }
-                "q" => return None,
+                "q" => return Err(AocError::unsolvable("Quit before the tree was found")),
                 "" => {
                     if n_bytes == 0 {
                         // EOF
                         println!();
-                        return None
-                    }
-                    else {
+                        return Err(AocError::unsolvable("Stopped before the tree was found"));
+                    } else {
                         // Empty input
                         print_grid = false;
                         continue;
@@ -241,13 +258,28 @@ mod tests {
 
     #[test]
     fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, Some(12));
+        let result = part_one(&advent_of_code::template::read_file("examples", YEAR, DAY));
+        assert_eq!(result, Ok(12));
     }
 
     #[test]
-    fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, None);
+    fn test_find_tree() {
+        // the example map is too small for a tree, so gather a few robots in the middle of the
+        // full map after a known number of seconds instead.
+        let seconds = 1234;
+        let mut robots: Vec<Robot> = [(1, 2), (-3, 5), (7, -4), (-11, -13), (20, 9)]
+            .into_iter()
+            .map(|(v_x, v_y)| {
+                let mut robot = Robot {
+                    x: 50,
+                    y: 51,
+                    v_x,
+                    v_y,
+                };
+                robot.travel_and_clip(-seconds, MAP_X, MAP_Y);
+                robot
+            })
+            .collect();
+        assert_eq!(find_tree(&mut robots), i64::from(seconds));
     }
 }
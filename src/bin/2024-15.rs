@@ -1,32 +1,13 @@
-advent_of_code::solution!(15);
+advent_of_code::solution!(2024, 15);
 
 use std::io::{stdin, stdout, Write};
 
-#[derive(Copy, Debug, Clone, PartialEq, Eq)]
-enum Dir {
-    N,
-    E,
-    S,
-    W,
-}
-
-fn get_next_index(cur_index: (usize, usize), dir: &Dir) -> (usize, usize) {
-    // No need for bounds check because there is an edge of walls which will never be moved / moved
-    // into
-    match dir {
-        Dir::N => (cur_index.0 - 1, cur_index.1),
-        Dir::E => (cur_index.0, cur_index.1 + 1),
-        Dir::S => (cur_index.0 + 1, cur_index.1),
-        Dir::W => (cur_index.0, cur_index.1 - 1),
-    }
-}
-
-fn get_next_index_multi(cur_index: (usize, usize), dirs: &[Dir]) -> (usize, usize) {
-    dirs.iter().fold(cur_index, get_next_index)
-}
+use advent_of_code::error::AocError;
+use advent_of_code::grid::{Direction, Grid, Pos};
+use advent_of_code::parse::ParseError;
 
 #[derive(Debug, PartialEq, Eq)]
-enum Grid {
+enum Tile {
     Empty,
     Wall,
     Box,
@@ -37,8 +18,8 @@ enum Grid {
 
 #[derive(Debug)]
 struct Game {
-    grid: Vec<Vec<Grid>>,
-    robot: (usize, usize),
+    grid: Grid<Tile>,
+    robot: Pos,
 }
 
 #[derive(Copy, Clone, Eq, PartialEq, Debug)]
@@ -48,17 +29,23 @@ enum Part {
 }
 
 impl Game {
+    fn next_index(&self, index: Pos, dir: Direction) -> Pos {
+        // No need for bounds check because there is an edge of walls which will never be moved /
+        // moved into
+        self.grid.step(index, dir).expect("walls surround the grid")
+    }
+
     // If the box at `box_index` can be moved, this returns Some(Vec of indices of boxes that need
     // to be moved) (including the one passed to this function). If it can't, returns None.
     fn get_boxes_to_move_incl(
         &mut self,
-        box_index: (usize, usize),
-        dir: Dir,
+        box_index: Pos,
+        dir: Direction,
         part: Part,
-    ) -> Option<Vec<(usize, usize)>> {
+    ) -> Option<Vec<Pos>> {
         // Note that box_index should only contain a Box, NOT a BoxRightHalf
-        match &self.grid[box_index.0][box_index.1] {
-            Grid::Box => {}
+        match &self.grid[box_index] {
+            Tile::Box => {}
             _ => panic!("get_boxes_to_move called with non-box index"),
         }
         match part {
@@ -69,21 +56,17 @@ impl Game {
         }
     }
 
-    fn get_boxes_to_move_incl_p1(
-        &mut self,
-        this_index: (usize, usize),
-        dir: Dir,
-    ) -> Option<Vec<(usize, usize)>> {
+    fn get_boxes_to_move_incl_p1(&mut self, this_index: Pos, dir: Direction) -> Option<Vec<Pos>> {
         // Check what's in front of the box
-        let next_index = get_next_index(this_index, &dir);
-        match &self.grid[next_index.0][next_index.1] {
+        let next_index = self.next_index(this_index, dir);
+        match &self.grid[next_index] {
             // If it's empty, then the only thing that needs to be moved is this box itself
-            Grid::Empty => Some(vec![this_index]),
+            Tile::Empty => Some(vec![this_index]),
             // If it's a wall, we can't move anything
-            Grid::Wall => None,
+            Tile::Wall => None,
             // If it's a box, we need to check if that box can be moved, so we call this function
             // recursively
-            Grid::Box => match self.get_boxes_to_move_incl_p1(next_index, dir) {
+            Tile::Box => match self.get_boxes_to_move_incl_p1(next_index, dir) {
                 // If that returned None, it means the box couldn't be moved, so we
                 // can't move this one either
                 None => None,
@@ -93,32 +76,28 @@ impl Game {
                     Some(boxes_to_be_moved)
                 }
             },
-            Grid::BoxRightHalf => panic!("BoxRightHalf found in part 1"),
+            Tile::BoxRightHalf => panic!("BoxRightHalf found in part 1"),
         }
     }
 
-    fn get_boxes_to_move_incl_p2(
-        &mut self,
-        this_index: (usize, usize),
-        dir: Dir,
-    ) -> Option<Vec<(usize, usize)>> {
+    fn get_boxes_to_move_incl_p2(&mut self, this_index: Pos, dir: Direction) -> Option<Vec<Pos>> {
         // The recursive behaviour of this function is, at its core, the same as that of part 1
         // (get_boxes_to_move_incl_p1()). It checks what's in front of the box, sees if those
         // are themselves movable, and returns a list of all boxes that need to be moved. However,
         // we need to be careful about which indices to check.
         match dir {
-            Dir::W => {
+            Direction::W => {
                 // For example, when moving west, we need to check whether next_index is a
                 // BoxRightHalf, not a Box.
-                let next_index = get_next_index(this_index, &Dir::W);
-                match &self.grid[next_index.0][next_index.1] {
-                    Grid::Empty => Some(vec![this_index]),
-                    Grid::Wall => None,
-                    Grid::Box => panic!("Shouldn't happen"),
-                    Grid::BoxRightHalf => {
+                let next_index = self.next_index(this_index, Direction::W);
+                match &self.grid[next_index] {
+                    Tile::Empty => Some(vec![this_index]),
+                    Tile::Wall => None,
+                    Tile::Box => panic!("Shouldn't happen"),
+                    Tile::BoxRightHalf => {
                         // Same code as for Part 1, except that we skip over the BoxRightHalf
                         // and check the Box to the left of it.
-                        let next_next_index = get_next_index(next_index, &Dir::W);
+                        let next_next_index = self.next_index(next_index, Direction::W);
                         match self.get_boxes_to_move_incl_p2(next_next_index, dir) {
                             None => None,
                             Some(mut next_boxes_to_be_moved) => {
@@ -129,35 +108,36 @@ impl Game {
                     }
                 }
             }
-            Dir::E => {
+            Direction::E => {
                 // When moving east, the next_index is guaranteed to be a BoxRightHalf, so we
                 // need to skip over it.
-                let next_next_index = get_next_index_multi(this_index, &[Dir::E, Dir::E]);
-                match &self.grid[next_next_index.0][next_next_index.1] {
-                    Grid::Empty => Some(vec![this_index]),
-                    Grid::Wall => None,
+                let next_next_index =
+                    self.next_index(self.next_index(this_index, Direction::E), Direction::E);
+                match &self.grid[next_next_index] {
+                    Tile::Empty => Some(vec![this_index]),
+                    Tile::Wall => None,
                     // Otherwise, though, the behaviour is exactly the same as in Part 1.
-                    Grid::Box => match self.get_boxes_to_move_incl_p2(next_next_index, dir) {
+                    Tile::Box => match self.get_boxes_to_move_incl_p2(next_next_index, dir) {
                         None => None,
                         Some(mut next_boxes_to_be_moved) => {
                             next_boxes_to_be_moved.push(this_index);
                             Some(next_boxes_to_be_moved)
                         }
                     },
-                    Grid::BoxRightHalf => panic!("Shouldn't happen"),
+                    Tile::BoxRightHalf => panic!("Shouldn't happen"),
                 }
             }
-            Dir::N | Dir::S => {
-                // When moving north or south, it gets ugly.
-                let next_index = get_next_index(this_index, &dir);
-                let next_index_w = get_next_index(next_index, &Dir::W);
-                let next_index_e = get_next_index(next_index, &Dir::E);
+            _ => {
+                // When moving north or south (the robot never moves diagonally), it gets ugly.
+                let next_index = self.next_index(this_index, dir);
+                let next_index_w = self.next_index(next_index, Direction::W);
+                let next_index_e = self.next_index(next_index, Direction::E);
                 // Say we are moving north. First, we check for walls above the box. The wall could
                 // also be above the BoxRightHalf, so we need to check the northeast index as well.
                 let possible_wall_indices = [next_index, next_index_e];
                 if possible_wall_indices
                     .into_iter()
-                    .any(|i| self.grid[i.0][i.1] == Grid::Wall)
+                    .any(|i| self.grid[i] == Tile::Wall)
                 {
                     return None;
                 }
@@ -167,7 +147,7 @@ impl Game {
                 let possible_box_indices = [next_index_w, next_index, next_index_e];
                 let mut boxes_to_be_moved = Vec::new();
                 for i in possible_box_indices {
-                    if self.grid[i.0][i.1] == Grid::Box {
+                    if self.grid[i] == Tile::Box {
                         match self.get_boxes_to_move_incl_p2(i, dir) {
                             // If _any_ of the boxes can't be moved, then the entire thing can't be
                             // moved, so we shortcircuit and return None.
@@ -188,7 +168,7 @@ impl Game {
 
     // Move a box together with all other boxes it would push. If any boxes were moved,
     // returns true. If no boxes were moved, returns false.
-    fn move_box(&mut self, box_index: (usize, usize), dir: Dir, part: Part) -> bool {
+    fn move_box(&mut self, box_index: Pos, dir: Direction, part: Part) -> bool {
         match self.get_boxes_to_move_incl(box_index, dir, part) {
             None => false,
             // Otherwise, move all of them
@@ -205,21 +185,21 @@ impl Game {
                     moved_boxes.push(box_index);
 
                     // Actually push
-                    let next_index = get_next_index(box_index, &dir);
-                    self.grid[next_index.0][next_index.1] = Grid::Box;
-                    self.grid[box_index.0][box_index.1] = Grid::Empty;
+                    let next_index = self.next_index(box_index, dir);
+                    self.grid[next_index] = Tile::Box;
+                    self.grid[box_index] = Tile::Empty;
 
                     // For part 2, there's some special handling as we need to move both parts of
                     // the box.
                     if part == Part::Two {
                         // Move the BoxRightHalf along with the original Box
-                        let new_east_index = get_next_index(next_index, &Dir::E);
-                        self.grid[new_east_index.0][new_east_index.1] = Grid::BoxRightHalf;
+                        let new_east_index = self.next_index(next_index, Direction::E);
+                        self.grid[new_east_index] = Tile::BoxRightHalf;
                         // We need to clear the old BoxRightHalf, _unless_ we moved east, because
                         // in that case the Box will be moved into the BoxRightHalf's old position.
-                        if dir != Dir::E {
-                            let old_east_index = get_next_index(box_index, &Dir::E);
-                            self.grid[old_east_index.0][old_east_index.1] = Grid::Empty;
+                        if dir != Direction::E {
+                            let old_east_index = self.next_index(box_index, Direction::E);
+                            self.grid[old_east_index] = Tile::Empty;
                         }
                     }
                 }
@@ -229,22 +209,22 @@ impl Game {
     }
 
     // Move the robot in the given direction.
-    fn move_robot(&mut self, dir: Dir, part: Part) {
-        let next_index = get_next_index(self.robot, &dir);
-        match &self.grid[next_index.0][next_index.1] {
-            Grid::Empty => {
+    fn move_robot(&mut self, dir: Direction, part: Part) {
+        let next_index = self.next_index(self.robot, dir);
+        match &self.grid[next_index] {
+            Tile::Empty => {
                 self.robot = next_index;
             }
-            Grid::Wall => {}
-            Grid::Box => {
+            Tile::Wall => {}
+            Tile::Box => {
                 if self.move_box(next_index, dir, part) {
                     self.robot = next_index;
                 }
             }
             // Pushing against the right half is the same as pushing against the left half,
             // so we can reuse the function
-            Grid::BoxRightHalf => {
-                let box_left_half_index = get_next_index(next_index, &Dir::W);
+            Tile::BoxRightHalf => {
+                let box_left_half_index = self.next_index(next_index, Direction::W);
                 if self.move_box(box_left_half_index, dir, part) {
                     self.robot = next_index;
                 }
@@ -255,19 +235,19 @@ impl Game {
 
 impl Game {
     fn _pretty_print(&self, part: Part) {
-        for (i, row) in self.grid.iter().enumerate() {
+        for (i, row) in self.grid.rows().enumerate() {
             for (j, cell) in row.iter().enumerate() {
                 if (i, j) == self.robot {
                     print!("@");
                 } else {
                     match cell {
-                        Grid::Empty => print!("."),
-                        Grid::Wall => print!("#"),
-                        Grid::Box => match part {
+                        Tile::Empty => print!("."),
+                        Tile::Wall => print!("#"),
+                        Tile::Box => match part {
                             Part::One => print!("O"),
                             Part::Two => print!("["),
                         },
-                        Grid::BoxRightHalf => print!("]"),
+                        Tile::BoxRightHalf => print!("]"),
                     }
                 }
             }
@@ -276,19 +256,15 @@ impl Game {
     }
 
     fn sum_gps(&self) -> u32 {
-        let mut gps = 0;
-        for (i, row) in self.grid.iter().enumerate() {
-            for (j, cell) in row.iter().enumerate() {
-                if cell == &Grid::Box {
-                    gps += (100 * i) + j;
-                }
-            }
-        }
-        gps as u32
+        self.grid
+            .iter()
+            .filter(|(_, cell)| **cell == Tile::Box)
+            .map(|((i, j), _)| (100 * i + j) as u32)
+            .sum()
     }
 }
 
-fn parse_input(input: &str, part: Part) -> (Game, Vec<Dir>) {
+fn parse_input(input: &str, part: Part) -> Result<(Game, Vec<Direction>), AocError> {
     if let Some((game_str, moves_str)) = input.split_once("\n\n") {
         // Parse game state
         let mut robot = None;
@@ -298,56 +274,70 @@ fn parse_input(input: &str, part: Part) -> (Game, Vec<Dir>) {
             for (j, c) in line.chars().enumerate() {
                 match c {
                     '.' => {
-                        row.push(Grid::Empty);
+                        row.push(Tile::Empty);
                         if part == Part::Two {
-                            row.push(Grid::Empty);
+                            row.push(Tile::Empty);
                         }
                     }
                     '@' => {
-                        row.push(Grid::Empty);
+                        row.push(Tile::Empty);
                         match part {
                             Part::One => robot = Some((i, j)),
                             Part::Two => {
-                                row.push(Grid::Empty);
+                                row.push(Tile::Empty);
                                 robot = Some((i, 2 * j));
                             }
                         }
                     }
                     '#' => {
-                        row.push(Grid::Wall);
+                        row.push(Tile::Wall);
                         if part == Part::Two {
-                            row.push(Grid::Wall);
+                            row.push(Tile::Wall);
                         }
                     }
                     'O' => {
-                        row.push(Grid::Box);
+                        row.push(Tile::Box);
                         if part == Part::Two {
-                            row.push(Grid::BoxRightHalf);
+                            row.push(Tile::BoxRightHalf);
                         }
                     }
-                    _ => panic!("Invalid character in input: {}", c),
+                    _ => {
+                        let message = format!("Invalid character in input: {c:?}");
+                        return Err(ParseError::at_pos((i, j), message).into());
+                    }
                 }
             }
+            if grid
+                .first()
+                .is_some_and(|first: &Vec<Tile>| first.len() != row.len())
+            {
+                let message = "Row has a different length than the first row";
+                return Err(ParseError::at_pos((i, 0), message).into());
+            }
             grid.push(row);
         }
         let game = Game {
-            grid,
-            robot: robot.expect("No robot found"),
+            grid: Grid::from_rows(grid),
+            robot: robot.ok_or_else(|| AocError::invalid("No robot found"))?,
         };
         // Parse moves
         let moves = moves_str
             .chars()
             .filter_map(|c| match c {
-                '^' => Some(Dir::N),
-                '>' => Some(Dir::E),
-                'v' => Some(Dir::S),
-                '<' => Some(Dir::W),
+                '^' => Some(Direction::N),
+                '>' => Some(Direction::E),
+                'v' => Some(Direction::S),
+                '<' => Some(Direction::W),
                 _ => None, // Drop all other characters
             })
             .collect();
-        return (game, moves);
+        return Ok((game, moves));
     }
-    panic!("Failed parse");
+    Err(AocError::parse_at(
+        input,
+        &input[input.len()..],
+        "Expected a blank line between the map and the moves",
+    ))
 }
 
 // Play the game yourself! Interactively! With vim bindings!
@@ -359,10 +349,10 @@ fn _play_game(mut game: Game, part: Part) {
         let mut input = String::new();
         let n_bytes = stdin().read_line(&mut input).unwrap();
         match input.trim() {
-            "h" => game.move_robot(Dir::W, part),
-            "j" => game.move_robot(Dir::S, part),
-            "k" => game.move_robot(Dir::N, part),
-            "l" => game.move_robot(Dir::E, part),
+            "h" => game.move_robot(Direction::W, part),
+            "j" => game.move_robot(Direction::S, part),
+            "k" => game.move_robot(Direction::N, part),
+            "l" => game.move_robot(Direction::E, part),
             "q" => break,
             "" => {
                 if n_bytes == 0 {
@@ -374,18 +364,18 @@ fn _play_game(mut game: Game, part: Part) {
     }
 }
 
-pub fn part_one(input: &str) -> Option<u32> {
+pub fn part_one(input: &str) -> Result<u32, AocError> {
     const PART: Part = Part::One;
-    let (mut game, moves) = parse_input(input, PART);
+    let (mut game, moves) = parse_input(input, PART)?;
     moves.into_iter().for_each(|dir| game.move_robot(dir, PART));
-    Some(game.sum_gps())
+    Ok(game.sum_gps())
 }
 
-pub fn part_two(input: &str) -> Option<u32> {
+pub fn part_two(input: &str) -> Result<u32, AocError> {
     const PART: Part = Part::Two;
-    let (mut game, moves) = parse_input(input, PART);
+    let (mut game, moves) = parse_input(input, PART)?;
     moves.into_iter().for_each(|dir| game.move_robot(dir, PART));
-    Some(game.sum_gps())
+    Ok(game.sum_gps())
 }
 
 #[cfg(test)]
@@ -394,13 +384,24 @@ mod tests {
 
     #[test]
     fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, Some(10092));
+        let result = part_one(&advent_of_code::template::read_file("examples", YEAR, DAY));
+        assert_eq!(result, Ok(10092));
     }
 
     #[test]
     fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, Some(9021));
+        let result = part_two(&advent_of_code::template::read_file("examples", YEAR, DAY));
+        assert_eq!(result, Ok(9021));
+    }
+
+    #[test]
+    fn test_invalid_input() {
+        let error = part_one("####\n#@x#\n####\n\n<<").unwrap_err();
+        assert_eq!(error.location(), Some((2, 3)));
+        assert_eq!(
+            part_two("####\n#..#\n####\n\n<<"),
+            Err(AocError::invalid("No robot found"))
+        );
+        assert!(part_one("#@#").is_err());
     }
 }
@@ -0,0 +1,159 @@
+advent_of_code::solution!(2024, 16);
+
+use std::collections::HashSet;
+
+use advent_of_code::error::AocError;
+use advent_of_code::grid::{Direction, Grid, Pos};
+use advent_of_code::pathfinding::{dijkstra, Paths};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+struct Node {
+    pos: Pos,
+    dir: Direction,
+}
+impl Node {
+    fn new(pos: Pos, dir: Direction) -> Self {
+        Node { pos, dir }
+    }
+}
+
+#[derive(Debug)]
+struct Maze {
+    // Whether each square can be walked on
+    open: Grid<bool>,
+    start_pos: Pos,
+    end_pos: Pos,
+}
+
+impl Maze {
+    // The square next to `pos` in direction `dir`, if it can be walked on
+    fn next(&self, pos: Pos, dir: Direction) -> Option<Pos> {
+        self.open.step(pos, dir).filter(|next| self.open[*next])
+    }
+
+    // Get all possible edges from a node, along with their weights
+    fn edges(&self, node: &Node) -> Vec<(Node, u32)> {
+        [
+            // Walk forward
+            (node.dir, 1),
+            // Rotate 90 then walk forward
+            (node.dir.turn_right(), 1001),
+            // Rotate -90 then walk forward
+            (node.dir.turn_left(), 1001),
+        ]
+        .into_iter()
+        .filter_map(|(dir, weight)| Some((Node::new(self.next(node.pos, dir)?, dir), weight)))
+        .collect()
+    }
+
+    // Dijkstra's algorithm, starting facing east. Because our 'moves' consist of turning first
+    // BEFORE walking forward, there is no way we can enter a state where our back is facing a
+    // wall, so those nodes are never visited.
+    fn solve(&self) -> (Paths<Node>, [Node; 4]) {
+        let paths = dijkstra(Node::new(self.start_pos, Direction::E), |node| {
+            self.edges(node)
+        });
+        // We can reach the end facing any direction
+        let end_nodes = Direction::ORTHOGONAL.map(|dir| Node::new(self.end_pos, dir));
+        (paths, end_nodes)
+    }
+}
+
+impl TryFrom<&str> for Maze {
+    type Error = AocError;
+
+    fn try_from(input: &str) -> Result<Self, Self::Error> {
+        // Parse input String
+        let grid = Grid::try_parse(input, |c| "#.ES".contains(c).then_some(c))?;
+        let start_pos = grid
+            .find(|c| *c == 'S')
+            .ok_or_else(|| AocError::invalid("No start point found"))?;
+        let end_pos = grid
+            .find(|c| *c == 'E')
+            .ok_or_else(|| AocError::invalid("No end point found"))?;
+        let mut maze = Maze {
+            open: grid.map(|c| *c != '#'),
+            start_pos,
+            end_pos,
+        };
+
+        // Get rid of dead ends. We detect dead ends by the fact that they only have one neighbour
+        // in the grid. However, we must make sure to exclude the start and end points from this
+        loop {
+            let dead_ends = maze
+                .open
+                .find_all(|open| *open)
+                .filter(|p| *p != start_pos && *p != end_pos)
+                .filter(|p| {
+                    Direction::ORTHOGONAL
+                        .into_iter()
+                        .filter(|dir| maze.next(*p, *dir).is_some())
+                        .count()
+                        < 2
+                })
+                .collect::<Vec<_>>();
+            if dead_ends.is_empty() {
+                break;
+            }
+            for p in dead_ends {
+                maze.open[p] = false;
+            }
+        }
+
+        Ok(maze)
+    }
+}
+
+pub fn part_one(input: &str) -> Result<u32, AocError> {
+    let (paths, end_nodes) = Maze::try_from(input)?.solve();
+    // Return the distance to the end node with the lowest weight
+    paths
+        .closest(&end_nodes)
+        .map(|(_, distance)| distance)
+        .ok_or_else(|| AocError::unsolvable("The end point cannot be reached"))
+}
+
+pub fn part_two(input: &str) -> Result<u32, AocError> {
+    let (paths, end_nodes) = Maze::try_from(input)?.solve();
+    // Return all nodes on the shortest paths to the end node
+    let nodes = paths.nodes_on_shortest_paths(&end_nodes);
+    if nodes.is_empty() {
+        return Err(AocError::unsolvable("The end point cannot be reached"));
+    }
+    // Remove duplicate squares
+    Ok(nodes
+        .into_iter()
+        .map(|n| n.pos)
+        .collect::<HashSet<_>>()
+        .len() as u32)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        let result = part_one(&advent_of_code::template::read_file("examples", YEAR, DAY));
+        assert_eq!(result, Ok(7036));
+    }
+
+    #[test]
+    fn test_part_two() {
+        let result = part_two(&advent_of_code::template::read_file("examples", YEAR, DAY));
+        assert_eq!(result, Ok(45));
+    }
+
+    #[test]
+    fn test_invalid_input() {
+        assert_eq!(part_one("#S#\n#x#").unwrap_err().location(), Some((2, 2)));
+        assert_eq!(
+            part_one("#S.#"),
+            Err(AocError::invalid("No end point found"))
+        );
+        assert_eq!(
+            part_two("S#E"),
+            Err(AocError::unsolvable("The end point cannot be reached"))
+        );
+    }
+}
@@ -1,4 +1,12 @@
-advent_of_code::solution!(17);
+advent_of_code::solution!(2024, 17);
+
+use advent_of_code::error::AocError;
+use advent_of_code::parse::{comma_list, labelled, parse_all, unsigned, IResult};
+use nom::{
+    character::complete::line_ending,
+    combinator::verify,
+    sequence::{terminated, tuple},
+};
 
 #[derive(Debug, Clone, Copy)]
 enum Opcode {
@@ -28,6 +36,15 @@ impl From<u64> for Opcode {
     }
 }
 
+impl Opcode {
+    fn takes_combo_operand(self) -> bool {
+        matches!(
+            self,
+            Opcode::Adv0 | Opcode::Bst2 | Opcode::Out5 | Opcode::Bdv6 | Opcode::Cdv7
+        )
+    }
+}
+
 #[derive(Debug, Clone, Copy)]
 enum Operand {
     Zero,
@@ -95,27 +112,34 @@ enum Stdout {
     Terminated,
 }
 
-impl From<&str> for Computer {
-    fn from(value: &str) -> Self {
-        let lines = value.lines().collect::<Vec<&str>>();
-        let a = lines[0].split_whitespace().last().unwrap().parse().unwrap();
-        let b = lines[1].split_whitespace().last().unwrap().parse().unwrap();
-        let c = lines[2].split_whitespace().last().unwrap().parse().unwrap();
-        let programme_numbers = lines[4]
-            .split_whitespace()
-            .last()
-            .unwrap()
-            .split(',')
-            .map(|x| x.parse().unwrap())
-            .collect::<Vec<u64>>();
-        let instructions = programme_numbers
-            .clone()
-            .chunks(2)
-            .map(|x| Instruction {
-                opcode: Opcode::from(x[0]),
-                operand: Operand::from(x[1]),
-            })
-            .collect::<Vec<Instruction>>();
+fn parse_computer(input: &str) -> IResult<'_, Computer> {
+    let register = |name| terminated(labelled(name, unsigned), line_ending);
+    // Every instruction is an opcode and an operand, each of them 3 bits, and the combo operand 7
+    // is reserved
+    let programme = verify(
+        comma_list(verify(unsigned, |n: &u64| *n < 8)),
+        |x: &Vec<u64>| {
+            x.len().is_multiple_of(2)
+                && x.chunks(2)
+                    .all(|i| !Opcode::from(i[0]).takes_combo_operand() || i[1] != 7)
+        },
+    );
+    let (input, (a, b, c, _, programme_numbers)) = tuple((
+        register("Register A"),
+        register("Register B"),
+        register("Register C"),
+        line_ending,
+        labelled("Program", programme),
+    ))(input)?;
+    let instructions = programme_numbers
+        .chunks(2)
+        .map(|x| Instruction {
+            opcode: Opcode::from(x[0]),
+            operand: Operand::from(x[1]),
+        })
+        .collect::<Vec<Instruction>>();
+    Ok((
+        input,
         Computer {
             a,
             b,
@@ -123,8 +147,8 @@ impl From<&str> for Computer {
             instructions,
             pointer: 0,
             programme_numbers,
-        }
-    }
+        },
+    ))
 }
 
 impl Computer {
@@ -137,7 +161,7 @@ impl Computer {
             Operand::Four => self.a,
             Operand::Five => self.b,
             Operand::Six => self.c,
-            Operand::Seven => panic!("The instructions said this should not appear"),
+            Operand::Seven => unreachable!("parse_computer rejects the combo operand 7"),
         }
     }
 
@@ -166,7 +190,7 @@ impl Computer {
                         if self.a == 0 {
                             self.pointer += 1;
                         } else {
-                            assert!(i.operand as usize % 2 == 0);
+                            assert!((i.operand as usize).is_multiple_of(2));
                             self.pointer = i.operand as usize / 2;
                         }
                         Stdout::Nothing
@@ -259,23 +283,23 @@ impl Computer {
     }
 }
 
-pub fn part_one(input: &str) -> Option<String> {
-    let mut computer = Computer::from(input);
-    Some(
-        computer
-            .step_until_terminated()
-            .iter()
-            .map(|x| x.to_string())
-            .collect::<Vec<String>>()
-            .join(","),
-    )
+pub fn part_one(input: &str) -> Result<String, AocError> {
+    let mut computer = parse_all(input, parse_computer)?;
+    Ok(computer
+        .step_until_terminated()
+        .iter()
+        .map(|x| x.to_string())
+        .collect::<Vec<String>>()
+        .join(","))
 }
 
-pub fn part_two(input: &str) -> Option<u64> {
-    let mut computer = Computer::from(input);
+pub fn part_two(input: &str) -> Result<u64, AocError> {
+    let mut computer = parse_all(input, parse_computer)?;
     let mut reverse_outputs = computer.programme_numbers.clone();
     reverse_outputs.reverse();
-    computer.find_solution(&reverse_outputs, 0)
+    computer
+        .find_solution(&reverse_outputs, 0)
+        .ok_or_else(|| AocError::unsolvable("No value of register A outputs the programme"))
 }
 
 #[cfg(test)]
@@ -284,15 +308,21 @@ mod tests {
 
     #[test]
     fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, Some("4,6,3,5,6,3,5,2,1,0".to_owned()));
+        let result = part_one(&advent_of_code::template::read_file("examples", YEAR, DAY));
+        assert_eq!(result, Ok("4,6,3,5,6,3,5,2,1,0".to_owned()));
     }
 
     #[test]
     fn test_part_two() {
         let result = part_two(&advent_of_code::template::read_file_part(
-            "examples", DAY, 2,
+            "examples", YEAR, DAY, 2,
         ));
-        assert_eq!(result, Some(117440));
+        assert_eq!(result, Ok(117440));
+    }
+
+    #[test]
+    fn test_reserved_combo_operand() {
+        let input = "Register A: 1\nRegister B: 0\nRegister C: 0\n\nProgram: 5,7";
+        assert!(matches!(part_one(input), Err(AocError::Parse(_))));
     }
 }
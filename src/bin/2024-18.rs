@@ -0,0 +1,115 @@
+advent_of_code::solution!(2024, 18);
+
+use advent_of_code::grid::{Grid, Pos};
+use advent_of_code::pathfinding::{astar, bfs};
+
+// The squares that can be walked to from `pos`
+fn get_adjacents(corrupted: &Grid<bool>, pos: Pos) -> impl Iterator<Item = Pos> + '_ {
+    corrupted.neighbours(pos).filter(|adj| !corrupted[*adj])
+}
+
+// NOTE: This output is transposed relative to the problem description
+fn _pretty_print(corrupted: &Grid<bool>) {
+    println!("{}", corrupted.map(|c| if *c { '#' } else { '.' }));
+}
+
+fn parse_input(input: &str, max_i: usize, max_j: usize, n_steps: usize) -> Grid<bool> {
+    // Mark the corrupted points
+    let mut corrupted = Grid::new(max_j + 1, max_i + 1, false);
+    input
+        .lines()
+        .map(|line| {
+            let nums = line
+                .split(',')
+                .map(|num| num.parse().unwrap())
+                .collect::<Vec<usize>>();
+            assert_eq!(nums.len(), 2);
+            (nums[0], nums[1])
+        })
+        .take(n_steps)
+        .for_each(|point| corrupted[point] = true);
+    corrupted
+}
+
+fn get_nth_byte(input: &str, n: usize) -> Pos {
+    input
+        .lines()
+        .map(|line| {
+            let nums = line
+                .split(',')
+                .map(|num| num.parse().unwrap())
+                .collect::<Vec<usize>>();
+            assert_eq!(nums.len(), 2);
+            (nums[0], nums[1])
+        })
+        .nth(n)
+        .expect("Could not find nth byte")
+}
+
+pub fn part_one(input: &str) -> Option<u32> {
+    // let corrupted = parse_input(input, 6, 6, 12);
+    // _pretty_print(&corrupted);
+    let (max_i, max_j) = (70, 70);
+    let corrupted = parse_input(input, max_i, max_j, 1024);
+    // Each step has a weight of 1, and the manhattan distance to the exit never overestimates
+    let (_, distance) = astar(
+        (0, 0),
+        |pos| get_adjacents(&corrupted, *pos).map(|adj| (adj, 1)),
+        |pos| (max_i - pos.0 + max_j - pos.1) as u32,
+        |pos| *pos == (max_i, max_j),
+    )
+    .expect("Could not reach target node");
+    Some(distance)
+}
+
+pub fn part_two(input: &str) -> Option<String> {
+    let max_i = 70;
+    let max_j = 70;
+    let mut min = 0;
+    // Technically the maximum should be the number of lines in the input, which is less than this,
+    // but because we're using `.take()` it's fine
+    let mut max = (max_i + 1) * (max_j + 1);
+    let mut guess = (min + max) / 2;
+
+    // Binary search between the interval [min, max]
+    while min <= max {
+        guess = (min + max) / 2;
+        let corrupted = parse_input(input, max_i, max_j, guess);
+        let can_reach =
+            bfs((0, 0), |pos| get_adjacents(&corrupted, *pos)).is_reachable(&(max_i, max_j));
+        println!(
+            "min={} max={} guess={} can_reach={}",
+            min, max, guess, can_reach
+        );
+        if can_reach {
+            // Not high enough, because there's still a path
+            min = guess + 1;
+        } else {
+            // Too high
+            max = guess - 1;
+        }
+    }
+
+    // `guess` is the minimum number of bytes to block the path, so if we index the bytes from 0,
+    // the first byte that blocks the path is `guess - 1`
+    let bytes = get_nth_byte(input, guess - 1);
+    let bytes_str = format!("{},{}", bytes.0, bytes.1);
+    Some(bytes_str)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        let result = part_one(&advent_of_code::template::read_file("examples", YEAR, DAY));
+        assert_eq!(result, Some(22));
+    }
+
+    #[test]
+    fn test_part_two() {
+        let result = part_two(&advent_of_code::template::read_file("examples", YEAR, DAY));
+        assert_eq!(result, Some("6,1".to_string()));
+    }
+}
@@ -0,0 +1,128 @@
+advent_of_code::solution!(2024, 20);
+
+use std::collections::BTreeMap;
+
+use advent_of_code::error::AocError;
+use advent_of_code::grid::{Grid, Pos};
+use advent_of_code::pathfinding::bfs;
+
+struct Track {
+    // Whether each square is part of the track
+    open: Grid<bool>,
+    start_node: Pos,
+    end_node: Pos,
+}
+
+impl Track {
+    fn get_cheats_with_manhattan<F: Fn(u32) -> bool>(
+        self,
+        manhattan_filter: F,
+    ) -> Result<BTreeMap<u32, u32>, AocError> {
+        // Minimum distance from the start to every point, and from every point to the end
+        let neighbours = |node: &Pos| {
+            self.open
+                .neighbours(*node)
+                .filter(|adjacent| self.open[*adjacent])
+        };
+        let fpaths = bfs(self.start_node, neighbours);
+        let rpaths = bfs(self.end_node, neighbours);
+
+        let original_solution = fpaths
+            .distance(&self.end_node)
+            .ok_or_else(|| AocError::unsolvable("The end point cannot be reached"))?;
+
+        // Construct mapping of (time saved, number of choices)
+        let mut cheats = BTreeMap::<u32, u32>::new();
+        // Iterate over the reachable points in the forward search.
+        for (fnode, fdist) in fpaths.distances() {
+            // Get all reachable points in the reverse search
+            for (rnode, rdist) in rpaths.distances() {
+                let manhattan = manhattan(fnode, rnode) as u32;
+                if manhattan_filter(manhattan) {
+                    // Otherwise, calculate the time saved
+                    let cheating_time = fdist + rdist + manhattan;
+                    if cheating_time < original_solution {
+                        let timesave = original_solution - cheating_time;
+                        cheats.entry(timesave).and_modify(|e| *e += 1).or_insert(1);
+                    }
+                }
+            }
+        }
+        Ok(cheats)
+    }
+}
+
+fn manhattan(a: &Pos, b: &Pos) -> usize {
+    a.0.abs_diff(b.0) + a.1.abs_diff(b.1)
+}
+
+// Input parsing
+impl TryFrom<&str> for Track {
+    type Error = AocError;
+
+    fn try_from(input: &str) -> Result<Self, Self::Error> {
+        // Parse nodes from the input string
+        let grid = Grid::try_parse(input, |c| "#.ES".contains(c).then_some(c))?;
+        let start_node = grid
+            .find(|c| *c == 'S')
+            .ok_or_else(|| AocError::invalid("No start point found"))?;
+        let end_node = grid
+            .find(|c| *c == 'E')
+            .ok_or_else(|| AocError::invalid("No end point found"))?;
+
+        Ok(Track {
+            open: grid.map(|c| *c != '#'),
+            start_node,
+            end_node,
+        })
+    }
+}
+
+pub fn part_one(input: &str) -> Result<u32, AocError> {
+    let track = Track::try_from(input)?;
+    let cheats = track.get_cheats_with_manhattan(|m| m == 2)?;
+    Ok(cheats
+        .iter()
+        .filter(|(time, _)| **time >= 100)
+        .map(|(_, choices)| choices)
+        .sum())
+}
+
+pub fn part_two(input: &str) -> Result<u32, AocError> {
+    let track = Track::try_from(input)?;
+    let cheats = track.get_cheats_with_manhattan(|m| m <= 20)?;
+    Ok(cheats
+        .iter()
+        .filter(|(time, _)| **time >= 100)
+        .map(|(_, choices)| choices)
+        .sum())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        let result = part_one(&advent_of_code::template::read_file("examples", YEAR, DAY));
+        assert_eq!(result, Ok(0));
+    }
+
+    #[test]
+    fn test_part_two() {
+        let result = part_two(&advent_of_code::template::read_file("examples", YEAR, DAY));
+        assert_eq!(result, Ok(0));
+    }
+
+    #[test]
+    fn test_invalid_input() {
+        assert_eq!(
+            part_one("#E#\n#.#"),
+            Err(AocError::invalid("No start point found"))
+        );
+        assert_eq!(
+            part_one("S#E"),
+            Err(AocError::unsolvable("The end point cannot be reached"))
+        );
+    }
+}
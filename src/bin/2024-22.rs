@@ -1,4 +1,4 @@
-advent_of_code::solution!(22);
+advent_of_code::solution!(2024, 22);
 
 use std::collections::HashMap;
 
@@ -87,13 +87,13 @@ mod tests {
 
     #[test]
     fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
+        let result = part_one(&advent_of_code::template::read_file("examples", YEAR, DAY));
         assert_eq!(result, Some(37327623));
     }
 
     #[test]
     fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file_part("examples", DAY, 2));
+        let result = part_two(&advent_of_code::template::read_file_part("examples", YEAR, DAY, 2));
         assert_eq!(result, Some(23));
     }
 }
@@ -1,4 +1,4 @@
-advent_of_code::solution!(23);
+advent_of_code::solution!(2024, 23);
 
 use std::collections::{BTreeSet, HashMap, HashSet};
 
@@ -230,13 +230,13 @@ mod tests {
 
     #[test]
     fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
+        let result = part_one(&advent_of_code::template::read_file("examples", YEAR, DAY));
         assert_eq!(result, Some(7));
     }
 
     #[test]
     fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
+        let result = part_two(&advent_of_code::template::read_file("examples", YEAR, DAY));
         assert_eq!(result, Some("co,de,ka,ta".to_string()));
     }
 }
@@ -1,5 +1,14 @@
-advent_of_code::solution!(24);
+advent_of_code::solution!(2024, 24);
 
+use advent_of_code::error::AocError;
+use advent_of_code::parse::{blank_line, lines_of, parse_all};
+use nom::{
+    branch::alt,
+    bytes::complete::tag,
+    character::complete::{alphanumeric1, one_of, space1},
+    combinator::value,
+    sequence::{preceded, separated_pair, terminated, tuple},
+};
 use regex::Regex;
 use std::collections::HashMap;
 
@@ -159,48 +168,63 @@ impl GlobalState {
     }
 }
 
-impl From<&str> for GlobalState {
-    fn from(input: &str) -> GlobalState {
-        let mut state = HashMap::new();
-        if let [values, exprs] = input.split("\n\n").collect::<Vec<_>>()[..] {
-            for line in values.lines() {
-                let parts = line.split(": ").collect::<Vec<_>>();
-                let name = parts[0].to_string();
-                let value = parts[1].parse::<u16>().unwrap(); // Rust doesn't parse '0' and '1' as bools
-                state.insert(name.clone(), State::Value(value == 1, name));
-            }
-            for line in exprs.lines() {
-                let parts = line.split_whitespace().collect::<Vec<_>>();
-                let in1 = parts[0].to_string();
-                let op = match parts[1] {
-                    "AND" => Op::And,
-                    "OR" => Op::Or,
-                    "XOR" => Op::Xor,
-                    _ => panic!("Unknown op"),
-                };
-                let in2 = parts[2].to_string();
-                let name = parts[4].to_string();
-                state.insert(name, State::Expr(Expr { op, in1, in2 }));
-            }
+fn parse_input(input: &str) -> Result<GlobalState, AocError> {
+    let wire = separated_pair(alphanumeric1, tag(": "), one_of("01"));
+    let op = alt((
+        value(Op::And, tag("AND")),
+        value(Op::Or, tag("OR")),
+        value(Op::Xor, tag("XOR")),
+    ));
+    let gate = tuple((
+        terminated(alphanumeric1, space1),
+        terminated(op, space1),
+        alphanumeric1,
+        preceded(tag(" -> "), alphanumeric1),
+    ));
+    let (values, exprs) = parse_all(
+        input,
+        separated_pair(lines_of(wire), blank_line, lines_of(gate)),
+    )?;
+
+    let mut state = HashMap::new();
+    for (name, value) in values {
+        state.insert(name.to_string(), State::Value(value == '1', name.to_string()));
+    }
+    for (in1, op, in2, name) in &exprs {
+        let expr = Expr {
+            op: op.clone(),
+            in1: in1.to_string(),
+            in2: in2.to_string(),
+        };
+        state.insert(name.to_string(), State::Expr(expr));
+    }
+    // Every gate must read wires that have a value or are the output of another gate
+    for (in1, _, in2, _) in exprs {
+        if let Some(unknown) = [in1, in2].into_iter().find(|w| !state.contains_key(*w)) {
+            return Err(AocError::parse_at(
+                input,
+                unknown,
+                format!("Unknown wire {unknown:?}"),
+            ));
         }
-        GlobalState { n_ops: 0, state }
     }
+    Ok(GlobalState { n_ops: 0, state })
 }
 
-pub fn part_one(input: &str) -> Option<u64> {
-    let mut global_state = GlobalState::from(input);
+pub fn part_one(input: &str) -> Result<u64, AocError> {
+    let mut global_state = parse_input(input)?;
     global_state.eval();
     // println!("{} evaluations", global_state.n_ops);
     // global_state._pretty_print(false);
-    Some(global_state.z_bits())
+    Ok(global_state.z_bits())
 }
 
-pub fn part_two(input: &str) -> Option<String> {
-    let mut global_state = GlobalState::from(input);
+pub fn part_two(input: &str) -> Result<String, AocError> {
+    let mut global_state = parse_input(input)?;
     global_state.eval();
     global_state._pretty_print(true);
     // Proof by inspection
-    Some("cgh,frt,pmd,sps,tst,z05,z11,z23".to_string())
+    Ok("cgh,frt,pmd,sps,tst,z05,z11,z23".to_string())
 }
 
 #[cfg(test)]
@@ -209,15 +233,24 @@ mod tests {
 
     #[test]
     fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, Some(4));
+        let result = part_one(&advent_of_code::template::read_file("examples", YEAR, DAY));
+        assert_eq!(result, Ok(4));
+    }
+
+    #[test]
+    fn test_invalid_input() {
+        let error = part_one("x00: 1\n\nx00 AND y00 -> z00").unwrap_err();
+        assert_eq!(error.location(), Some((3, 9)));
+        assert_eq!(error.message(), "Unknown wire \"y00\"");
+        let error = part_one("x00: 1\n\nx00 NAND x00 -> z00").unwrap_err();
+        assert_eq!(error.location(), Some((3, 5)));
     }
 
     #[test]
     fn test_part_two() {
         let result = part_two(&advent_of_code::template::read_file_part(
-            "examples", DAY, 2,
+            "examples", YEAR, DAY, 2,
         ));
-        assert_eq!(result, Some("z00,z01,z02,z05".to_string()));
+        assert_eq!(result, Ok("z00,z01,z02,z05".to_string()));
     }
 }
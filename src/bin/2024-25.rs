@@ -0,0 +1,80 @@
+advent_of_code::solution!(2024, 25);
+
+use advent_of_code::error::AocError;
+use advent_of_code::parse::{blocks_of, lines_of, parse_all, ParseError};
+use itertools::iproduct;
+use nom::{character::complete::one_of, combinator::verify, multi::many1};
+use std::collections::HashSet;
+
+#[derive(PartialEq, Eq, Hash)]
+struct Lock {
+    lengths: Vec<u32>,
+}
+
+#[derive(PartialEq, Eq, Hash)]
+struct Key {
+    lengths: Vec<u32>,
+}
+
+// The length of each column, not counting the full row at the top of a lock or the bottom of a key
+fn get_lengths(rows: &[Vec<char>]) -> Vec<u32> {
+    (0..rows[0].len())
+        .map(|i| rows.iter().filter(|row| row[i] == '#').count() as u32 - 1)
+        .collect()
+}
+
+fn fit(lock: &Lock, key: &Key) -> bool {
+    for (l, k) in lock.lengths.iter().zip(key.lengths.iter()) {
+        if l + k > 5 {
+            return false;
+        }
+    }
+    true
+}
+
+fn parse_input(input: &str) -> Result<(HashSet<Lock>, HashSet<Key>), ParseError> {
+    let schematic = verify(lines_of(many1(one_of("#."))), |rows: &Vec<Vec<char>>| {
+        rows.iter().all(|row| row.len() == rows[0].len())
+    });
+    let schematics = parse_all(input, blocks_of(schematic))?;
+
+    let mut locks = HashSet::new();
+    let mut keys = HashSet::new();
+    for rows in schematics {
+        let lengths = get_lengths(&rows);
+        if rows[0][0] == '#' {
+            locks.insert(Lock { lengths });
+        } else {
+            keys.insert(Key { lengths });
+        }
+    }
+    Ok((locks, keys))
+}
+
+pub fn part_one(input: &str) -> Result<u32, AocError> {
+    let (locks, keys) = parse_input(input)?;
+    Ok(iproduct!(locks.iter(), keys.iter())
+        .filter(|(lock, key)| fit(lock, key))
+        .count() as u32)
+}
+
+pub fn part_two(_input: &str) -> Option<u32> {
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        let result = part_one(&advent_of_code::template::read_file("examples", YEAR, DAY));
+        assert_eq!(result, Ok(3));
+    }
+
+    #[test]
+    fn test_part_two() {
+        let result = part_two(&advent_of_code::template::read_file("examples", YEAR, DAY));
+        assert_eq!(result, None);
+    }
+}
@@ -0,0 +1,131 @@
+/// Errors of solution parts that cannot answer for their input.
+///
+/// A part can return `Result<T, AocError>` instead of `Option<T>`, and the runner reports the
+/// error with the day, the part and where in the input it happened, rather than a raw panic.
+use std::fmt::{self, Display};
+
+use crate::parse::ParseError;
+
+/// Why a part has no answer for its input.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum AocError {
+    /// The input is not in the format of the puzzle.
+    Parse(ParseError),
+    /// The input parsed, but breaks a rule of the puzzle, e.g. a map without a start point.
+    Invalid(String),
+    /// The input is valid, but there is no answer for it, e.g. an unreachable exit.
+    Unsolvable(String),
+}
+
+impl AocError {
+    /// A parse error at `at`, a slice of `input` that starts where the error is.
+    pub fn parse_at(input: &str, at: &str, message: impl Into<String>) -> Self {
+        AocError::Parse(ParseError::at(input, at, message))
+    }
+
+    pub fn invalid(message: impl Into<String>) -> Self {
+        AocError::Invalid(message.into())
+    }
+
+    pub fn unsolvable(message: impl Into<String>) -> Self {
+        AocError::Unsolvable(message.into())
+    }
+
+    /// Short description of the kind of error, e.g. `invalid input`.
+    pub fn kind(&self) -> &'static str {
+        match self {
+            AocError::Parse(_) => "parse error",
+            AocError::Invalid(_) => "invalid input",
+            AocError::Unsolvable(_) => "no solution",
+        }
+    }
+
+    pub fn message(&self) -> &str {
+        match self {
+            AocError::Parse(e) => &e.message,
+            AocError::Invalid(message) | AocError::Unsolvable(message) => message,
+        }
+    }
+
+    /// Line and column of the error in the input, if it is known.
+    pub fn location(&self) -> Option<(usize, usize)> {
+        match self {
+            AocError::Parse(e) => Some((e.line, e.column)),
+            AocError::Invalid(_) | AocError::Unsolvable(_) => None,
+        }
+    }
+
+    /// The error for an input read from `source`, e.g.
+    /// `./data/2024/inputs/06.txt:3:7: parse error: unexpected "x"`.
+    pub fn report(&self, source: &str) -> String {
+        match self.location() {
+            Some((line, column)) => {
+                format!(
+                    "{source}:{line}:{column}: {}: {}",
+                    self.kind(),
+                    self.message()
+                )
+            }
+            None => format!("{source}: {}: {}", self.kind(), self.message()),
+        }
+    }
+}
+
+impl Display for AocError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            AocError::Parse(e) => write!(f, "{}: {e}", self.kind()),
+            AocError::Invalid(message) | AocError::Unsolvable(message) => {
+                write!(f, "{}: {message}", self.kind())
+            }
+        }
+    }
+}
+
+impl std::error::Error for AocError {}
+
+impl From<ParseError> for AocError {
+    fn from(value: ParseError) -> Self {
+        AocError::Parse(value)
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::AocError;
+    use crate::parse::{parse_all, unsigned};
+
+    #[test]
+    fn describes_errors() {
+        let error = AocError::from(parse_all("1\nx", unsigned::<u32>).unwrap_err());
+        assert_eq!(error.location(), Some((2, 1)));
+        assert_eq!(
+            error.to_string(),
+            "parse error: line 2, column 1: expected the end of the input, found \"x\""
+        );
+
+        let error = AocError::invalid("No start point found");
+        assert_eq!(error.location(), None);
+        assert_eq!(error.to_string(), "invalid input: No start point found");
+        assert_eq!(
+            AocError::unsolvable("No path").to_string(),
+            "no solution: No path"
+        );
+    }
+
+    #[test]
+    fn reports_errors_with_their_source() {
+        let input = "..#\n.x.";
+        let error = AocError::parse_at(input, &input[5..], "Invalid character 'x'");
+        assert_eq!(
+            error.report("06.txt"),
+            "06.txt:2:2: parse error: Invalid character 'x'"
+        );
+        assert_eq!(
+            AocError::invalid("No robot found").report("15.txt"),
+            "15.txt: invalid input: No robot found"
+        );
+    }
+}
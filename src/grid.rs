@@ -0,0 +1,480 @@
+/// A rectangular grid of cells, as found in many puzzle inputs.
+///
+/// Positions are `(row, col)` pairs, with `(0, 0)` in the top left corner. Moving off the grid is
+/// never an error or an underflow: [`Grid::step`] and the neighbour iterators only yield positions
+/// inside the grid.
+use std::{
+    fmt::{self, Display},
+    ops::{Index, IndexMut},
+};
+
+use crate::parse::ParseError;
+
+/// Position of a cell as `(row, col)`.
+pub type Pos = (usize, usize);
+
+/// One of the eight directions on a grid. North is up, towards row 0.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Direction {
+    N,
+    NE,
+    E,
+    SE,
+    S,
+    SW,
+    W,
+    NW,
+}
+
+impl Direction {
+    /// The four orthogonal directions, clockwise from north.
+    pub const ORTHOGONAL: [Direction; 4] = [Direction::N, Direction::E, Direction::S, Direction::W];
+
+    /// All eight directions, clockwise from north.
+    pub const ALL: [Direction; 8] = [
+        Direction::N,
+        Direction::NE,
+        Direction::E,
+        Direction::SE,
+        Direction::S,
+        Direction::SW,
+        Direction::W,
+        Direction::NW,
+    ];
+
+    /// Direction of an arrow, `^`, `>`, `v` or `<`.
+    pub fn from_arrow(c: char) -> Option<Self> {
+        match c {
+            '^' => Some(Direction::N),
+            '>' => Some(Direction::E),
+            'v' => Some(Direction::S),
+            '<' => Some(Direction::W),
+            _ => None,
+        }
+    }
+
+    /// Change in `(row, col)` of a single step.
+    pub fn offset(self) -> (isize, isize) {
+        match self {
+            Direction::N => (-1, 0),
+            Direction::NE => (-1, 1),
+            Direction::E => (0, 1),
+            Direction::SE => (1, 1),
+            Direction::S => (1, 0),
+            Direction::SW => (1, -1),
+            Direction::W => (0, -1),
+            Direction::NW => (-1, -1),
+        }
+    }
+
+    /// Whether the direction is one of the four [`Direction::ORTHOGONAL`] ones.
+    pub fn is_orthogonal(self) -> bool {
+        matches!(
+            self,
+            Direction::N | Direction::E | Direction::S | Direction::W
+        )
+    }
+
+    /// The direction after turning 90 degrees clockwise.
+    #[must_use]
+    pub fn turn_right(self) -> Self {
+        Direction::ALL[(self as usize + 2) % 8]
+    }
+
+    /// The direction after turning 90 degrees anticlockwise.
+    #[must_use]
+    pub fn turn_left(self) -> Self {
+        Direction::ALL[(self as usize + 6) % 8]
+    }
+
+    #[must_use]
+    pub fn opposite(self) -> Self {
+        Direction::ALL[(self as usize + 4) % 8]
+    }
+}
+
+/// A rectangular grid of cells, stored row by row.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    cells: Vec<T>,
+    width: usize,
+    height: usize,
+}
+
+impl<T> Grid<T> {
+    /// A grid of `height` rows and `width` columns, with every cell set to `fill`.
+    pub fn new(width: usize, height: usize, fill: T) -> Self
+    where
+        T: Clone,
+    {
+        Grid {
+            cells: vec![fill; width * height],
+            width,
+            height,
+        }
+    }
+
+    /// Parse a grid with a line per row, mapping every character to a cell.
+    ///
+    /// # Panics
+    ///
+    /// If the lines do not all have the same length.
+    pub fn parse(input: &str, mut cell: impl FnMut(char) -> T) -> Self {
+        let rows: Vec<Vec<T>> = input
+            .lines()
+            .map(|line| line.chars().map(&mut cell).collect())
+            .collect();
+        Grid::from_rows(rows)
+    }
+
+    /// Parse a grid like [`Grid::parse`], where `cell` returns `None` for characters that are not
+    /// allowed. Invalid characters and rows of a different length are reported where they are.
+    pub fn try_parse(
+        input: &str,
+        mut cell: impl FnMut(char) -> Option<T>,
+    ) -> Result<Self, ParseError> {
+        let mut cells = vec![];
+        let mut width = None;
+        let mut height = 0;
+
+        for (row, line) in input.lines().enumerate() {
+            let len = cells.len();
+            for (col, c) in line.chars().enumerate() {
+                let value = cell(c).ok_or_else(|| {
+                    ParseError::at_pos((row, col), format!("invalid character {c:?}"))
+                })?;
+                cells.push(value);
+            }
+            let row_width = cells.len() - len;
+            let expected = *width.get_or_insert(row_width);
+            if row_width != expected {
+                return Err(ParseError::at_pos(
+                    (row, row_width.min(expected)),
+                    format!("expected a row of {expected} cells, found {row_width}"),
+                ));
+            }
+            height += 1;
+        }
+
+        Ok(Grid {
+            cells,
+            width: width.unwrap_or_default(),
+            height,
+        })
+    }
+
+    /// Build a grid from its rows.
+    ///
+    /// # Panics
+    ///
+    /// If the rows do not all have the same length.
+    pub fn from_rows<R: IntoIterator<Item = T>>(rows: impl IntoIterator<Item = R>) -> Self {
+        let mut cells = vec![];
+        let mut width = None;
+        let mut height = 0;
+
+        for row in rows {
+            let len = cells.len();
+            cells.extend(row);
+            let row_width = cells.len() - len;
+            assert!(
+                *width.get_or_insert(row_width) == row_width,
+                "row {height} of the grid has {row_width} cells instead of {}",
+                width.unwrap_or_default()
+            );
+            height += 1;
+        }
+
+        Grid {
+            cells,
+            width: width.unwrap_or_default(),
+            height,
+        }
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn in_bounds(&self, (row, col): Pos) -> bool {
+        row < self.height && col < self.width
+    }
+
+    pub fn get(&self, pos: Pos) -> Option<&T> {
+        self.in_bounds(pos)
+            .then(|| &self.cells[pos.0 * self.width + pos.1])
+    }
+
+    pub fn get_mut(&mut self, pos: Pos) -> Option<&mut T> {
+        if self.in_bounds(pos) {
+            Some(&mut self.cells[pos.0 * self.width + pos.1])
+        } else {
+            None
+        }
+    }
+
+    /// The position `(rows, cols)` away from `pos`, if it is inside the grid.
+    pub fn offset(&self, (row, col): Pos, (rows, cols): (isize, isize)) -> Option<Pos> {
+        let pos = (row.checked_add_signed(rows)?, col.checked_add_signed(cols)?);
+        self.in_bounds(pos).then_some(pos)
+    }
+
+    /// The position a step from `pos` in `direction`, if it is inside the grid.
+    pub fn step(&self, pos: Pos, direction: Direction) -> Option<Pos> {
+        self.offset(pos, direction.offset())
+    }
+
+    /// The positions from `pos` in `direction` up to the edge of the grid, without `pos` itself.
+    pub fn ray(&self, pos: Pos, direction: Direction) -> impl Iterator<Item = Pos> + '_ {
+        std::iter::successors(self.step(pos, direction), move |&pos| {
+            self.step(pos, direction)
+        })
+    }
+
+    /// The orthogonal neighbours of `pos` inside the grid.
+    pub fn neighbours(&self, pos: Pos) -> impl Iterator<Item = Pos> + '_ {
+        Direction::ORTHOGONAL
+            .into_iter()
+            .filter_map(move |direction| self.step(pos, direction))
+    }
+
+    /// The orthogonal and diagonal neighbours of `pos` inside the grid.
+    pub fn neighbours8(&self, pos: Pos) -> impl Iterator<Item = Pos> + '_ {
+        Direction::ALL
+            .into_iter()
+            .filter_map(move |direction| self.step(pos, direction))
+    }
+
+    /// All positions of the grid, row by row.
+    pub fn positions(&self) -> impl Iterator<Item = Pos> {
+        let width = self.width;
+        (0..self.height).flat_map(move |row| (0..width).map(move |col| (row, col)))
+    }
+
+    /// All cells with their positions, row by row.
+    pub fn iter(&self) -> impl Iterator<Item = (Pos, &T)> {
+        self.positions().zip(self.cells.iter())
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        // NOTE: `chunks` panics for a size of 0, and an empty grid has no rows anyway.
+        self.cells.chunks(self.width.max(1)).take(self.height)
+    }
+
+    /// Position of the first cell matching `predicate`, row by row.
+    pub fn find(&self, predicate: impl Fn(&T) -> bool) -> Option<Pos> {
+        self.iter()
+            .find_map(|(pos, cell)| predicate(cell).then_some(pos))
+    }
+
+    /// Positions of all cells matching `predicate`, row by row.
+    pub fn find_all<'a>(
+        &'a self,
+        predicate: impl Fn(&T) -> bool + 'a,
+    ) -> impl Iterator<Item = Pos> + 'a {
+        self.iter()
+            .filter_map(move |(pos, cell)| predicate(cell).then_some(pos))
+    }
+
+    /// A grid of the same size, with `f` applied to every cell.
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid {
+            cells: self.cells.iter().map(f).collect(),
+            width: self.width,
+            height: self.height,
+        }
+    }
+
+    /// The grid mirrored along its main diagonal, so rows become columns.
+    #[must_use]
+    pub fn transpose(&self) -> Self
+    where
+        T: Clone,
+    {
+        self.rearrange(self.height, self.width, |(row, col)| (col, row))
+    }
+
+    /// The grid rotated 90 degrees clockwise.
+    #[must_use]
+    pub fn rotate_right(&self) -> Self
+    where
+        T: Clone,
+    {
+        let height = self.height;
+        self.rearrange(height, self.width, |(row, col)| (height - 1 - col, row))
+    }
+
+    /// The grid rotated 90 degrees anticlockwise.
+    #[must_use]
+    pub fn rotate_left(&self) -> Self
+    where
+        T: Clone,
+    {
+        let width = self.width;
+        self.rearrange(self.height, width, |(row, col)| (col, width - 1 - row))
+    }
+
+    /// A grid of `width` by `height` whose cell at `pos` is the cell of this grid at `source(pos)`.
+    fn rearrange(&self, width: usize, height: usize, source: impl Fn(Pos) -> Pos) -> Self
+    where
+        T: Clone,
+    {
+        Grid::from_rows((0..height).map(|row| {
+            (0..width)
+                .map(move |col| (row, col))
+                .map(|pos| self[source(pos)].clone())
+        }))
+    }
+}
+
+impl<T> Index<Pos> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, pos: Pos) -> &T {
+        self.get(pos).unwrap_or_else(|| {
+            panic!(
+                "{pos:?} is outside of the {}x{} grid",
+                self.width, self.height
+            )
+        })
+    }
+}
+
+impl<T> IndexMut<Pos> for Grid<T> {
+    fn index_mut(&mut self, pos: Pos) -> &mut T {
+        let (width, height) = (self.width, self.height);
+        self.get_mut(pos)
+            .unwrap_or_else(|| panic!("{pos:?} is outside of the {width}x{height} grid"))
+    }
+}
+
+/// Formats the grid with a line per row, e.g. to print it while debugging.
+impl<T: Display> Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (index, row) in self.rows().enumerate() {
+            if index > 0 {
+                writeln!(f)?;
+            }
+            for cell in row {
+                write!(f, "{cell}")?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{Direction, Grid};
+    use crate::parse::ParseError;
+
+    const EXAMPLE: &str = "abc\ndef\n";
+
+    #[test]
+    fn parses_and_displays() {
+        let grid = Grid::parse(EXAMPLE, |c| c);
+        assert_eq!((grid.width(), grid.height()), (3, 2));
+        assert_eq!(grid[(1, 0)], 'd');
+        assert_eq!(grid.get((2, 0)), None);
+        assert_eq!(grid.to_string(), "abc\ndef");
+
+        let digits = Grid::parse("12\n34", |c| c.to_digit(10).unwrap());
+        assert_eq!(digits[(1, 1)], 4);
+    }
+
+    #[test]
+    #[should_panic]
+    fn panics_for_ragged_rows() {
+        Grid::parse("ab\nc", |c| c);
+    }
+
+    #[test]
+    fn reports_invalid_cells() {
+        let digit = |c: char| c.to_digit(10);
+        assert_eq!(
+            Grid::try_parse("12\n34\n", digit).map(|grid| grid[(1, 0)]),
+            Ok(3)
+        );
+        assert_eq!(
+            Grid::try_parse("12\n3x", digit),
+            Err(ParseError {
+                line: 2,
+                column: 2,
+                message: "invalid character 'x'".into()
+            })
+        );
+        let error = Grid::try_parse("123\n4", digit).unwrap_err();
+        assert_eq!((error.line, error.column), (2, 2));
+        assert_eq!(error.message, "expected a row of 3 cells, found 1");
+    }
+
+    #[test]
+    fn steps_within_bounds() {
+        let grid = Grid::parse(EXAMPLE, |c| c);
+        assert_eq!(grid.step((0, 0), Direction::N), None);
+        assert_eq!(grid.step((0, 0), Direction::W), None);
+        assert_eq!(grid.step((0, 0), Direction::SE), Some((1, 1)));
+        assert_eq!(grid.offset((1, 2), (-1, -2)), Some((0, 0)));
+        assert_eq!(grid.offset((1, 2), (0, 1)), None);
+        assert_eq!(
+            grid.ray((0, 0), Direction::E).collect::<Vec<_>>(),
+            vec![(0, 1), (0, 2)]
+        );
+    }
+
+    #[test]
+    fn iterates_neighbours() {
+        let grid = Grid::parse(EXAMPLE, |c| c);
+        assert_eq!(
+            grid.neighbours((0, 0)).collect::<Vec<_>>(),
+            vec![(0, 1), (1, 0)]
+        );
+        assert_eq!(
+            grid.neighbours((1, 1)).collect::<Vec<_>>(),
+            vec![(0, 1), (1, 2), (1, 0)]
+        );
+        assert_eq!(grid.neighbours8((0, 1)).count(), 5);
+    }
+
+    #[test]
+    fn turns_directions() {
+        assert_eq!(Direction::N.turn_right(), Direction::E);
+        assert_eq!(Direction::W.turn_right(), Direction::N);
+        assert_eq!(Direction::N.turn_left(), Direction::W);
+        assert_eq!(Direction::NE.opposite(), Direction::SW);
+        assert_eq!(Direction::from_arrow('v'), Some(Direction::S));
+        assert!(!Direction::SE.is_orthogonal());
+    }
+
+    #[test]
+    fn finds_cells() {
+        let grid = Grid::parse("a.b\n.ab", |c| c);
+        assert_eq!(grid.find(|&c| c == 'b'), Some((0, 2)));
+        assert_eq!(
+            grid.find_all(|&c| c == 'a').collect::<Vec<_>>(),
+            vec![(0, 0), (1, 1)]
+        );
+        assert_eq!(grid.find(|&c| c == 'z'), None);
+    }
+
+    #[test]
+    fn transposes_and_rotates() {
+        let grid = Grid::parse(EXAMPLE, |c| c);
+        assert_eq!(grid.transpose().to_string(), "ad\nbe\ncf");
+        assert_eq!(grid.rotate_right().to_string(), "da\neb\nfc");
+        assert_eq!(grid.rotate_left().to_string(), "cf\nbe\nad");
+        assert_eq!(grid.rotate_right().rotate_left(), grid);
+    }
+
+    #[test]
+    fn maps_cells() {
+        let grid = Grid::parse(EXAMPLE, |c| c).map(|c| c.is_ascii_lowercase());
+        assert_eq!(grid, Grid::new(3, 2, true));
+    }
+}
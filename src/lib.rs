@@ -1,3 +1,8 @@
+pub mod error;
+pub mod grid;
+pub mod math;
+pub mod parse;
+pub mod pathfinding;
 pub mod template;
 
 // Use this file to add helper functions and additional modules.
@@ -1,79 +1,328 @@
-use advent_of_code::template::commands::{all, download, read, scaffold, solve, time};
+use advent_of_code::template::commands::{
+    all, download, examples, read, report, scaffold, solve, time, verify, watch,
+};
 use args::{parse, AppArguments};
 
 #[cfg(feature = "today")]
-use advent_of_code::template::Day;
+use advent_of_code::template::{Day, Year};
 #[cfg(feature = "today")]
-use std::process;
+use std::{collections::HashMap, process};
+
+/// Solutions compiled into this binary, generated by `build.rs`.
+#[cfg(not(test))]
+#[allow(dead_code)]
+mod solutions {
+    include!(concat!(env!("OUT_DIR"), "/solutions.rs"));
+}
+
+/// The solution modules carry their own tests, so they are left out of this binary's test build.
+#[cfg(test)]
+mod solutions {
+    pub const SOLUTIONS: &[advent_of_code::template::registry::Solution] = &[];
+}
 
 mod args {
-    use advent_of_code::template::Day;
-    use std::process;
+    use advent_of_code::template::commands::scaffold;
+    use advent_of_code::template::commands::watch::WatchAction;
+    use advent_of_code::template::history::HistoryOptions;
+    use advent_of_code::template::limits::Limits;
+    use advent_of_code::template::stats::{parse_stats, BenchConfig, Stat};
+    use advent_of_code::template::{Day, Year, YEAR_ENV};
+    use std::{collections::HashMap, path::PathBuf, process};
 
     pub enum AppArguments {
         Download {
+            year: Year,
             day: Day,
         },
         Read {
+            year: Year,
             day: Day,
         },
+        Report {
+            year: Year,
+            output: Option<String>,
+        },
+        Examples {
+            year: Year,
+            day: Day,
+            overwrite: bool,
+        },
         Scaffold {
+            year: Year,
             day: Day,
             download: bool,
             overwrite: bool,
+            template: String,
+            variables: HashMap<String, String>,
         },
         Solve {
+            year: Year,
             day: Day,
             release: bool,
             dhat: bool,
             submit: Option<u8>,
+            bench: Option<BenchConfig>,
+            input: Option<String>,
         },
         All {
+            year: Year,
             release: bool,
+            isolated: bool,
+            jobs: usize,
+            limits: Limits,
+            inputs: Option<PathBuf>,
         },
         Time {
+            year: Year,
             all: bool,
             day: Option<Day>,
             store: bool,
+            isolated: bool,
+            jobs: usize,
+            limits: Limits,
+            bench: BenchConfig,
+            columns: Vec<Stat>,
+            memory: bool,
+            history: HistoryOptions,
+        },
+        Verify {
+            year: Year,
+            release: bool,
+            isolated: bool,
+            jobs: usize,
+            limits: Limits,
+            accept: bool,
+        },
+        Watch {
+            year: Year,
+            day: Day,
+            action: WatchAction,
+            release: bool,
         },
         #[cfg(feature = "today")]
         Today,
     }
 
+    /// Parse the bench config flags shared by `time` and `solve --time`.
+    fn parse_bench(
+        args: &mut pico_args::Arguments,
+    ) -> Result<BenchConfig, Box<dyn std::error::Error>> {
+        let default = BenchConfig::default();
+
+        Ok(BenchConfig {
+            budget: args
+                .opt_value_from_str("--budget")?
+                .map_or(default.budget, std::time::Duration::from_millis),
+            min_samples: args
+                .opt_value_from_str("--min-samples")?
+                .unwrap_or(default.min_samples),
+            max_samples: args
+                .opt_value_from_str("--max-samples")?
+                .unwrap_or(default.max_samples),
+            warmup: args
+                .opt_value_from_str("--warmup")?
+                .unwrap_or(default.warmup),
+        })
+    }
+
+    /// Parse the flags of `time` that record and compare benchmark history.
+    fn parse_history(
+        args: &mut pico_args::Arguments,
+    ) -> Result<HistoryOptions, Box<dyn std::error::Error>> {
+        let fail_on_regression: Option<f64> = args.opt_value_from_str("--fail-on-regression")?;
+        if fail_on_regression.is_some_and(|x| x < 0.0) {
+            return Err("`--fail-on-regression` must not be negative.".into());
+        }
+
+        Ok(HistoryOptions {
+            tag: args.opt_value_from_str("--tag")?,
+            compare: args.contains("--compare"),
+            baseline: args.opt_value_from_str("--baseline")?,
+            fail_on_regression,
+        })
+    }
+
+    /// Parse `--year`, falling back to `AOC_YEAR` from `.cargo/config.toml`.
+    fn parse_year(args: &mut pico_args::Arguments) -> Result<Year, Box<dyn std::error::Error>> {
+        match args.opt_value_from_str("--year")? {
+            Some(year) => Ok(year),
+            None => Year::from_env().ok_or_else(|| {
+                format!("no year specified. Pass `--year` or set {YEAR_ENV} in .cargo/config.toml.")
+                    .into()
+            }),
+        }
+    }
+
+    /// Parse a `--var KEY=VALUE` template variable.
+    fn parse_variable(arg: &str) -> Result<(String, String), String> {
+        arg.split_once('=')
+            .map(|(key, value)| (key.to_string(), value.to_string()))
+            .ok_or_else(|| format!("expected `KEY=VALUE`, got `{arg}`."))
+    }
+
+    /// Parse `--jobs`. Days run one after another by default.
+    fn parse_jobs(args: &mut pico_args::Arguments) -> Result<usize, Box<dyn std::error::Error>> {
+        match args.opt_value_from_str("--jobs")? {
+            Some(0) => Err("`--jobs` must be at least 1.".into()),
+            Some(jobs) => Ok(jobs),
+            None => Ok(1),
+        }
+    }
+
+    /// Parse `--timeout` (in seconds) and `--memory` (in megabytes).
+    fn parse_limits(args: &mut pico_args::Arguments) -> Result<Limits, Box<dyn std::error::Error>> {
+        let timeout: Option<f64> = args.opt_value_from_str("--timeout")?;
+        if timeout.is_some_and(|x| x <= 0.0) {
+            return Err("`--timeout` must be positive.".into());
+        }
+
+        Ok(Limits {
+            timeout: timeout.map(std::time::Duration::from_secs_f64),
+            memory_mb: args.opt_value_from_str("--memory")?,
+        })
+    }
+
+    /// Check that `day` is a puzzle day of `year`.
+    fn check_day(year: Year, day: Day) -> Result<Day, Box<dyn std::error::Error>> {
+        if year.contains(day) {
+            Ok(day)
+        } else {
+            Err(format!("day {day} does not exist, {year} has {} days.", year.days()).into())
+        }
+    }
+
     pub fn parse() -> Result<AppArguments, Box<dyn std::error::Error>> {
         let mut args = pico_args::Arguments::from_env();
 
         let app_args = match args.subcommand()?.as_deref() {
             Some("all") => AppArguments::All {
+                year: parse_year(&mut args)?,
                 release: args.contains("--release"),
+                isolated: args.contains("--isolated"),
+                jobs: parse_jobs(&mut args)?,
+                limits: parse_limits(&mut args)?,
+                inputs: args.opt_value_from_str("--inputs")?,
             },
             Some("time") => {
+                let year = parse_year(&mut args)?;
                 let all = args.contains("--all");
                 let store = args.contains("--store");
+                let isolated = args.contains("--isolated");
+                let jobs = parse_jobs(&mut args)?;
+                let limits = parse_limits(&mut args)?;
+                let bench = parse_bench(&mut args)?;
+                let memory = args.contains("--show-memory");
+                let history = parse_history(&mut args)?;
+                let columns = args
+                    .opt_value_from_fn("--stats", parse_stats)?
+                    .unwrap_or_default();
 
                 AppArguments::Time {
+                    year,
                     all,
-                    day: args.opt_free_from_str()?,
+                    day: args
+                        .opt_free_from_str()?
+                        .map(|day| check_day(year, day))
+                        .transpose()?,
                     store,
+                    isolated,
+                    jobs,
+                    limits,
+                    bench,
+                    columns,
+                    memory,
+                    history,
                 }
             }
-            Some("download") => AppArguments::Download {
-                day: args.free_from_str()?,
-            },
-            Some("read") => AppArguments::Read {
-                day: args.free_from_str()?,
-            },
-            Some("scaffold") => AppArguments::Scaffold {
-                day: args.free_from_str()?,
-                download: args.contains("--download"),
-                overwrite: args.contains("--overwrite"),
-            },
-            Some("solve") => AppArguments::Solve {
-                day: args.free_from_str()?,
+            Some("verify") => AppArguments::Verify {
+                year: parse_year(&mut args)?,
                 release: args.contains("--release"),
-                submit: args.opt_value_from_str("--submit")?,
-                dhat: args.contains("--dhat"),
+                isolated: args.contains("--isolated"),
+                jobs: parse_jobs(&mut args)?,
+                limits: parse_limits(&mut args)?,
+                accept: args.contains("--accept"),
             },
+            Some("download") => {
+                let year = parse_year(&mut args)?;
+                AppArguments::Download {
+                    year,
+                    day: check_day(year, args.free_from_str()?)?,
+                }
+            }
+            Some("read") => {
+                let year = parse_year(&mut args)?;
+                AppArguments::Read {
+                    year,
+                    day: check_day(year, args.free_from_str()?)?,
+                }
+            }
+            Some("report") => AppArguments::Report {
+                year: parse_year(&mut args)?,
+                output: args.opt_value_from_str("--output")?,
+            },
+            Some("examples") => {
+                let year = parse_year(&mut args)?;
+                AppArguments::Examples {
+                    year,
+                    overwrite: args.contains("--overwrite"),
+                    day: check_day(year, args.free_from_str()?)?,
+                }
+            }
+            Some("scaffold") => {
+                let year = parse_year(&mut args)?;
+                AppArguments::Scaffold {
+                    year,
+                    day: check_day(year, args.free_from_str()?)?,
+                    download: args.contains("--download"),
+                    overwrite: args.contains("--overwrite"),
+                    template: args
+                        .opt_value_from_str("--template")?
+                        .unwrap_or_else(|| scaffold::DEFAULT_TEMPLATE.to_string()),
+                    variables: args
+                        .values_from_fn("--var", parse_variable)?
+                        .into_iter()
+                        .collect(),
+                }
+            }
+            Some("solve") => {
+                let year = parse_year(&mut args)?;
+                let time = args.contains("--time");
+                let bench = parse_bench(&mut args)?;
+
+                let submit = args.opt_value_from_str("--submit")?;
+                let input: Option<String> = args.opt_value_from_str("--input")?;
+                if submit.is_some() && input.is_some() {
+                    return Err(
+                        "`--submit` cannot be used with `--input`, only answers for your own input can be submitted.".into(),
+                    );
+                }
+
+                AppArguments::Solve {
+                    year,
+                    day: check_day(year, args.free_from_str()?)?,
+                    release: args.contains("--release"),
+                    submit,
+                    dhat: args.contains("--dhat"),
+                    bench: time.then_some(bench),
+                    input,
+                }
+            }
+            Some("watch") => {
+                let year = parse_year(&mut args)?;
+                let action = match (args.contains("--test"), args.contains("--solve")) {
+                    (true, true) => return Err("pass either `--test` or `--solve`.".into()),
+                    (_, true) => WatchAction::Solve,
+                    _ => WatchAction::Test,
+                };
+
+                AppArguments::Watch {
+                    year,
+                    day: check_day(year, args.free_from_str()?)?,
+                    action,
+                    release: args.contains("--release"),
+                }
+            }
             #[cfg(feature = "today")]
             Some("today") => AppArguments::Today,
             Some(x) => {
@@ -102,38 +351,130 @@ fn main() {
             std::process::exit(1);
         }
         Ok(args) => match args {
-            AppArguments::All { release } => all::handle(release),
-            AppArguments::Time { day, all, store } => time::handle(day, all, store),
-            AppArguments::Download { day } => download::handle(day),
-            AppArguments::Read { day } => read::handle(day),
+            AppArguments::All {
+                year,
+                release,
+                isolated,
+                jobs,
+                limits,
+                inputs,
+            } => {
+                all::handle(
+                    solutions::SOLUTIONS,
+                    year,
+                    release,
+                    isolated,
+                    jobs,
+                    limits,
+                    inputs.as_deref(),
+                );
+            }
+            AppArguments::Time {
+                year,
+                day,
+                all,
+                store,
+                isolated,
+                jobs,
+                limits,
+                bench,
+                columns,
+                memory,
+                history,
+            } => time::handle(
+                solutions::SOLUTIONS,
+                year,
+                day,
+                all,
+                store,
+                isolated,
+                jobs,
+                limits,
+                &bench,
+                &columns,
+                memory,
+                &history,
+            ),
+            AppArguments::Verify {
+                year,
+                release,
+                isolated,
+                jobs,
+                limits,
+                accept,
+            } => verify::handle(
+                solutions::SOLUTIONS,
+                year,
+                release,
+                isolated,
+                jobs,
+                limits,
+                accept,
+            ),
+            AppArguments::Download { year, day } => download::handle(year, day),
+            AppArguments::Read { year, day } => read::handle(year, day),
+            AppArguments::Report { year, output } => report::handle(year, output),
+            AppArguments::Watch {
+                year,
+                day,
+                action,
+                release,
+            } => watch::handle(year, day, action, release),
+            AppArguments::Examples {
+                year,
+                day,
+                overwrite,
+            } => examples::handle(year, day, overwrite),
             AppArguments::Scaffold {
+                year,
                 day,
                 download,
                 overwrite,
+                template,
+                variables,
             } => {
-                scaffold::handle(day, overwrite);
+                // download first, so that the tests can be filled in from the puzzle's examples.
                 if download {
-                    download::handle(day);
+                    download::handle(year, day);
                 }
+                scaffold::handle(year, day, overwrite, &template, &variables);
             }
             AppArguments::Solve {
+                year,
                 day,
                 release,
                 dhat,
                 submit,
-            } => solve::handle(day, release, dhat, submit),
+                bench,
+                input,
+            } => solve::handle(
+                year,
+                day,
+                release,
+                dhat,
+                submit,
+                bench.as_ref(),
+                input.as_deref(),
+            ),
             #[cfg(feature = "today")]
             AppArguments::Today => {
-                match Day::today() {
-                    Some(day) => {
-                        scaffold::handle(day, false);
-                        download::handle(day);
-                        read::handle(day)
+                match Year::today().zip(Day::today()) {
+                    Some((year, day)) => {
+                        download::handle(year, day);
+                        scaffold::handle(
+                            year,
+                            day,
+                            false,
+                            scaffold::DEFAULT_TEMPLATE,
+                            &HashMap::new(),
+                        );
+                        read::handle(year, day)
                     }
                     None => {
+                        let last_day = Year::today().map_or(25, Year::days);
                         eprintln!(
                             "`today` command can only be run between the 1st and \
-                            the 25th of december. Please use `scaffold` with a specific day."
+                            the {last_day}th of december. Please use `scaffold` with a specific day."
                         );
                         process::exit(1)
                     }
@@ -0,0 +1,378 @@
+/// Number theory and exact linear algebra on integers.
+///
+/// Everything works on `i64`. Intermediate products are computed in `i128`, so only results
+/// that do not fit into an `i64` overflow.
+use std::{
+    cmp::Ordering,
+    fmt::{self, Display},
+    ops::{Add, Div, Mul, Neg, Sub},
+};
+
+/// Greatest common divisor, never negative. `gcd(0, 0)` is 0.
+pub fn gcd(a: i64, b: i64) -> i64 {
+    if b == 0 {
+        a.abs()
+    } else {
+        gcd(b, a % b)
+    }
+}
+
+/// Least common multiple, never negative. 0 if either number is 0.
+pub fn lcm(a: i64, b: i64) -> i64 {
+    if a == 0 || b == 0 {
+        0
+    } else {
+        (a / gcd(a, b) * b).abs()
+    }
+}
+
+/// Extended Euclidean algorithm: `(g, x, y)` with `a * x + b * y = g = gcd(a, b)`.
+pub fn extended_gcd(a: i64, b: i64) -> (i64, i64, i64) {
+    if b == 0 {
+        (a.abs(), a.signum(), 0)
+    } else {
+        let (g, x, y) = extended_gcd(b, a % b);
+        (g, y, x - (a / b) * y)
+    }
+}
+
+/// The inverse of `a` modulo `m`, in `0..m`, if `a` and `m` are coprime.
+pub fn mod_inverse(a: i64, m: i64) -> Option<i64> {
+    let (g, x, _) = extended_gcd(a.rem_euclid(m), m);
+    (g == 1).then(|| x.rem_euclid(m))
+}
+
+/// Chinese remainder theorem: the smallest `x >= 0` with `x ≡ r (mod m)` for every `(r, m)`,
+/// together with the modulus of all solutions (the lcm of the moduli). The moduli need not be
+/// coprime. `None` if the congruences contradict each other.
+pub fn crt(congruences: &[(i64, i64)]) -> Option<(i64, i64)> {
+    congruences.iter().try_fold((0, 1), |(x, m), &(r, n)| {
+        // Find k with x + k * m ≡ r (mod n), i.e. k * m ≡ r - x (mod n)
+        let (g, inverse, _) = extended_gcd(m, n);
+        let difference = r - x;
+        if difference % g != 0 {
+            return None;
+        }
+        let modulus = lcm(m, n);
+        let k = i128::from(difference / g) * i128::from(inverse) % i128::from(n / g);
+        let x = (i128::from(x) + k * i128::from(m)).rem_euclid(i128::from(modulus));
+        Some((x as i64, modulus))
+    })
+}
+
+/// Number of decimal digits of `n`, 1 for 0.
+pub fn digit_count(n: u64) -> u32 {
+    n.checked_ilog10().unwrap_or(0) + 1
+}
+
+/// The decimal digits of `b` appended to `a`, e.g. `concat(12, 345) == 12345`.
+pub fn concat(a: u64, b: u64) -> u64 {
+    a * 10u64.pow(digit_count(b)) + b
+}
+
+/// Division rounding towards negative infinity.
+pub fn div_floor(a: i64, b: i64) -> i64 {
+    let q = a / b;
+    if a % b != 0 && (a < 0) != (b < 0) {
+        q - 1
+    } else {
+        q
+    }
+}
+
+/// Division rounding towards positive infinity.
+pub fn div_ceil(a: i64, b: i64) -> i64 {
+    -div_floor(-a, b)
+}
+
+/// An exact fraction, always in lowest terms with a positive denominator.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Rational {
+    num: i64,
+    den: i64,
+}
+
+impl Rational {
+    pub const ZERO: Rational = Rational { num: 0, den: 1 };
+    pub const ONE: Rational = Rational { num: 1, den: 1 };
+
+    /// # Panics
+    ///
+    /// If `den` is 0.
+    pub fn new(num: i64, den: i64) -> Self {
+        Rational::reduce(i128::from(num), i128::from(den))
+    }
+
+    fn reduce(num: i128, den: i128) -> Self {
+        assert!(den != 0, "denominator of a rational must not be 0");
+        let g = gcd_i128(num, den) * den.signum();
+        Rational {
+            num: i64::try_from(num / g).expect("rational overflowed"),
+            den: i64::try_from(den / g).expect("rational overflowed"),
+        }
+    }
+
+    pub fn num(self) -> i64 {
+        self.num
+    }
+
+    pub fn den(self) -> i64 {
+        self.den
+    }
+
+    pub fn is_integer(self) -> bool {
+        self.den == 1
+    }
+
+    /// The value as an integer, if it is one.
+    pub fn to_integer(self) -> Option<i64> {
+        self.is_integer().then_some(self.num)
+    }
+}
+
+fn gcd_i128(a: i128, b: i128) -> i128 {
+    if b == 0 {
+        a.abs()
+    } else {
+        gcd_i128(b, a % b)
+    }
+}
+
+impl From<i64> for Rational {
+    fn from(n: i64) -> Self {
+        Rational { num: n, den: 1 }
+    }
+}
+
+impl Add for Rational {
+    type Output = Rational;
+
+    fn add(self, other: Rational) -> Rational {
+        Rational::reduce(
+            i128::from(self.num) * i128::from(other.den)
+                + i128::from(other.num) * i128::from(self.den),
+            i128::from(self.den) * i128::from(other.den),
+        )
+    }
+}
+
+impl Neg for Rational {
+    type Output = Rational;
+
+    fn neg(self) -> Rational {
+        Rational {
+            num: -self.num,
+            den: self.den,
+        }
+    }
+}
+
+impl Sub for Rational {
+    type Output = Rational;
+
+    fn sub(self, other: Rational) -> Rational {
+        self + -other
+    }
+}
+
+impl Mul for Rational {
+    type Output = Rational;
+
+    fn mul(self, other: Rational) -> Rational {
+        Rational::reduce(
+            i128::from(self.num) * i128::from(other.num),
+            i128::from(self.den) * i128::from(other.den),
+        )
+    }
+}
+
+impl Div for Rational {
+    type Output = Rational;
+
+    /// # Panics
+    ///
+    /// If `other` is 0.
+    fn div(self, other: Rational) -> Rational {
+        Rational::reduce(
+            i128::from(self.num) * i128::from(other.den),
+            i128::from(self.den) * i128::from(other.num),
+        )
+    }
+}
+
+impl Ord for Rational {
+    fn cmp(&self, other: &Self) -> Ordering {
+        (i128::from(self.num) * i128::from(other.den))
+            .cmp(&(i128::from(other.num) * i128::from(self.den)))
+    }
+}
+
+impl PartialOrd for Rational {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Display for Rational {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.is_integer() {
+            write!(f, "{}", self.num)
+        } else {
+            write!(f, "{}/{}", self.num, self.den)
+        }
+    }
+}
+
+/// Solve `a * x + b * y = e`, `c * x + d * y = f` given as `[[a, b], [c, d]]` and `[e, f]`.
+/// `None` if the system has no unique solution, i.e. its determinant is 0.
+pub fn solve_2x2(matrix: [[i64; 2]; 2], rhs: [i64; 2]) -> Option<(Rational, Rational)> {
+    let [[a, b], [c, d]] = matrix;
+    let [e, f] = rhs;
+    let det = i128::from(a) * i128::from(d) - i128::from(b) * i128::from(c);
+    if det == 0 {
+        return None;
+    }
+    // Cramer's rule
+    let x = i128::from(e) * i128::from(d) - i128::from(b) * i128::from(f);
+    let y = i128::from(a) * i128::from(f) - i128::from(e) * i128::from(c);
+    Some((Rational::reduce(x, det), Rational::reduce(y, det)))
+}
+
+/// Solve the square system `matrix * x = rhs` by Gaussian elimination over the rationals.
+/// `None` if the system has no unique solution.
+///
+/// # Panics
+///
+/// If `matrix` is not square or `rhs` does not have a value per row.
+pub fn solve_linear(matrix: &[Vec<i64>], rhs: &[i64]) -> Option<Vec<Rational>> {
+    let n = matrix.len();
+    assert!(
+        matrix.iter().all(|row| row.len() == n) && rhs.len() == n,
+        "expected a square system"
+    );
+
+    // Augmented matrix
+    let mut rows: Vec<Vec<Rational>> = matrix
+        .iter()
+        .zip(rhs)
+        .map(|(row, b)| row.iter().chain([b]).map(|x| Rational::from(*x)).collect())
+        .collect();
+
+    for col in 0..n {
+        let pivot = (col..n).find(|row| rows[*row][col] != Rational::ZERO)?;
+        rows.swap(col, pivot);
+        for row in 0..n {
+            if row == col || rows[row][col] == Rational::ZERO {
+                continue;
+            }
+            let factor = rows[row][col] / rows[col][col];
+            let pivot_row = rows[col].clone();
+            for (x, p) in rows[row].iter_mut().zip(pivot_row).skip(col) {
+                *x = *x - factor * p;
+            }
+        }
+    }
+
+    Some(
+        rows.iter()
+            .enumerate()
+            .map(|(i, row)| row[n] / row[i])
+            .collect(),
+    )
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{
+        concat, crt, digit_count, div_ceil, div_floor, extended_gcd, gcd, lcm, mod_inverse,
+        solve_2x2, solve_linear, Rational,
+    };
+
+    #[test]
+    fn computes_gcd_and_lcm() {
+        assert_eq!(gcd(12, 18), 6);
+        assert_eq!(gcd(-12, 18), 6);
+        assert_eq!(gcd(0, 5), 5);
+        assert_eq!(lcm(4, 6), 12);
+        assert_eq!(lcm(-4, 6), 12);
+        assert_eq!(lcm(0, 6), 0);
+
+        let (g, x, y) = extended_gcd(240, 46);
+        assert_eq!(g, 2);
+        assert_eq!(240 * x + 46 * y, 2);
+    }
+
+    #[test]
+    fn computes_modular_inverses() {
+        assert_eq!(mod_inverse(3, 11), Some(4));
+        assert_eq!(mod_inverse(-3, 11), Some(7));
+        assert_eq!(mod_inverse(4, 8), None);
+    }
+
+    #[test]
+    fn solves_congruences() {
+        assert_eq!(crt(&[(2, 3), (3, 5), (2, 7)]), Some((23, 105)));
+        assert_eq!(crt(&[(3, 4), (1, 6)]), Some((7, 12)));
+        assert_eq!(crt(&[(0, 4), (1, 6)]), None);
+        assert_eq!(crt(&[]), Some((0, 1)));
+    }
+
+    #[test]
+    fn handles_digits() {
+        assert_eq!(digit_count(0), 1);
+        assert_eq!(digit_count(9), 1);
+        assert_eq!(digit_count(10), 2);
+        assert_eq!(digit_count(u64::MAX), 20);
+        assert_eq!(concat(12, 345), 12345);
+        assert_eq!(concat(12, 0), 120);
+    }
+
+    #[test]
+    fn divides_with_rounding() {
+        assert_eq!(div_floor(7, 2), 3);
+        assert_eq!(div_floor(-7, 2), -4);
+        assert_eq!(div_ceil(7, 2), 4);
+        assert_eq!(div_ceil(-7, 2), -3);
+        assert_eq!(div_ceil(6, -2), -3);
+    }
+
+    #[test]
+    fn calculates_with_rationals() {
+        let half = Rational::new(2, 4);
+        assert_eq!((half.num(), half.den()), (1, 2));
+        assert_eq!(Rational::new(3, -6), -half);
+        assert_eq!(half + Rational::new(1, 3), Rational::new(5, 6));
+        assert_eq!(half - Rational::ONE, Rational::new(-1, 2));
+        assert_eq!(half * Rational::from(4), Rational::from(2));
+        assert_eq!(half / Rational::new(1, 4), Rational::from(2));
+        assert!(Rational::new(1, 3) < half);
+        assert_eq!(Rational::from(2).to_integer(), Some(2));
+        assert_eq!(half.to_integer(), None);
+        assert_eq!(half.to_string(), "1/2");
+    }
+
+    #[test]
+    fn solves_linear_systems() {
+        assert_eq!(
+            solve_2x2([[94, 22], [34, 67]], [8400, 5400]),
+            Some((Rational::from(80), Rational::from(40)))
+        );
+        assert_eq!(
+            solve_2x2([[2, 0], [0, 3]], [1, 1]),
+            Some((Rational::new(1, 2), Rational::new(1, 3)))
+        );
+        assert_eq!(solve_2x2([[1, 2], [2, 4]], [3, 6]), None);
+
+        assert_eq!(
+            solve_linear(&[vec![0, 1, 1], vec![2, 0, 1], vec![1, 1, 0]], &[5, 5, 3]),
+            Some(vec![
+                Rational::from(1),
+                Rational::from(2),
+                Rational::from(3)
+            ])
+        );
+        assert_eq!(solve_linear(&[vec![1, 1], vec![2, 2]], &[1, 2]), None);
+    }
+}
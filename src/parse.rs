@@ -0,0 +1,305 @@
+/// Parsers for the common shapes of puzzle inputs, built on `nom`.
+///
+/// The combinators parse a single value, and [`parse_all`] runs one over a whole input. Its errors
+/// point to where parsing failed, e.g. `line 3, column 13: expected a number, found "x"`.
+use std::{
+    fmt::{self, Display},
+    str::FromStr,
+};
+
+use nom::{
+    bytes::complete::tag,
+    character::complete::{
+        char, digit1, line_ending, multispace0, not_line_ending, one_of, space0,
+    },
+    combinator::{all_consuming, map, map_res, opt, recognize},
+    error::{Error, ErrorKind},
+    multi::{many1, separated_list1},
+    sequence::{pair, terminated, tuple},
+    Parser,
+};
+
+/// The result of a parser of `&str`.
+pub type IResult<'a, T> = nom::IResult<&'a str, T>;
+
+/// Why an input could not be parsed, and where.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ParseError {
+    /// Line of the error, starting at 1.
+    pub line: usize,
+    /// Column of the error in characters, starting at 1.
+    pub column: usize,
+    pub message: String,
+}
+
+impl ParseError {
+    /// An error at `at`, a slice of `input` that starts where the error is.
+    pub fn at(input: &str, at: &str, message: impl Into<String>) -> Self {
+        let offset = (at.as_ptr() as usize)
+            .checked_sub(input.as_ptr() as usize)
+            .filter(|offset| *offset <= input.len())
+            .unwrap_or(0);
+        let before = &input[..offset];
+
+        ParseError {
+            line: before.matches('\n').count() + 1,
+            column: before.rsplit('\n').next().unwrap_or("").chars().count() + 1,
+            message: message.into(),
+        }
+    }
+
+    /// An error at `(row, col)` of an input that is a grid of characters, see [`crate::grid`].
+    pub fn at_pos((row, col): (usize, usize), message: impl Into<String>) -> Self {
+        ParseError {
+            line: row + 1,
+            column: col + 1,
+            message: message.into(),
+        }
+    }
+
+    fn from_nom(input: &str, error: Error<&str>) -> Self {
+        let found = match error.input.lines().next() {
+            None | Some("") => "the end of the line".to_string(),
+            Some(line) if line.chars().count() > 20 => {
+                let start: String = line.chars().take(20).collect();
+                format!("{:?}", start + "...")
+            }
+            Some(line) => format!("{line:?}"),
+        };
+        let expected = match error.code {
+            ErrorKind::Digit => "a number",
+            ErrorKind::CrLf => "a line break",
+            ErrorKind::Eof => "the end of the input",
+            ErrorKind::MapRes | ErrorKind::MapOpt | ErrorKind::Verify => "a valid value",
+            // NOTE: nom does not tell which text it expected.
+            ErrorKind::Char | ErrorKind::Tag | ErrorKind::OneOf => {
+                return ParseError::at(input, error.input, format!("unexpected {found}"));
+            }
+            _ => error.code.description(),
+        };
+        ParseError::at(
+            input,
+            error.input,
+            format!("expected {expected}, found {found}"),
+        )
+    }
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "line {}, column {}: {}",
+            self.line, self.column, self.message
+        )
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+/// Parse all of `input` with `parser`, ignoring trailing whitespace.
+pub fn parse_all<'a, T>(
+    input: &'a str,
+    parser: impl Parser<&'a str, T, Error<&'a str>>,
+) -> Result<T, ParseError> {
+    match all_consuming(terminated(parser, multispace0)).parse(input) {
+        Ok((_, value)) => Ok(value),
+        Err(nom::Err::Error(e) | nom::Err::Failure(e)) => Err(ParseError::from_nom(input, e)),
+        Err(nom::Err::Incomplete(_)) => Err(ParseError::at(
+            input,
+            &input[input.len()..],
+            "unexpected end of input",
+        )),
+    }
+}
+
+/// An unsigned integer, e.g. `42`.
+pub fn unsigned<T: FromStr>(input: &str) -> IResult<'_, T> {
+    map_res(digit1, str::parse)(input)
+}
+
+/// An integer with an optional sign, e.g. `-42` or `+42`.
+pub fn signed<T: FromStr>(input: &str) -> IResult<'_, T> {
+    map_res(recognize(pair(opt(one_of("+-")), digit1)), str::parse)(input)
+}
+
+/// All integers in `text`, e.g. `[94, -34]` for `Button A: X+94, Y-34`. A `-` right before the
+/// digits is a sign, unless `T` is unsigned. Numbers that do not fit into `T` are skipped.
+pub fn ints<T: FromStr>(text: &str) -> Vec<T> {
+    let bytes = text.as_bytes();
+    let mut ints = vec![];
+    let mut i = 0;
+    while i < bytes.len() {
+        if !bytes[i].is_ascii_digit() {
+            i += 1;
+            continue;
+        }
+        let start = i;
+        while i < bytes.len() && bytes[i].is_ascii_digit() {
+            i += 1;
+        }
+        let number = match start.checked_sub(1) {
+            Some(sign) if bytes[sign] == b'-' => text[sign..i].parse().or(text[start..i].parse()),
+            _ => text[start..i].parse(),
+        };
+        ints.extend(number);
+    }
+    ints
+}
+
+/// All integers up to the end of the line, see [`ints`].
+pub fn ints_in_line<T: FromStr>(input: &str) -> IResult<'_, Vec<T>> {
+    map(not_line_ending, ints)(input)
+}
+
+/// A labelled value, e.g. `Register A: 729` for `labelled("Register A", unsigned)`.
+pub fn labelled<'a, T>(
+    label: &'a str,
+    mut value: impl Parser<&'a str, T, Error<&'a str>>,
+) -> impl FnMut(&'a str) -> IResult<'a, T> {
+    move |input| {
+        let (input, _) = tuple((tag(label), char(':'), space0))(input)?;
+        value.parse(input)
+    }
+}
+
+/// A list of one or more values separated by commas, e.g. `0,1,5` or `x, y`.
+pub fn comma_list<'a, T>(
+    item: impl Parser<&'a str, T, Error<&'a str>>,
+) -> impl FnMut(&'a str) -> IResult<'a, Vec<T>> {
+    separated_list1(pair(char(','), space0), item)
+}
+
+/// One value per line, up to the end of the input or the next blank line. Every line in between
+/// must be a value, so a malformed line is reported where it is wrong.
+pub fn lines_of<'a, T>(
+    mut item: impl Parser<&'a str, T, Error<&'a str>>,
+) -> impl FnMut(&'a str) -> IResult<'a, Vec<T>> {
+    move |mut input| {
+        let mut items = vec![];
+        loop {
+            let (rest, value) = item.parse(input)?;
+            items.push(value);
+            match line_ending::<_, Error<&str>>(rest) {
+                Ok((next, _))
+                    if !next.is_empty() && line_ending::<_, Error<&str>>(next).is_err() =>
+                {
+                    input = next;
+                }
+                _ => return Ok((rest, items)),
+            }
+        }
+    }
+}
+
+/// Blocks of lines separated by blank lines, e.g. `blocks_of(lines_of(unsigned))` for groups of
+/// numbers. Every block must be a value, so a malformed block is reported where it is wrong.
+pub fn blocks_of<'a, T>(
+    mut item: impl Parser<&'a str, T, Error<&'a str>>,
+) -> impl FnMut(&'a str) -> IResult<'a, Vec<T>> {
+    move |mut input| {
+        let mut items = vec![];
+        loop {
+            let (rest, value) = item.parse(input)?;
+            items.push(value);
+            match blank_line(rest) {
+                Ok((next, _)) if !next.trim().is_empty() => input = next,
+                _ => return Ok((rest, items)),
+            }
+        }
+    }
+}
+
+/// The end of a line followed by one or more empty lines.
+pub fn blank_line(input: &str) -> IResult<'_, ()> {
+    map(pair(line_ending, many1(line_ending)), |_| ())(input)
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use nom::{bytes::complete::tag, sequence::separated_pair};
+
+    use super::{
+        blocks_of, comma_list, ints, labelled, lines_of, parse_all, signed, unsigned, ParseError,
+    };
+
+    #[test]
+    fn parses_numbers() {
+        assert_eq!(unsigned::<u32>("42 rest"), Ok((" rest", 42)));
+        assert!(unsigned::<u32>("-42").is_err());
+        assert_eq!(signed::<i32>("-42"), Ok(("", -42)));
+        assert_eq!(signed::<i32>("+42,"), Ok((",", 42)));
+        assert!(unsigned::<u8>("300").is_err());
+    }
+
+    #[test]
+    fn extracts_integers() {
+        assert_eq!(ints::<i32>("p=0,4 v=3,-3"), vec![0, 4, 3, -3]);
+        assert_eq!(ints::<u32>("2024-12-05"), vec![2024, 12, 5]);
+        assert_eq!(ints::<i64>("Button A: X+94, Y-34"), vec![94, -34]);
+        assert_eq!(ints::<u8>("1 300 2"), vec![1, 2]);
+        assert!(ints::<u32>("no numbers").is_empty());
+    }
+
+    #[test]
+    fn parses_labelled_lists() {
+        assert_eq!(
+            parse_all("Register A: 729\n", labelled("Register A", unsigned::<u32>)),
+            Ok(729)
+        );
+        assert_eq!(
+            parse_all(
+                "Program: 0,1, 5",
+                labelled("Program", comma_list(unsigned::<u8>))
+            ),
+            Ok(vec![0, 1, 5])
+        );
+    }
+
+    #[test]
+    fn parses_lines_and_blocks() {
+        let input = "1\n2\n\n3\n\n\n4\n";
+        assert_eq!(
+            parse_all(input, blocks_of(lines_of(unsigned::<u32>))),
+            Ok(vec![vec![1, 2], vec![3], vec![4]])
+        );
+        assert_eq!(
+            parse_all(
+                "1 2\n3 4",
+                lines_of(separated_pair(unsigned, tag(" "), unsigned))
+            ),
+            Ok(vec![(1u32, 2u32), (3, 4)])
+        );
+    }
+
+    #[test]
+    fn locates_errors() {
+        let result = parse_all("1: 2\n3: x\n", lines_of(labelled("1", unsigned::<u32>)));
+        assert_eq!(
+            result,
+            Err(ParseError {
+                line: 2,
+                column: 1,
+                message: "unexpected \"3: x\"".into()
+            })
+        );
+
+        let parser = lines_of(separated_pair(unsigned::<u32>, tag(": "), unsigned::<u32>));
+        let error = parse_all("1: 2\n3: x\n", parser).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "line 2, column 4: expected a number, found \"x\""
+        );
+
+        let error = parse_all("1\n\n2\nx", blocks_of(lines_of(unsigned::<u32>))).unwrap_err();
+        assert_eq!((error.line, error.column), (4, 1));
+
+        let error = parse_all("1 2", unsigned::<u32>).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "line 1, column 3: expected the end of the input, found \"2\""
+        );
+    }
+}
@@ -0,0 +1,311 @@
+/// Shortest paths over any graph given as a neighbours closure.
+///
+/// Nodes can be anything that is `Clone + Eq + Hash`, e.g. a grid position or a position together
+/// with a direction. The graph is never built up front: the searches call `neighbours` for the
+/// nodes they visit, so unreachable parts of a graph cost nothing.
+use std::{
+    cmp::{Ordering, Reverse},
+    collections::{HashMap, HashSet, VecDeque},
+    hash::Hash,
+};
+
+use priority_queue::PriorityQueue;
+
+/// The shortest distances from a start node to every node reachable from it, together with all
+/// predecessors of each node on a shortest path.
+#[derive(Clone, Debug)]
+pub struct Paths<N> {
+    start: N,
+    distances: HashMap<N, u32>,
+    predecessors: HashMap<N, Vec<N>>,
+}
+
+impl<N: Clone + Eq + Hash> Paths<N> {
+    /// The node the search started from.
+    pub fn start(&self) -> &N {
+        &self.start
+    }
+
+    /// Length of a shortest path to `node`, if it is reachable.
+    pub fn distance(&self, node: &N) -> Option<u32> {
+        self.distances.get(node).copied()
+    }
+
+    /// Whether there is a path to `node`.
+    pub fn is_reachable(&self, node: &N) -> bool {
+        self.distances.contains_key(node)
+    }
+
+    /// All reachable nodes with the length of a shortest path to them.
+    pub fn distances(&self) -> impl Iterator<Item = (&N, u32)> {
+        self.distances
+            .iter()
+            .map(|(node, distance)| (node, *distance))
+    }
+
+    /// The closest reachable node of `targets` with its distance. Ties go to the first one.
+    pub fn closest<'a>(&self, targets: impl IntoIterator<Item = &'a N>) -> Option<(&'a N, u32)>
+    where
+        N: 'a,
+    {
+        targets
+            .into_iter()
+            .filter_map(|target| Some((target, self.distance(target)?)))
+            .min_by_key(|(_, distance)| *distance)
+    }
+
+    /// The predecessors of `node` on its shortest paths.
+    pub fn predecessors(&self, node: &N) -> &[N] {
+        self.predecessors.get(node).map_or(&[], Vec::as_slice)
+    }
+
+    /// One shortest path from the start to `target`, both included.
+    pub fn path_to(&self, target: &N) -> Option<Vec<N>> {
+        self.distance(target)?;
+
+        let mut path = vec![target.clone()];
+        let mut node = target;
+        while *node != self.start {
+            node = &self.predecessors(node)[0];
+            path.push(node.clone());
+        }
+        path.reverse();
+        Some(path)
+    }
+
+    /// All nodes on any shortest path to the closest of `targets`, including the start and the
+    /// closest targets themselves. Empty if no target is reachable.
+    pub fn nodes_on_shortest_paths<'a>(
+        &self,
+        targets: impl IntoIterator<Item = &'a N>,
+    ) -> HashSet<N>
+    where
+        N: 'a,
+    {
+        let targets: Vec<&N> = targets.into_iter().collect();
+        let Some((_, shortest)) = self.closest(targets.iter().copied()) else {
+            return HashSet::new();
+        };
+
+        let mut nodes = HashSet::new();
+        let mut to_visit: Vec<&N> = targets
+            .into_iter()
+            .filter(|target| self.distance(target) == Some(shortest))
+            .collect();
+        while let Some(node) = to_visit.pop() {
+            if nodes.insert(node.clone()) {
+                to_visit.extend(self.predecessors(node));
+            }
+        }
+        nodes
+    }
+}
+
+/// Dijkstra's algorithm from `start` to every reachable node. `neighbours` returns the nodes
+/// connected to a node with the weights of their edges.
+pub fn dijkstra<N, I>(start: N, mut neighbours: impl FnMut(&N) -> I) -> Paths<N>
+where
+    N: Clone + Eq + Hash,
+    I: IntoIterator<Item = (N, u32)>,
+{
+    let mut distances = HashMap::from([(start.clone(), 0)]);
+    let mut predecessors: HashMap<N, Vec<N>> = HashMap::new();
+    let mut unvisited = PriorityQueue::new();
+    unvisited.push(start.clone(), Reverse(0));
+
+    // Remove the node with the smallest distance
+    while let Some((node, Reverse(distance))) = unvisited.pop() {
+        for (next, weight) in neighbours(&node) {
+            let distance_through_node = distance + weight;
+            let ordering = distances
+                .get(&next)
+                .map_or(Ordering::Less, |known| distance_through_node.cmp(known));
+            match ordering {
+                // A shorter path, so this node is now its only predecessor
+                Ordering::Less => {
+                    distances.insert(next.clone(), distance_through_node);
+                    predecessors.insert(next.clone(), vec![node.clone()]);
+                    unvisited.push_increase(next, Reverse(distance_through_node));
+                }
+                // Another path that is just as short
+                Ordering::Equal => predecessors.entry(next).or_default().push(node.clone()),
+                Ordering::Greater => (),
+            }
+        }
+    }
+
+    Paths {
+        start,
+        distances,
+        predecessors,
+    }
+}
+
+/// Breadth-first search from `start` to every reachable node, where every edge has a weight of 1.
+pub fn bfs<N, I>(start: N, mut neighbours: impl FnMut(&N) -> I) -> Paths<N>
+where
+    N: Clone + Eq + Hash,
+    I: IntoIterator<Item = N>,
+{
+    let mut distances = HashMap::from([(start.clone(), 0)]);
+    let mut predecessors: HashMap<N, Vec<N>> = HashMap::new();
+    let mut to_visit = VecDeque::from([(start.clone(), 0)]);
+
+    while let Some((node, distance)) = to_visit.pop_front() {
+        for next in neighbours(&node) {
+            match distances.get(&next) {
+                None => {
+                    distances.insert(next.clone(), distance + 1);
+                    predecessors.insert(next.clone(), vec![node.clone()]);
+                    to_visit.push_back((next, distance + 1));
+                }
+                Some(known) if *known == distance + 1 => {
+                    predecessors.entry(next).or_default().push(node.clone());
+                }
+                Some(_) => (),
+            }
+        }
+    }
+
+    Paths {
+        start,
+        distances,
+        predecessors,
+    }
+}
+
+/// A* search from `start` to the first node that `is_target`, returning a shortest path to it
+/// and its length. `heuristic` must never overestimate the distance to the closest target.
+pub fn astar<N, I>(
+    start: N,
+    mut neighbours: impl FnMut(&N) -> I,
+    heuristic: impl Fn(&N) -> u32,
+    is_target: impl Fn(&N) -> bool,
+) -> Option<(Vec<N>, u32)>
+where
+    N: Clone + Eq + Hash,
+    I: IntoIterator<Item = (N, u32)>,
+{
+    let mut distances = HashMap::from([(start.clone(), 0)]);
+    let mut predecessors: HashMap<N, N> = HashMap::new();
+    let mut unvisited = PriorityQueue::new();
+    unvisited.push(start.clone(), Reverse(heuristic(&start)));
+
+    while let Some((node, _)) = unvisited.pop() {
+        let distance = distances[&node];
+        if is_target(&node) {
+            let mut path = vec![node];
+            while let Some(previous) = predecessors.get(&path[path.len() - 1]) {
+                path.push(previous.clone());
+            }
+            path.reverse();
+            return Some((path, distance));
+        }
+
+        for (next, weight) in neighbours(&node) {
+            let distance_through_node = distance + weight;
+            if distances
+                .get(&next)
+                .is_some_and(|known| *known <= distance_through_node)
+            {
+                continue;
+            }
+            distances.insert(next.clone(), distance_through_node);
+            predecessors.insert(next.clone(), node.clone());
+            let estimate = distance_through_node + heuristic(&next);
+            unvisited.push_increase(next, Reverse(estimate));
+        }
+    }
+
+    None
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use std::collections::HashSet;
+
+    use super::{astar, bfs, dijkstra};
+    use crate::grid::{Grid, Pos};
+
+    // a -1-> b -1-> d
+    // a -2-> c -0-> d -5-> e, f is unreachable
+    fn neighbours(node: &char) -> Vec<(char, u32)> {
+        match node {
+            'a' => vec![('b', 1), ('c', 2)],
+            'b' => vec![('d', 1)],
+            'c' => vec![('d', 0)],
+            'd' => vec![('e', 5)],
+            _ => vec![],
+        }
+    }
+
+    const MAZE: &str = "\
+..#
+.##
+...";
+
+    fn open_neighbours(grid: &Grid<char>, pos: Pos) -> Vec<Pos> {
+        grid.neighbours(pos).filter(|p| grid[*p] == '.').collect()
+    }
+
+    #[test]
+    fn finds_shortest_distances() {
+        let paths = dijkstra('a', neighbours);
+        assert_eq!(paths.distance(&'d'), Some(2));
+        assert_eq!(paths.distance(&'e'), Some(7));
+        assert_eq!(paths.distance(&'f'), None);
+        assert!(!paths.is_reachable(&'f'));
+        assert_eq!(paths.closest(&['f', 'e', 'c']), Some((&'c', 2)));
+        assert_eq!(paths.distances().count(), 5);
+    }
+
+    #[test]
+    fn keeps_all_shortest_predecessors() {
+        let paths = dijkstra('a', neighbours);
+        let mut predecessors = paths.predecessors(&'d').to_vec();
+        predecessors.sort();
+        assert_eq!(predecessors, vec!['b', 'c']);
+        assert_eq!(paths.path_to(&'b'), Some(vec!['a', 'b']));
+        assert_eq!(paths.path_to(&'f'), None);
+        assert_eq!(
+            paths.nodes_on_shortest_paths(&['e', 'f']),
+            HashSet::from(['a', 'b', 'c', 'd', 'e'])
+        );
+        assert_eq!(
+            paths.nodes_on_shortest_paths(&['b', 'e']),
+            HashSet::from(['a', 'b'])
+        );
+        assert!(paths.nodes_on_shortest_paths(&['f']).is_empty());
+    }
+
+    #[test]
+    fn searches_breadth_first() {
+        let grid = Grid::parse(MAZE, |c| c);
+        let paths = bfs((0, 0), |pos| open_neighbours(&grid, *pos));
+        assert_eq!(paths.distance(&(2, 2)), Some(4));
+        assert_eq!(paths.distance(&(0, 2)), None);
+        assert_eq!(
+            paths.path_to(&(2, 2)),
+            Some(vec![(0, 0), (1, 0), (2, 0), (2, 1), (2, 2)])
+        );
+    }
+
+    #[test]
+    fn finds_path_with_heuristic() {
+        let grid = Grid::parse(MAZE, |c| c);
+        let (path, distance) = astar(
+            (0, 0),
+            |pos| open_neighbours(&grid, *pos).into_iter().map(|p| (p, 1)),
+            |pos| (2 - pos.0 + 2 - pos.1) as u32,
+            |pos| *pos == (2, 2),
+        )
+        .unwrap();
+        assert_eq!(distance, 4);
+        assert_eq!(path.first(), Some(&(0, 0)));
+        assert_eq!(path.last(), Some(&(2, 2)));
+        assert_eq!(astar('a', neighbours, |_| 0, |n| *n == 'e').unwrap().1, 7);
+        assert_eq!(astar('a', neighbours, |_| 0, |n| *n == 'f'), None);
+    }
+}
@@ -0,0 +1,241 @@
+/// Lightweight allocation accounting for solution parts.
+///
+/// With the `alloc-stats` feature, a counting global allocator tracks the bytes in use, and each
+/// part records its peak and total allocated bytes and its number of allocations. Without the
+/// feature, nothing is tracked and parts have no allocation stats.
+use std::{collections::HashMap, fmt::Display};
+
+use tinyjson::JsonValue;
+
+#[cfg(all(feature = "alloc-stats", feature = "dhat-heap"))]
+compile_error!("`alloc-stats` and `dhat-heap` both replace the global allocator.");
+
+/// Allocations made while running a part once.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct AllocStats {
+    /// Most bytes in use at once, on top of what was in use before the part started.
+    pub peak_bytes: u64,
+    /// Bytes allocated in total, including memory that was freed again.
+    pub total_bytes: u64,
+    pub allocations: u64,
+}
+
+impl Display for AllocStats {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "peak {}, {} allocs",
+            format_bytes(self.peak_bytes),
+            self.allocations
+        )
+    }
+}
+
+/// Format a byte count with a binary unit, e.g. `1.5 MiB`.
+pub fn format_bytes(bytes: u64) -> String {
+    const UNITS: [&str; 4] = ["KiB", "MiB", "GiB", "TiB"];
+
+    if bytes < 1024 {
+        return format!("{bytes} B");
+    }
+
+    #[allow(clippy::cast_precision_loss)]
+    let mut value = bytes as f64 / 1024.0;
+    let mut unit = 0;
+    while value >= 1024.0 && unit < UNITS.len() - 1 {
+        value /= 1024.0;
+        unit += 1;
+    }
+    format!("{value:.1} {}", UNITS[unit])
+}
+
+/// Cargo arguments that build child processes with the same allocation accounting as this one.
+pub fn cargo_args() -> Vec<String> {
+    if cfg!(feature = "alloc-stats") {
+        vec!["--features".into(), "alloc-stats".into()]
+    } else {
+        vec![]
+    }
+}
+
+/// Run `func` and return its result with the allocations it made.
+/// The stats are `None` unless the `alloc-stats` feature is enabled.
+pub fn measure<T>(func: impl FnOnce() -> T) -> (T, Option<AllocStats>) {
+    #[cfg(feature = "alloc-stats")]
+    {
+        let baseline = counting::reset();
+        let result = func();
+        (result, Some(counting::stats(baseline)))
+    }
+
+    #[cfg(not(feature = "alloc-stats"))]
+    {
+        (func(), None)
+    }
+}
+
+#[cfg(feature = "alloc-stats")]
+mod counting {
+    use std::alloc::{GlobalAlloc, Layout, System};
+    use std::sync::atomic::{AtomicU64, Ordering};
+
+    use super::AllocStats;
+
+    static CURRENT: AtomicU64 = AtomicU64::new(0);
+    static PEAK: AtomicU64 = AtomicU64::new(0);
+    static TOTAL: AtomicU64 = AtomicU64::new(0);
+    static COUNT: AtomicU64 = AtomicU64::new(0);
+
+    /// The system allocator, counting the bytes that pass through it.
+    struct CountingAlloc;
+
+    #[global_allocator]
+    static ALLOC: CountingAlloc = CountingAlloc;
+
+    fn record_alloc(size: usize) {
+        let size = size as u64;
+        let current = CURRENT.fetch_add(size, Ordering::Relaxed) + size;
+        PEAK.fetch_max(current, Ordering::Relaxed);
+        TOTAL.fetch_add(size, Ordering::Relaxed);
+        COUNT.fetch_add(1, Ordering::Relaxed);
+    }
+
+    fn record_dealloc(size: usize) {
+        CURRENT.fetch_sub(size as u64, Ordering::Relaxed);
+    }
+
+    unsafe impl GlobalAlloc for CountingAlloc {
+        unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+            let ptr = System.alloc(layout);
+            if !ptr.is_null() {
+                record_alloc(layout.size());
+            }
+            ptr
+        }
+
+        unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+            let ptr = System.alloc_zeroed(layout);
+            if !ptr.is_null() {
+                record_alloc(layout.size());
+            }
+            ptr
+        }
+
+        unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+            System.dealloc(ptr, layout);
+            record_dealloc(layout.size());
+        }
+
+        unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+            let new_ptr = System.realloc(ptr, layout, new_size);
+            if !new_ptr.is_null() {
+                record_dealloc(layout.size());
+                record_alloc(new_size);
+            }
+            new_ptr
+        }
+    }
+
+    /// Start a new measurement. Returns the bytes in use, which the peak is measured against.
+    pub fn reset() -> u64 {
+        let current = CURRENT.load(Ordering::Relaxed);
+        PEAK.store(current, Ordering::Relaxed);
+        TOTAL.store(0, Ordering::Relaxed);
+        COUNT.store(0, Ordering::Relaxed);
+        current
+    }
+
+    pub fn stats(baseline: u64) -> AllocStats {
+        AllocStats {
+            peak_bytes: PEAK.load(Ordering::Relaxed).saturating_sub(baseline),
+            total_bytes: TOTAL.load(Ordering::Relaxed),
+            allocations: COUNT.load(Ordering::Relaxed),
+        }
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+impl From<&AllocStats> for JsonValue {
+    fn from(value: &AllocStats) -> Self {
+        let mut map: HashMap<String, JsonValue> = HashMap::new();
+
+        #[allow(clippy::cast_precision_loss)]
+        for (key, number) in [
+            ("peak_bytes", value.peak_bytes),
+            ("total_bytes", value.total_bytes),
+            ("allocations", value.allocations),
+        ] {
+            map.insert(key.into(), JsonValue::Number(number as f64));
+        }
+
+        JsonValue::Object(map)
+    }
+}
+
+impl TryFrom<&JsonValue> for AllocStats {
+    type Error = String;
+
+    fn try_from(value: &JsonValue) -> Result<Self, Self::Error> {
+        let json = value
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or("Expected alloc stats to be a JSON object.")?;
+
+        #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+        let number = |key: &str| {
+            json.get(key)
+                .and_then(|v| v.get::<f64>().copied())
+                .map(|x| x as u64)
+                .ok_or(format!("Expected alloc.{key} to be a number."))
+        };
+
+        Ok(AllocStats {
+            peak_bytes: number("peak_bytes")?,
+            total_bytes: number("total_bytes")?,
+            allocations: number("allocations")?,
+        })
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use tinyjson::JsonValue;
+
+    use super::{format_bytes, measure, AllocStats};
+
+    #[test]
+    fn formats_bytes() {
+        assert_eq!(format_bytes(512), "512 B");
+        assert_eq!(format_bytes(1536), "1.5 KiB");
+        assert_eq!(format_bytes(3 * 1024 * 1024), "3.0 MiB");
+    }
+
+    #[test]
+    fn round_trips_stats() {
+        let stats = AllocStats {
+            peak_bytes: 4096,
+            total_bytes: 10_000,
+            allocations: 12,
+        };
+        let json = JsonValue::from(&stats);
+        assert_eq!(AllocStats::try_from(&json).unwrap(), stats);
+    }
+
+    #[test]
+    fn measures_allocations_with_feature() {
+        let (len, stats) = measure(|| vec![0_u8; 1 << 20].len());
+        assert_eq!(len, 1 << 20);
+
+        if cfg!(feature = "alloc-stats") {
+            let stats = stats.unwrap();
+            // NOTE: other tests allocate concurrently, so only the total is exact enough.
+            assert!(stats.peak_bytes > 0);
+            assert!(stats.total_bytes >= 1 << 20);
+            assert!(stats.allocations >= 1);
+        } else {
+            assert_eq!(stats, None);
+        }
+    }
+}
@@ -0,0 +1,361 @@
+/// Accepted answers for every day and part, used by `verify` as a regression check.
+///
+/// Every year has its own file. It maps zero-padded days to their answers and is meant to be editable by hand:
+/// `{ "03": { "part_1": "189600467", "part_2": "107069718" } }`.
+/// Answers of named inputs are kept under `inputs`, e.g. `{ "03": { "inputs": { "alice": { "part_1": "1" } } } }`.
+use std::{
+    collections::{BTreeMap, BTreeSet, HashMap},
+    fs,
+    io::Error,
+    str::FromStr,
+};
+
+use tinyjson::JsonValue;
+
+use crate::template::{Day, Year};
+
+fn get_path_for_answers(year: Year) -> String {
+    format!("./data/{year}/answers.json")
+}
+
+/// Known answers of the parts of an input. Part numbers are 1 or 2.
+type PartAnswers = BTreeMap<u8, String>;
+
+/// Known answers per day of a year, for the default input and for named inputs.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Answers {
+    data: BTreeMap<Day, PartAnswers>,
+    named: BTreeMap<Day, BTreeMap<String, PartAnswers>>,
+}
+
+impl Answers {
+    /// Rehydrate answers from the JSON file. If not present, returns no answers.
+    pub fn read_from_file(year: Year) -> Result<Self, String> {
+        match fs::read_to_string(get_path_for_answers(year)) {
+            Ok(content) => Answers::try_from(content),
+            Err(_) => Ok(Answers::default()),
+        }
+    }
+
+    /// Dehydrate answers to the JSON file.
+    pub fn store_file(&self, year: Year) -> Result<(), Error> {
+        fs::write(get_path_for_answers(year), self.to_json_string())
+    }
+
+    /// Serialize to JSON with days and parts in order, so the file diffs well when edited by hand.
+    fn to_json_string(&self) -> String {
+        let days: BTreeSet<&Day> = self.data.keys().chain(self.named.keys()).collect();
+        if days.is_empty() {
+            return "{}\n".into();
+        }
+
+        let days: Vec<String> = days
+            .into_iter()
+            .map(|day| {
+                let mut entries = self
+                    .data
+                    .get(day)
+                    .map_or(vec![], |parts| parts_json(parts, 4));
+
+                if let Some(named) = self.named.get(day) {
+                    let inputs: Vec<String> = named
+                        .iter()
+                        .map(|(name, parts)| {
+                            format!(
+                                "      {}: {{\n{}\n      }}",
+                                string_json(name),
+                                parts_json(parts, 8).join(",\n")
+                            )
+                        })
+                        .collect();
+                    entries.push(format!(
+                        "    \"inputs\": {{\n{}\n    }}",
+                        inputs.join(",\n")
+                    ));
+                }
+
+                format!("  \"{day}\": {{\n{}\n  }}", entries.join(",\n"))
+            })
+            .collect();
+
+        format!("{{\n{}\n}}\n", days.join(",\n"))
+    }
+
+    pub fn get(&self, day: Day, part: u8) -> Option<&str> {
+        self.data.get(&day)?.get(&part).map(String::as_str)
+    }
+
+    pub fn set(&mut self, day: Day, part: u8, answer: &str) {
+        self.set_for_input(day, None, part, answer);
+    }
+
+    /// Known answer of a part for the named `input`, or for the default input if `None`.
+    pub fn get_for_input(&self, day: Day, input: Option<&str>, part: u8) -> Option<&str> {
+        match input {
+            Some(name) => self
+                .named
+                .get(&day)?
+                .get(name)?
+                .get(&part)
+                .map(String::as_str),
+            None => self.get(day, part),
+        }
+    }
+
+    pub fn set_for_input(&mut self, day: Day, input: Option<&str>, part: u8, answer: &str) {
+        let parts = match input {
+            Some(name) => self
+                .named
+                .entry(day)
+                .or_default()
+                .entry(name.to_string())
+                .or_default(),
+            None => self.data.entry(day).or_default(),
+        };
+        parts.insert(part, answer.to_string());
+    }
+
+    /// Compare the answer a part produced with the known answer.
+    pub fn check(&self, day: Day, part: u8, answer: Option<&str>) -> Verdict {
+        self.check_for_input(day, None, part, answer)
+    }
+
+    /// Compare the answer a part produced for the named `input` with its known answer.
+    pub fn check_for_input(
+        &self,
+        day: Day,
+        input: Option<&str>,
+        part: u8,
+        answer: Option<&str>,
+    ) -> Verdict {
+        match (self.get_for_input(day, input, part), answer) {
+            (Some(expected), Some(actual)) if expected == actual => Verdict::Pass,
+            (Some(expected), actual) => Verdict::Fail {
+                expected: expected.to_string(),
+                actual: actual.map(ToString::to_string),
+            },
+            (None, Some(_)) => Verdict::Unknown,
+            (None, None) => Verdict::Unsolved,
+        }
+    }
+
+    /// Record an accepted answer in the answers file.
+    pub fn record(year: Year, day: Day, part: u8, answer: &str) -> Result<(), String> {
+        let mut answers = Answers::read_from_file(year)?;
+        answers.set(day, part, answer);
+        answers.store_file(year).map_err(|e| e.to_string())
+    }
+}
+
+fn string_json(value: &str) -> String {
+    JsonValue::String(value.to_string())
+        .stringify()
+        .expect("strings are always serializable")
+}
+
+fn parts_json(parts: &PartAnswers, indent: usize) -> Vec<String> {
+    parts
+        .iter()
+        .map(|(part, answer)| format!("{:indent$}\"part_{part}\": {}", "", string_json(answer)))
+        .collect()
+}
+
+/// Outcome of checking a part against the known answers.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Verdict {
+    /// The part produced the known answer.
+    Pass,
+    /// The part produced a different answer, or none at all.
+    Fail {
+        expected: String,
+        actual: Option<String>,
+    },
+    /// The part produced an answer, but there is no known answer to compare it to.
+    Unknown,
+    /// The part produced no answer and there is no known answer.
+    Unsolved,
+}
+
+/* -------------------------------------------------------------------------- */
+
+impl TryFrom<String> for Answers {
+    type Error = String;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        let json = JsonValue::from_str(&value).or(Err("not valid JSON file."))?;
+
+        let days = json
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or("expected JSON document to be an object.")?;
+
+        let mut answers = Answers::default();
+
+        for (day_str, parts) in days {
+            let day = Day::from_str(day_str)
+                .map_err(|_| format!("expected `{day_str}` to be a day number."))?;
+
+            let parts = parts
+                .get::<HashMap<String, JsonValue>>()
+                .ok_or(format!("expected answers of day {day} to be an object."))?;
+
+            for (key, value) in parts {
+                if key == "inputs" {
+                    let inputs = value
+                        .get::<HashMap<String, JsonValue>>()
+                        .ok_or(format!("expected inputs of day {day} to be an object."))?;
+                    for (name, parts) in inputs {
+                        let parts = parts.get::<HashMap<String, JsonValue>>().ok_or(format!(
+                            "expected answers of day {day} input `{name}` to be an object."
+                        ))?;
+                        for (key, value) in parts {
+                            let (part, answer) = parse_part(day, key, value)?;
+                            answers.set_for_input(day, Some(name), part, answer);
+                        }
+                    }
+                } else {
+                    let (part, answer) = parse_part(day, key, value)?;
+                    answers.set(day, part, answer);
+                }
+            }
+        }
+
+        Ok(answers)
+    }
+}
+
+fn parse_part<'a>(day: Day, key: &str, value: &'a JsonValue) -> Result<(u8, &'a str), String> {
+    let part = match key {
+        "part_1" => 1,
+        "part_2" => 2,
+        x => return Err(format!("unknown key `{x}` in answers of day {day}.")),
+    };
+    let answer = value.get::<String>().ok_or(format!(
+        "expected answer of day {day} {key} to be a string."
+    ))?;
+    Ok((part, answer))
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{Answers, Verdict};
+    use crate::day;
+
+    #[test]
+    fn handles_json_answers() {
+        let json = r#"{ "03": { "part_1": "189600467", "part_2": "107069718" }, "24": { "part_2": "a,b" } }"#;
+        let answers = Answers::try_from(json.to_string()).unwrap();
+        assert_eq!(answers.get(day!(3), 1), Some("189600467"));
+        assert_eq!(answers.get(day!(24), 1), None);
+        assert_eq!(answers.get(day!(24), 2), Some("a,b"));
+    }
+
+    #[test]
+    fn round_trips_answers() {
+        let mut answers = Answers::default();
+        answers.set(day!(1), 1, "11");
+        answers.set(day!(1), 2, "31");
+        answers.set(day!(1), 2, "32");
+        let json = answers.to_json_string();
+        assert_eq!(Answers::try_from(json).unwrap(), answers);
+        assert_eq!(answers.get(day!(1), 2), Some("32"));
+    }
+
+    #[test]
+    fn serializes_in_order() {
+        let mut answers = Answers::default();
+        answers.set(day!(2), 1, "\"quoted\"");
+        answers.set(day!(1), 2, "31");
+        answers.set(day!(1), 1, "11");
+        assert_eq!(
+            answers.to_json_string(),
+            [
+                "{",
+                r#"  "01": {"#,
+                r#"    "part_1": "11","#,
+                r#"    "part_2": "31""#,
+                "  },",
+                r#"  "02": {"#,
+                r#"    "part_1": "\"quoted\"""#,
+                "  }",
+                "}",
+                "",
+            ]
+            .join("\n")
+        );
+    }
+
+    #[test]
+    fn handles_answers_of_named_inputs() {
+        let json = r#"{ "17": { "part_1": "1", "inputs": { "alice": { "part_2": "2" } } }, "18": { "inputs": { "bob": { "part_1": "3" } } } }"#;
+        let answers = Answers::try_from(json.to_string()).unwrap();
+        assert_eq!(answers.get(day!(17), 1), Some("1"));
+        assert_eq!(answers.get_for_input(day!(17), Some("alice"), 1), None);
+        assert_eq!(answers.get_for_input(day!(17), Some("alice"), 2), Some("2"));
+        assert_eq!(answers.get_for_input(day!(18), Some("bob"), 1), Some("3"));
+        assert_eq!(answers.get(day!(18), 1), None);
+        assert_eq!(
+            answers.check_for_input(day!(18), Some("bob"), 1, Some("3")),
+            Verdict::Pass
+        );
+        assert_eq!(
+            Answers::try_from(answers.to_json_string()).unwrap(),
+            answers
+        );
+    }
+
+    #[test]
+    fn serializes_named_inputs_in_order() {
+        let mut answers = Answers::default();
+        answers.set_for_input(day!(1), Some("bob"), 1, "2");
+        answers.set_for_input(day!(1), Some("alice"), 1, "1");
+        answers.set(day!(1), 1, "11");
+        assert_eq!(
+            answers.to_json_string(),
+            [
+                "{",
+                r#"  "01": {"#,
+                r#"    "part_1": "11","#,
+                r#"    "inputs": {"#,
+                r#"      "alice": {"#,
+                r#"        "part_1": "1""#,
+                "      },",
+                r#"      "bob": {"#,
+                r#"        "part_1": "2""#,
+                "      }",
+                "    }",
+                "  }",
+                "}",
+                "",
+            ]
+            .join("\n")
+        );
+    }
+
+    #[test]
+    fn checks_answers() {
+        let mut answers = Answers::default();
+        answers.set(day!(1), 1, "11");
+        assert_eq!(answers.check(day!(1), 1, Some("11")), Verdict::Pass);
+        assert_eq!(
+            answers.check(day!(1), 1, Some("12")),
+            Verdict::Fail {
+                expected: "11".into(),
+                actual: Some("12".into())
+            }
+        );
+        assert!(matches!(
+            answers.check(day!(1), 1, None),
+            Verdict::Fail { actual: None, .. }
+        ));
+        assert_eq!(answers.check(day!(1), 2, Some("31")), Verdict::Unknown);
+        assert_eq!(answers.check(day!(2), 1, None), Verdict::Unsolved);
+    }
+
+    #[test]
+    #[should_panic]
+    fn panics_for_unknown_parts() {
+        Answers::try_from(r#"{ "01": { "part_3": "1" } }"#.to_string()).unwrap();
+    }
+}
@@ -0,0 +1,488 @@
+/// Built-in client for the Advent of Code website.
+///
+/// Reads the session cookie the same way aoc-cli does, so an existing aoc-cli setup keeps working
+/// without the `aoc` binary.
+use std::{
+    env,
+    fmt::Display,
+    fs, io,
+    path::{Path, PathBuf},
+    time::Duration,
+};
+
+use crate::template::markdown::{articles_to_markdown, to_markdown};
+use crate::template::{Day, Year};
+
+/// Environment variable that overrides the website, e.g. to test against a local server.
+pub const BASE_URL_ENV: &str = "AOC_BASE_URL";
+/// Environment variable that holds the session cookie. Takes precedence over the session file.
+pub const SESSION_ENV: &str = "ADVENT_OF_CODE_SESSION";
+
+const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+const SESSION_FILE_NAME: &str = ".adventofcode.session";
+const USER_AGENT: &str = "github.com/penelopeysm/aoc24-rs";
+
+#[derive(Debug)]
+pub enum AocClientError {
+    MissingSession,
+    /// The website answered with an error status.
+    Http {
+        status: u16,
+        body: String,
+    },
+    /// The request could not be sent or the response could not be read.
+    Transport(String),
+    /// The response to a submission could not be classified.
+    UnexpectedResponse(String),
+    IO(io::Error),
+}
+
+impl Display for AocClientError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            AocClientError::MissingSession => write!(
+                f,
+                "no session cookie found. Set {SESSION_ENV} or write it to ~/{SESSION_FILE_NAME}."
+            ),
+            AocClientError::Http { status, body } => {
+                write!(f, "request failed with status {status}: {}", body.trim())
+            }
+            AocClientError::Transport(e) => write!(f, "request failed: {e}"),
+            AocClientError::UnexpectedResponse(_) => {
+                write!(f, "could not tell whether the answer was accepted.")
+            }
+            AocClientError::IO(e) => write!(f, "{e}"),
+        }
+    }
+}
+
+impl From<io::Error> for AocClientError {
+    fn from(e: io::Error) -> Self {
+        AocClientError::IO(e)
+    }
+}
+
+impl From<ureq::Error> for AocClientError {
+    fn from(e: ureq::Error) -> Self {
+        match e {
+            ureq::Error::Status(status, response) => AocClientError::Http {
+                status,
+                body: response.into_string().unwrap_or_default(),
+            },
+            ureq::Error::Transport(e) => AocClientError::Transport(e.to_string()),
+        }
+    }
+}
+
+pub struct AocClient {
+    base_url: String,
+    session: String,
+    agent: ureq::Agent,
+}
+
+impl AocClient {
+    pub fn new(base_url: &str, session: &str) -> Self {
+        AocClient {
+            base_url: base_url.trim_end_matches('/').to_string(),
+            session: session.trim().to_string(),
+            agent: ureq::AgentBuilder::new()
+                .user_agent(USER_AGENT)
+                .timeout(Duration::from_secs(30))
+                .build(),
+        }
+    }
+
+    /// Build a client from `AOC_BASE_URL` and the session cookie.
+    pub fn from_env() -> Result<Self, AocClientError> {
+        let base_url = env::var(BASE_URL_ENV).unwrap_or_else(|_| DEFAULT_BASE_URL.into());
+        let session = read_session().ok_or(AocClientError::MissingSession)?;
+        Ok(AocClient::new(&base_url, &session))
+    }
+
+    /// Fetch the personal puzzle input.
+    pub fn get_input(&self, year: Year, day: Day) -> Result<String, AocClientError> {
+        self.get(&format!("/{year}/day/{}/input", day.into_inner()))
+    }
+
+    /// Fetch the puzzle description as markdown.
+    pub fn get_puzzle(&self, year: Year, day: Day) -> Result<String, AocClientError> {
+        let html = self.get(&format!("/{year}/day/{}", day.into_inner()))?;
+        Ok(articles_to_markdown(&html))
+    }
+
+    /// Post an answer and return the website's response as markdown.
+    pub fn submit(
+        &self,
+        year: Year,
+        day: Day,
+        part: u8,
+        answer: &str,
+    ) -> Result<String, AocClientError> {
+        let url = format!("{}/{year}/day/{}/answer", self.base_url, day.into_inner());
+        let html = self
+            .agent
+            .post(&url)
+            .set("Cookie", &self.cookie())
+            .send_form(&[("level", &part.to_string()), ("answer", answer)])?
+            .into_string()?;
+
+        let article = articles_to_markdown(&html);
+        Ok(if article.is_empty() {
+            to_markdown(&html)
+        } else {
+            article
+        })
+    }
+
+    fn get(&self, path: &str) -> Result<String, AocClientError> {
+        Ok(self
+            .agent
+            .get(&format!("{}{path}", self.base_url))
+            .set("Cookie", &self.cookie())
+            .call()?
+            .into_string()?)
+    }
+
+    fn cookie(&self) -> String {
+        format!("session={}", self.session)
+    }
+}
+
+/// Which way a wrong answer was off, if the website said so.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Hint {
+    TooHigh,
+    TooLow,
+}
+
+/// What the website made of a submitted answer.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum SubmitOutcome {
+    Correct,
+    Incorrect {
+        hint: Option<Hint>,
+    },
+    /// An answer was submitted too recently. The answer was not checked.
+    RateLimited {
+        wait: Duration,
+    },
+    /// The part has already been solved. The answer was not checked.
+    AlreadySolved,
+}
+
+impl SubmitOutcome {
+    /// Classify the response to a submission. Returns `None` if the response is not recognized.
+    pub fn from_response(response: &str) -> Option<Self> {
+        if response.contains("That's the right answer") {
+            Some(SubmitOutcome::Correct)
+        } else if response.contains("not the right answer") {
+            let hint = if response.contains("too high") {
+                Some(Hint::TooHigh)
+            } else if response.contains("too low") {
+                Some(Hint::TooLow)
+            } else {
+                None
+            };
+            Some(SubmitOutcome::Incorrect { hint })
+        } else if response.contains("answer too recently") {
+            Some(SubmitOutcome::RateLimited {
+                wait: parse_wait(response).unwrap_or_default(),
+            })
+        } else if response.contains("Did you already complete it?") {
+            Some(SubmitOutcome::AlreadySolved)
+        } else {
+            None
+        }
+    }
+}
+
+/// Parse the remaining wait from e.g. "You have 1m 23s left to wait."
+fn parse_wait(response: &str) -> Option<Duration> {
+    let end = response.find(" left to wait")?;
+    let start = response[..end].rfind("You have ")? + "You have ".len();
+
+    response[start..end]
+        .split_whitespace()
+        .map(|x| {
+            let unit = x.chars().last()?;
+            let value: u64 = x[..x.len() - 1].parse().ok()?;
+            match unit {
+                'h' => Some(value * 3600),
+                'm' => Some(value * 60),
+                's' => Some(value),
+                _ => None,
+            }
+        })
+        .sum::<Option<u64>>()
+        .map(Duration::from_secs)
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// Download the input and puzzle description of a day.
+pub fn download(year: Year, day: Day) -> Result<(), AocClientError> {
+    let client = AocClient::from_env()?;
+    let input_path = get_input_path(year, day);
+    let puzzle_path = get_puzzle_path(year, day);
+
+    write_file(&input_path, &client.get_input(year, day)?)?;
+    write_file(&puzzle_path, &client.get_puzzle(year, day)?)?;
+
+    println!("🎄 Successfully wrote input to \"{}\".", &input_path);
+    println!("🎄 Successfully wrote puzzle to \"{}\".", &puzzle_path);
+    Ok(())
+}
+
+/// Fetch the puzzle description of a day, store it and print it.
+pub fn read(year: Year, day: Day) -> Result<(), AocClientError> {
+    let client = AocClient::from_env()?;
+    let puzzle = client.get_puzzle(year, day)?;
+    write_file(&get_puzzle_path(year, day), &puzzle)?;
+    println!("{puzzle}");
+    Ok(())
+}
+
+/// Submit an answer. The response is printed and classified.
+pub fn submit(
+    year: Year,
+    day: Day,
+    part: u8,
+    answer: &str,
+) -> Result<SubmitOutcome, AocClientError> {
+    let response = AocClient::from_env()?.submit(year, day, part, answer)?;
+    println!("{response}");
+    SubmitOutcome::from_response(&response).ok_or(AocClientError::UnexpectedResponse(response))
+}
+
+/// Write a file, creating the data directories of a new year as needed.
+fn write_file(path: &str, content: &str) -> io::Result<()> {
+    if let Some(parent) = Path::new(path).parent() {
+        fs::create_dir_all(parent)?;
+    }
+    fs::write(path, content)
+}
+
+fn get_input_path(year: Year, day: Day) -> String {
+    format!("data/{year}/inputs/{day}.txt")
+}
+
+#[must_use]
+pub fn get_puzzle_path(year: Year, day: Day) -> String {
+    format!("data/{year}/puzzles/{day}.md")
+}
+
+/// Read the session cookie from the environment, `~/.adventofcode.session` or the config directory.
+fn read_session() -> Option<String> {
+    if let Ok(session) = env::var(SESSION_ENV) {
+        return Some(session);
+    }
+
+    let home = env::var_os("HOME").map(PathBuf::from);
+    let config = env::var_os("XDG_CONFIG_HOME")
+        .map(PathBuf::from)
+        .or_else(|| home.as_ref().map(|h| h.join(".config")));
+
+    [
+        home.map(|h| h.join(SESSION_FILE_NAME)),
+        config.map(|c| c.join("adventofcode.session")),
+    ]
+    .into_iter()
+    .flatten()
+    .find_map(|path| read_session_file(&path))
+}
+
+fn read_session_file(path: &Path) -> Option<String> {
+    let session = fs::read_to_string(path).ok()?;
+    let session = session.trim();
+    (!session.is_empty()).then(|| session.to_string())
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use std::time::Duration;
+
+    use super::{AocClient, AocClientError, Hint, SubmitOutcome};
+    use crate::{day, year};
+
+    /// A stand-in for the website that serves canned responses and records the requests it receives.
+    pub mod server {
+        use std::{
+            io::{BufRead, BufReader, Read, Write},
+            net::TcpListener,
+            sync::{Arc, Mutex},
+            thread,
+        };
+
+        /// A request as seen by the server: request line, cookie header and body.
+        #[derive(Clone, Debug, Default)]
+        pub struct Request {
+            pub line: String,
+            pub cookie: String,
+            pub body: String,
+        }
+
+        pub struct Server {
+            pub url: String,
+            pub requests: Arc<Mutex<Vec<Request>>>,
+        }
+
+        /// Serve `routes` of `(request line prefix, status, body)` on a random local port.
+        pub fn serve(routes: Vec<(&'static str, u16, &'static str)>) -> Server {
+            let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+            let url = format!("http://{}", listener.local_addr().unwrap());
+            let requests = Arc::new(Mutex::new(vec![]));
+            let recorded = Arc::clone(&requests);
+
+            thread::spawn(move || {
+                for stream in listener.incoming() {
+                    let mut stream = stream.unwrap();
+                    let mut reader = BufReader::new(stream.try_clone().unwrap());
+                    let mut request = Request::default();
+                    let mut content_length = 0;
+
+                    reader.read_line(&mut request.line).unwrap();
+                    loop {
+                        let mut header = String::new();
+                        reader.read_line(&mut header).unwrap();
+                        let header = header.trim_end();
+                        if header.is_empty() {
+                            break;
+                        }
+                        let (name, value) = header.split_once(": ").unwrap();
+                        match name.to_ascii_lowercase().as_str() {
+                            "cookie" => request.cookie = value.to_string(),
+                            "content-length" => content_length = value.parse().unwrap(),
+                            _ => {}
+                        }
+                    }
+                    let mut body = vec![0; content_length];
+                    reader.read_exact(&mut body).unwrap();
+                    request.body = String::from_utf8(body).unwrap();
+
+                    let (status, body) = routes
+                        .iter()
+                        .find(|(prefix, _, _)| request.line.starts_with(prefix))
+                        .map_or((404, "Not Found"), |(_, status, body)| (*status, *body));
+
+                    recorded.lock().unwrap().push(request);
+
+                    write!(
+                        stream,
+                        "HTTP/1.1 {status} Status\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+                        body.len()
+                    )
+                    .unwrap();
+                }
+            });
+
+            Server { url, requests }
+        }
+    }
+
+    #[test]
+    fn fetches_input_with_session() {
+        let server = server::serve(vec![("GET /2024/day/5/input ", 200, "1 2\n3 4\n")]);
+        let client = AocClient::new(&server.url, "secret\n");
+
+        assert_eq!(
+            client.get_input(year!(2024), day!(5)).unwrap(),
+            "1 2\n3 4\n"
+        );
+        let requests = server.requests.lock().unwrap();
+        assert_eq!(requests[0].cookie, "session=secret");
+    }
+
+    #[test]
+    fn fetches_puzzle_as_markdown() {
+        let server = server::serve(vec![(
+            "GET /2024/day/1 ",
+            200,
+            "<main><article><h2>--- Day 1 ---</h2><p>Hi <em>there</em>.</p></article></main>",
+        )]);
+        let client = AocClient::new(&server.url, "secret");
+
+        assert_eq!(
+            client.get_puzzle(year!(2024), day!(1)).unwrap(),
+            "## --- Day 1 ---\n\nHi *there*."
+        );
+    }
+
+    #[test]
+    fn posts_answers() {
+        let server = server::serve(vec![(
+            "POST /2024/day/3/answer ",
+            200,
+            "<main><article><p>That's the right answer! You are <em>one gold star</em> closer.</p></article></main>",
+        )]);
+        let client = AocClient::new(&server.url, "secret");
+
+        let response = client.submit(year!(2024), day!(3), 2, "1 + 1").unwrap();
+        assert_eq!(
+            SubmitOutcome::from_response(&response),
+            Some(SubmitOutcome::Correct)
+        );
+        let requests = server.requests.lock().unwrap();
+        assert_eq!(requests[0].body, "level=2&answer=1+%2B+1");
+    }
+
+    #[test]
+    fn reports_http_errors() {
+        let server = server::serve(vec![(
+            "GET /2024/day/6/input ",
+            400,
+            "Puzzle inputs differ by user.  Please log in to get your puzzle input.",
+        )]);
+        let client = AocClient::new(&server.url, "expired");
+
+        match client.get_input(year!(2024), day!(6)) {
+            Err(AocClientError::Http { status, body }) => {
+                assert_eq!(status, 400);
+                assert!(body.contains("Please log in"));
+            }
+            x => panic!("expected an HTTP error, got {x:?}"),
+        }
+    }
+
+    #[test]
+    fn classifies_submission_responses() {
+        let cases = [
+            (
+                "That's not the right answer; your answer is too high. If you're stuck, make sure you're using the full input data.",
+                Some(SubmitOutcome::Incorrect {
+                    hint: Some(Hint::TooHigh),
+                }),
+            ),
+            (
+                "That's not the right answer; your answer is too low.",
+                Some(SubmitOutcome::Incorrect {
+                    hint: Some(Hint::TooLow),
+                }),
+            ),
+            (
+                "That's not the right answer. If you're stuck, make sure you're using the full input data.",
+                Some(SubmitOutcome::Incorrect { hint: None }),
+            ),
+            (
+                "You gave an answer too recently; you have to wait after submitting an answer before trying again. You have 1m 23s left to wait. [[Return to Day 1]](/2024/day/1)",
+                Some(SubmitOutcome::RateLimited {
+                    wait: Duration::from_secs(83),
+                }),
+            ),
+            (
+                "You don't seem to be solving the right level. Did you already complete it? [[Return to Day 1]](/2024/day/1)",
+                Some(SubmitOutcome::AlreadySolved),
+            ),
+            ("Something else entirely.", None),
+        ];
+
+        for (response, outcome) in cases {
+            assert_eq!(
+                SubmitOutcome::from_response(response),
+                outcome,
+                "{response}"
+            );
+        }
+    }
+}
@@ -0,0 +1,355 @@
+/// History of submitted answers, used to avoid submitting answers that are known to be wrong.
+///
+/// Every submission is appended to the file of its year as one JSON line, regardless of its outcome.
+use std::{
+    collections::HashMap,
+    fmt::Display,
+    fs::{self, OpenOptions},
+    io::{self, Write},
+    path::Path,
+    str::FromStr,
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+use tinyjson::JsonValue;
+
+use crate::template::aoc_client::{Hint, SubmitOutcome};
+use crate::template::{Day, Year};
+
+/// A single submission of an answer.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Attempt {
+    pub day: Day,
+    pub part: u8,
+    pub answer: String,
+    pub outcome: SubmitOutcome,
+    /// Seconds since the unix epoch.
+    pub timestamp: u64,
+}
+
+impl Attempt {
+    pub fn new(day: Day, part: u8, answer: &str, outcome: SubmitOutcome) -> Self {
+        Attempt {
+            day,
+            part,
+            answer: answer.to_string(),
+            outcome,
+            timestamp: SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .map_or(0, |d| d.as_secs()),
+        }
+    }
+
+    /// Append this attempt to the attempts file of `year`.
+    pub fn record(&self, year: Year) -> io::Result<()> {
+        let mut file = OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(get_path_for_attempts(year))?;
+        let line = JsonValue::from(self)
+            .stringify()
+            .map_err(io::Error::other)?;
+        writeln!(file, "{line}")
+    }
+}
+
+fn get_path_for_attempts(year: Year) -> String {
+    format!("./data/{year}/attempts.jsonl")
+}
+
+/// All attempts made so far in a year.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Attempts {
+    attempts: Vec<Attempt>,
+}
+
+impl Attempts {
+    /// Rehydrate attempts from the JSON-lines file. If not present, returns no attempts.
+    pub fn read_from_file(year: Year) -> Result<Self, String> {
+        Attempts::read_from_path(Path::new(&get_path_for_attempts(year)))
+    }
+
+    /// Rehydrate attempts from the JSON-lines file at `path`. If not present, returns no attempts.
+    pub fn read_from_path(path: &Path) -> Result<Self, String> {
+        match fs::read_to_string(path) {
+            Ok(content) => Attempts::from_str(&content),
+            Err(_) => Ok(Attempts::default()),
+        }
+    }
+
+    fn of(&self, day: Day, part: u8) -> impl Iterator<Item = &Attempt> {
+        self.attempts
+            .iter()
+            .filter(move |a| a.day == day && a.part == part)
+    }
+
+    /// Bounds learned from too-high and too-low hints, as the exclusive range `(low, high)`.
+    pub fn bounds(&self, day: Day, part: u8) -> (Option<i128>, Option<i128>) {
+        let mut low: Option<i128> = None;
+        let mut high: Option<i128> = None;
+
+        for attempt in self.of(day, part) {
+            let SubmitOutcome::Incorrect { hint: Some(hint) } = attempt.outcome else {
+                continue;
+            };
+            let Ok(value) = attempt.answer.trim().parse::<i128>() else {
+                continue;
+            };
+            match hint {
+                Hint::TooHigh => high = Some(high.map_or(value, |h| h.min(value))),
+                Hint::TooLow => low = Some(low.map_or(value, |l| l.max(value))),
+            }
+        }
+
+        (low, high)
+    }
+
+    /// Check whether an answer is worth submitting, given earlier attempts.
+    pub fn check(&self, day: Day, part: u8, answer: &str) -> Result<(), Refusal> {
+        if self
+            .of(day, part)
+            .any(|a| a.answer == answer && matches!(a.outcome, SubmitOutcome::Incorrect { .. }))
+        {
+            return Err(Refusal::KnownWrong);
+        }
+
+        if let Ok(value) = answer.trim().parse::<i128>() {
+            match self.bounds(day, part) {
+                (_, Some(high)) if value >= high => return Err(Refusal::TooHigh { bound: high }),
+                (Some(low), _) if value <= low => return Err(Refusal::TooLow { bound: low }),
+                _ => {}
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// Reason for not submitting an answer.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Refusal {
+    /// The same answer was already rejected.
+    KnownWrong,
+    /// The answer is not below an answer that was too high.
+    TooHigh { bound: i128 },
+    /// The answer is not above an answer that was too low.
+    TooLow { bound: i128 },
+}
+
+impl Display for Refusal {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Refusal::KnownWrong => write!(f, "this answer was already rejected."),
+            Refusal::TooHigh { bound } => {
+                write!(
+                    f,
+                    "the answer must be lower than {bound}, which was too high."
+                )
+            }
+            Refusal::TooLow { bound } => {
+                write!(
+                    f,
+                    "the answer must be higher than {bound}, which was too low."
+                )
+            }
+        }
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+impl FromStr for Attempts {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let attempts = s
+            .lines()
+            .filter(|l| !l.trim().is_empty())
+            .map(|l| {
+                let json = JsonValue::from_str(l).or(Err("attempt line is not valid JSON."))?;
+                Attempt::try_from(&json)
+            })
+            .collect::<Result<_, _>>()?;
+
+        Ok(Attempts { attempts })
+    }
+}
+
+impl From<&Attempt> for JsonValue {
+    fn from(value: &Attempt) -> Self {
+        let mut map: HashMap<String, JsonValue> = HashMap::new();
+
+        map.insert("day".into(), JsonValue::String(value.day.to_string()));
+        map.insert("part".into(), JsonValue::Number(f64::from(value.part)));
+        map.insert("answer".into(), JsonValue::String(value.answer.clone()));
+
+        let outcome = match value.outcome {
+            SubmitOutcome::Correct => "correct",
+            SubmitOutcome::Incorrect { hint: None } => "incorrect",
+            SubmitOutcome::Incorrect {
+                hint: Some(Hint::TooHigh),
+            } => "too_high",
+            SubmitOutcome::Incorrect {
+                hint: Some(Hint::TooLow),
+            } => "too_low",
+            SubmitOutcome::RateLimited { wait } => {
+                #[allow(clippy::cast_precision_loss)]
+                map.insert("wait".into(), JsonValue::Number(wait.as_secs() as f64));
+                "rate_limited"
+            }
+            SubmitOutcome::AlreadySolved => "already_solved",
+        };
+        map.insert("outcome".into(), JsonValue::String(outcome.into()));
+
+        #[allow(clippy::cast_precision_loss)]
+        map.insert(
+            "timestamp".into(),
+            JsonValue::Number(value.timestamp as f64),
+        );
+
+        JsonValue::Object(map)
+    }
+}
+
+impl TryFrom<&JsonValue> for Attempt {
+    type Error = String;
+
+    fn try_from(value: &JsonValue) -> Result<Self, Self::Error> {
+        let json = value
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or("Expected attempt to be a JSON object.")?;
+
+        let string = |key: &str| {
+            json.get(key)
+                .and_then(|v| v.get::<String>())
+                .ok_or(format!("Expected attempt.{key} to be a string."))
+        };
+        let number = |key: &str| {
+            json.get(key)
+                .and_then(|v| v.get::<f64>().copied())
+                .ok_or(format!("Expected attempt.{key} to be a number."))
+        };
+
+        let day = string("day")?;
+        let day =
+            Day::from_str(day).map_err(|_| format!("expected `{day}` to be a day number."))?;
+
+        let outcome = match string("outcome")?.as_str() {
+            "correct" => SubmitOutcome::Correct,
+            "incorrect" => SubmitOutcome::Incorrect { hint: None },
+            "too_high" => SubmitOutcome::Incorrect {
+                hint: Some(Hint::TooHigh),
+            },
+            "too_low" => SubmitOutcome::Incorrect {
+                hint: Some(Hint::TooLow),
+            },
+            #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+            "rate_limited" => SubmitOutcome::RateLimited {
+                wait: Duration::from_secs(number("wait").unwrap_or_default() as u64),
+            },
+            "already_solved" => SubmitOutcome::AlreadySolved,
+            x => return Err(format!("unknown attempt outcome `{x}`.")),
+        };
+
+        #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+        Ok(Attempt {
+            day,
+            part: number("part")? as u8,
+            answer: string("answer")?.clone(),
+            outcome,
+            timestamp: number("timestamp")? as u64,
+        })
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use std::{str::FromStr, time::Duration};
+
+    use tinyjson::JsonValue;
+
+    use super::{Attempt, Attempts, Refusal};
+    use crate::day;
+    use crate::template::aoc_client::{Hint, SubmitOutcome};
+
+    fn attempts(answers: &[(&str, SubmitOutcome)]) -> Attempts {
+        Attempts {
+            attempts: answers
+                .iter()
+                .map(|(answer, outcome)| Attempt::new(day!(1), 1, answer, outcome.clone()))
+                .collect(),
+        }
+    }
+
+    fn incorrect(hint: Option<Hint>) -> SubmitOutcome {
+        SubmitOutcome::Incorrect { hint }
+    }
+
+    #[test]
+    fn round_trips_attempts() {
+        let outcomes = [
+            SubmitOutcome::Correct,
+            incorrect(None),
+            incorrect(Some(Hint::TooHigh)),
+            incorrect(Some(Hint::TooLow)),
+            SubmitOutcome::RateLimited {
+                wait: Duration::from_secs(42),
+            },
+            SubmitOutcome::AlreadySolved,
+        ];
+        let lines: Vec<String> = outcomes
+            .into_iter()
+            .map(|outcome| {
+                let attempt = Attempt::new(day!(7), 2, "1,2", outcome);
+                JsonValue::from(&attempt).stringify().unwrap()
+            })
+            .collect();
+
+        let parsed = Attempts::from_str(&lines.join("\n")).unwrap();
+        assert_eq!(parsed.attempts.len(), 6);
+        assert_eq!(
+            parsed.attempts[4].outcome,
+            SubmitOutcome::RateLimited {
+                wait: Duration::from_secs(42)
+            }
+        );
+    }
+
+    #[test]
+    fn refuses_known_wrong_answers() {
+        let attempts = attempts(&[
+            ("abc", incorrect(None)),
+            (
+                "def",
+                SubmitOutcome::RateLimited {
+                    wait: Duration::from_secs(5),
+                },
+            ),
+        ]);
+        assert_eq!(attempts.check(day!(1), 1, "abc"), Err(Refusal::KnownWrong));
+        assert_eq!(attempts.check(day!(1), 1, "def"), Ok(()));
+        assert_eq!(attempts.check(day!(1), 2, "abc"), Ok(()));
+    }
+
+    #[test]
+    fn refuses_answers_outside_bounds() {
+        let attempts = attempts(&[
+            ("100", incorrect(Some(Hint::TooHigh))),
+            ("150", incorrect(Some(Hint::TooHigh))),
+            ("20", incorrect(Some(Hint::TooLow))),
+        ]);
+        assert_eq!(attempts.bounds(day!(1), 1), (Some(20), Some(100)));
+        assert_eq!(
+            attempts.check(day!(1), 1, "120"),
+            Err(Refusal::TooHigh { bound: 100 })
+        );
+        assert_eq!(
+            attempts.check(day!(1), 1, "-5"),
+            Err(Refusal::TooLow { bound: 20 })
+        );
+        assert_eq!(attempts.check(day!(1), 1, "99"), Ok(()));
+        assert_eq!(attempts.check(day!(1), 1, "not a number"), Ok(()));
+    }
+}
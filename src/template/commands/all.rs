@@ -1,5 +1,26 @@
-use crate::template::{all_days, run_multi::run_multi};
+use std::path::Path;
 
-pub fn handle(is_release: bool) {
-    run_multi(&all_days().collect(), is_release, false);
+use crate::template::inputs::Inputs;
+use crate::template::{all_days, limits::Limits, registry::Solution, run_multi::run_multi, Year};
+
+pub fn handle(
+    solutions: &[Solution],
+    year: Year,
+    is_release: bool,
+    isolated: bool,
+    jobs: usize,
+    limits: Limits,
+    inputs: Option<&Path>,
+) {
+    run_multi(
+        solutions,
+        year,
+        &all_days(year).collect(),
+        is_release,
+        None,
+        isolated,
+        jobs,
+        limits,
+        inputs.map_or(Inputs::Default, Inputs::Dir),
+    );
 }
@@ -0,0 +1,112 @@
+use std::{fs, path::Path, process};
+
+use tinyjson::JsonValue;
+
+use crate::template::aoc_client::get_puzzle_path;
+use crate::template::example_tests::get_path_for_answers;
+use crate::template::examples::{self, Examples};
+use crate::template::{Day, Year};
+
+/// Extract the examples of a day from its downloaded puzzle and write them to the example files.
+pub fn handle(year: Year, day: Day, overwrite: bool) {
+    let Some(examples) = read(year, day) else {
+        eprintln!(
+            "No puzzle found at \"{}\". Run `cargo download {day} --year {year}` first.",
+            get_puzzle_path(year, day)
+        );
+        process::exit(1);
+    };
+
+    for (part, example) in [(1, &examples.part_1), (2, &examples.part_2)] {
+        println!("Part {part}:");
+        for (index, input) in example.inputs.iter().enumerate() {
+            println!("--- example {} ---", index + 1);
+            print!("{input}");
+        }
+        match &example.answer {
+            Some(answer) => println!("Expected answer: {answer}"),
+            None => println!("No expected answer found."),
+        }
+        println!();
+    }
+
+    if let Err(e) = write(year, day, &examples, overwrite) {
+        eprintln!("Failed to write example file: {e}");
+        process::exit(1);
+    }
+}
+
+/// Read and extract the examples of a day. Returns `None` if the puzzle was not downloaded.
+pub fn read(year: Year, day: Day) -> Option<Examples> {
+    let puzzle = fs::read_to_string(get_puzzle_path(year, day)).ok()?;
+    Some(examples::extract(&puzzle))
+}
+
+/// Write the first example to `{day}.txt`, and a different example of part two to `{day}-2.txt`.
+/// The expected answers of an example are written to its sidecar file, e.g. `{day}.json`.
+/// Existing, non-empty example files are kept unless `overwrite` is set.
+pub fn write(
+    year: Year,
+    day: Day,
+    examples: &Examples,
+    overwrite: bool,
+) -> Result<(), std::io::Error> {
+    let mut files = vec![(
+        format!("data/{year}/examples/{day}.txt"),
+        examples.input(1),
+        vec![(1, &examples.part_1.answer), (2, &examples.part_2.answer)],
+    )];
+    if examples.has_separate_part_2() {
+        files[0].2.pop();
+        files.push((
+            format!("data/{year}/examples/{day}-2.txt"),
+            examples.input(2),
+            vec![(2, &examples.part_2.answer)],
+        ));
+    }
+
+    for (path, input, answers) in files {
+        let Some(input) = input else {
+            println!("No example input found for \"{path}\".");
+            continue;
+        };
+
+        let is_empty = fs::read_to_string(&path).map_or(true, |x| x.trim().is_empty());
+        if !is_empty && !overwrite {
+            println!("Kept existing example file \"{path}\". Pass `--overwrite` to replace it.");
+            continue;
+        }
+
+        if let Some(parent) = Path::new(&path).parent() {
+            fs::create_dir_all(parent)?;
+        }
+        fs::write(&path, input)?;
+        println!("Wrote example file \"{path}\"");
+
+        let answers: Vec<(u8, &String)> = answers
+            .into_iter()
+            .filter_map(|(part, answer)| Some((part, answer.as_ref()?)))
+            .collect();
+        if !answers.is_empty() {
+            let answers_path = get_path_for_answers(Path::new(&path));
+            fs::write(&answers_path, answers_json(&answers))?;
+            println!("Wrote expected answers \"{}\"", answers_path.display());
+        }
+    }
+
+    Ok(())
+}
+
+/// Serialize the expected answers of an example, e.g. `{ "part_1": "11" }`.
+fn answers_json(answers: &[(u8, &String)]) -> String {
+    let parts: Vec<String> = answers
+        .iter()
+        .map(|(part, answer)| {
+            let answer = JsonValue::String((*answer).clone())
+                .stringify()
+                .expect("strings are always serializable");
+            format!("  \"part_{part}\": {answer}")
+        })
+        .collect();
+    format!("{{\n{}\n}}\n", parts.join(",\n"))
+}
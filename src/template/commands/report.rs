@@ -0,0 +1,27 @@
+use std::{fs, process};
+
+use crate::template::history::History;
+use crate::template::timings::Timings;
+use crate::template::{report, Year};
+
+fn get_path_for_report(year: Year) -> String {
+    format!("./data/{year}/report.html")
+}
+
+pub fn handle(year: Year, output: Option<String>) {
+    let timings = Timings::read_from_file(year);
+
+    let history = History::read_from_file(year).unwrap_or_else(|e| {
+        eprintln!("Failed to read benchmark history, leaving it out: {e}");
+        History::default()
+    });
+
+    let path = output.unwrap_or_else(|| get_path_for_report(year));
+    match fs::write(&path, report::render(year, &timings, &history)) {
+        Ok(()) => println!("Wrote benchmark report to {path}."),
+        Err(e) => {
+            eprintln!("Failed to write benchmark report: {e}");
+            process::exit(1);
+        }
+    }
+}
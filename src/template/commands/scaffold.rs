@@ -1,13 +1,17 @@
 use std::{
-    fs::{File, OpenOptions},
+    collections::HashMap,
+    fs::{self, File, OpenOptions},
     io::Write,
+    path::Path,
     process,
 };
 
-use crate::template::Day;
+use crate::template::commands::examples;
+use crate::template::examples::Examples;
+use crate::template::templates::{unknown_variables, Template, TEMPLATES_DIR};
 
-const MODULE_TEMPLATE: &str =
-    include_str!(concat!(env!("CARGO_MANIFEST_DIR"), "/src/template.txt"));
+pub use crate::template::templates::DEFAULT_TEMPLATE;
+use crate::template::{Day, Year};
 
 fn safe_create_file(path: &str, overwrite: bool) -> Result<File, std::io::Error> {
     let mut file = OpenOptions::new();
@@ -19,61 +23,129 @@ fn safe_create_file(path: &str, overwrite: bool) -> Result<File, std::io::Error>
     file.truncate(true).write(true).open(path)
 }
 
-fn create_file(path: &str) -> Result<File, std::io::Error> {
-    OpenOptions::new()
-        .write(true)
-        .create(true)
-        .truncate(true)
-        .open(path)
+/// Create an empty file, keeping the contents of an existing one. Returns whether it was created.
+fn create_file(path: &str) -> Result<bool, std::io::Error> {
+    let exists = fs::metadata(path).is_ok();
+    OpenOptions::new().append(true).create(true).open(path)?;
+    Ok(!exists)
 }
 
-pub fn handle(day: Day, overwrite: bool) {
-    let input_path = format!("data/inputs/{day}.txt");
-    let example_path = format!("data/examples/{day}.txt");
-    let module_path = format!("src/bin/{day}.rs");
+/// Variables available to every template, in addition to the template's own.
+fn template_variables(
+    year: Year,
+    day: Day,
+    examples: Option<&Examples>,
+) -> HashMap<String, String> {
+    let examples = examples.cloned().unwrap_or_default();
 
-    let mut file = match safe_create_file(&module_path, overwrite) {
-        Ok(file) => file,
+    [
+        ("YEAR", year.to_string()),
+        ("DAY", day.to_string()),
+        ("DAY_NUMBER", day.into_inner().to_string()),
+        (
+            "PART_1_EXPECTED",
+            examples.part_1.answer.clone().unwrap_or_default(),
+        ),
+        (
+            "PART_2_EXPECTED",
+            examples.part_2.answer.clone().unwrap_or_default(),
+        ),
+    ]
+    .into_iter()
+    .map(|(name, value)| (name.to_string(), value))
+    .collect()
+}
+
+/// Scaffold a day from `template`. `variables` override the variables of the template.
+pub fn handle(
+    year: Year,
+    day: Day,
+    overwrite: bool,
+    template: &str,
+    variables: &HashMap<String, String>,
+) {
+    let input_path = format!("data/{year}/inputs/{day}.txt");
+    let example_path = format!("data/{year}/examples/{day}.txt");
+    let examples = examples::read(year, day);
+
+    let template = match Template::load(Path::new(TEMPLATES_DIR), template) {
+        Ok(template) => template,
         Err(e) => {
-            eprintln!("Failed to create module file: {e}");
+            eprintln!("Failed to load template: {e}");
             process::exit(1);
         }
     };
 
-    match file.write_all(
-        MODULE_TEMPLATE
-            .replace("%DAY_NUMBER%", &day.into_inner().to_string())
-            .as_bytes(),
-    ) {
-        Ok(()) => {
-            println!("Created module file \"{}\"", &module_path);
+    let mut all_variables = HashMap::from([("ANSWER_TYPE".to_string(), "u32".to_string())]);
+    all_variables.extend(template.variables.clone());
+    all_variables.extend(template_variables(year, day, examples.as_ref()));
+    all_variables.extend(variables.clone());
+
+    let files = template.render(&all_variables);
+
+    if !overwrite {
+        if let Some((path, _)) = files.iter().find(|(path, _)| Path::new(path).exists()) {
+            eprintln!(
+                "Failed to create \"{path}\": file exists. Pass `--overwrite` to replace it."
+            );
+            process::exit(1);
         }
-        Err(e) => {
-            eprintln!("Failed to write module contents: {e}");
+    }
+
+    for dir in ["inputs", "examples", "puzzles"] {
+        if let Err(e) = fs::create_dir_all(format!("data/{year}/{dir}")) {
+            eprintln!("Failed to create data directory: {e}");
             process::exit(1);
         }
     }
 
+    for (path, content) in &files {
+        let written = Path::new(path)
+            .parent()
+            .map_or(Ok(()), fs::create_dir_all)
+            .and_then(|()| safe_create_file(path, overwrite))
+            .and_then(|mut file| file.write_all(content.as_bytes()));
+
+        match written {
+            Ok(()) => {
+                println!("Created file \"{path}\"");
+            }
+            Err(e) => {
+                eprintln!("Failed to write \"{path}\": {e}");
+                process::exit(1);
+            }
+        }
+
+        for name in unknown_variables(content) {
+            eprintln!("Warning: unknown variable `%{name}%` in \"{path}\".");
+        }
+    }
+
     match create_file(&input_path) {
-        Ok(_) => {
+        Ok(true) => {
             println!("Created empty input file \"{}\"", &input_path);
         }
+        Ok(false) => {}
         Err(e) => {
             eprintln!("Failed to create input file: {e}");
             process::exit(1);
         }
     }
 
-    match create_file(&example_path) {
-        Ok(_) => {
-            println!("Created empty example file \"{}\"", &example_path);
-        }
-        Err(e) => {
-            eprintln!("Failed to create example file: {e}");
-            process::exit(1);
-        }
+    let written = match &examples {
+        Some(examples) => examples::write(year, day, examples, overwrite),
+        None => create_file(&example_path).map(|created| {
+            if created {
+                println!("Created empty example file \"{}\"", &example_path);
+            }
+        }),
+    };
+
+    if let Err(e) = written {
+        eprintln!("Failed to create example file: {e}");
+        process::exit(1);
     }
 
     println!("---");
-    println!("🎄 Type `cargo solve {day}` to run your solution.");
+    println!("🎄 Type `cargo solve {day} --year {year}` to run your solution.");
 }
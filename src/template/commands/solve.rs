@@ -1,9 +1,30 @@
 use std::process::{Command, Stdio};
 
-use crate::template::Day;
+use crate::template::alloc;
+use crate::template::answers::{Answers, Verdict};
+use crate::template::inputs::{named_inputs, Inputs};
+use crate::template::run_multi::child_commands;
+use crate::template::stats::BenchConfig;
+use crate::template::{Day, Year, ANSI_BOLD, ANSI_RESET};
 
-pub fn handle(day: Day, release: bool, dhat: bool, submit_part: Option<u8>) {
-    let mut cmd_args = vec!["run".to_string(), "--bin".to_string(), day.to_string()];
+/// Run the solution for `day` of `year`, benching it with `bench` if passed.
+/// The solution reads `input` if passed, a file or `-` for stdin, instead of the input of the day.
+/// Otherwise it also runs against the named inputs of the day, which are checked against their
+/// known answers.
+pub fn handle(
+    year: Year,
+    day: Day,
+    release: bool,
+    dhat: bool,
+    submit_part: Option<u8>,
+    bench: Option<&BenchConfig>,
+    input: Option<&str>,
+) {
+    let mut cmd_args = vec![
+        "run".to_string(),
+        "--bin".to_string(),
+        format!("{year}-{day}"),
+    ];
 
     if dhat {
         cmd_args.extend([
@@ -12,8 +33,11 @@ pub fn handle(day: Day, release: bool, dhat: bool, submit_part: Option<u8>) {
             "--features".to_string(),
             "dhat-heap".to_string(),
         ]);
-    } else if release {
-        cmd_args.push("--release".to_string());
+    } else {
+        if release {
+            cmd_args.push("--release".to_string());
+        }
+        cmd_args.extend(alloc::cargo_args());
     }
 
     cmd_args.push("--".to_string());
@@ -23,6 +47,16 @@ pub fn handle(day: Day, release: bool, dhat: bool, submit_part: Option<u8>) {
         cmd_args.push(submit_part.to_string());
     }
 
+    if let Some(bench) = bench {
+        cmd_args.push("--time".to_string());
+        cmd_args.extend(bench.to_args());
+    }
+
+    if let Some(input) = input {
+        cmd_args.push("--input".to_string());
+        cmd_args.push(input.to_string());
+    }
+
     let mut cmd = Command::new("cargo")
         .args(&cmd_args)
         .stdout(Stdio::inherit())
@@ -31,4 +65,71 @@ pub fn handle(day: Day, release: bool, dhat: bool, submit_part: Option<u8>) {
         .unwrap();
 
     cmd.wait().unwrap();
+
+    if input.is_none() && submit_part.is_none() && !dhat {
+        solve_named_inputs(year, day, release);
+    }
+}
+
+/// Run the solution against every named input of `day` and report the inputs that fail.
+fn solve_named_inputs(year: Year, day: Day, release: bool) {
+    let names = named_inputs(year, day);
+    if names.is_empty() {
+        return;
+    }
+
+    let answers = Answers::read_from_file(year).unwrap_or_else(|e| {
+        eprintln!("Failed to read known answers: {e}");
+        Answers::default()
+    });
+
+    let mut report = vec![];
+
+    for name in &names {
+        println!("\n{ANSI_BOLD}Input {name}{ANSI_RESET}");
+        let results =
+            match child_commands::run_solution(year, day, None, release, Inputs::Named(name)) {
+                Ok(results) => results,
+                Err(e) => {
+                    eprintln!("Failed to run solution: {e:?}");
+                    vec![]
+                }
+            };
+
+        let verdicts: Vec<(u8, Verdict)> = (1..=year.parts(day))
+            .map(|part| {
+                let answer = results
+                    .iter()
+                    .find(|r| r.part == part)
+                    .and_then(|r| r.answer.as_deref());
+                (part, answers.check_for_input(day, Some(name), part, answer))
+            })
+            .collect();
+
+        report.push((name, verdicts));
+    }
+
+    println!("\n{ANSI_BOLD}Inputs{ANSI_RESET}");
+    for (name, verdicts) in report {
+        let failures: Vec<String> = verdicts
+            .iter()
+            .filter_map(|(part, verdict)| match verdict {
+                Verdict::Fail { expected, actual } => Some(format!(
+                    "part {part} expected `{expected}`, got {}",
+                    actual
+                        .as_ref()
+                        .map_or("no answer".into(), |x| format!("`{x}`"))
+                )),
+                _ => None,
+            })
+            .collect();
+
+        if !failures.is_empty() {
+            println!("✖ {name}: {}", failures.join(", "));
+        } else if verdicts.iter().all(|(_, v)| *v == Verdict::Pass) {
+            println!("✔ {name}");
+        } else {
+            println!("? {name}: not every answer is known yet");
+        }
+    }
 }
@@ -1,34 +1,98 @@
-use std::collections::HashSet;
+use std::{collections::HashSet, process};
 
+use crate::template::history::{compare, Delta, History, HistoryEntry, HistoryOptions};
+use crate::template::inputs::Inputs;
+use crate::template::limits::Limits;
+use crate::template::readme_benchmarks::format_nanos;
+use crate::template::results::PartStatus;
 use crate::template::run_multi::run_multi;
-use crate::template::timings::Timings;
-use crate::template::{all_days, readme_benchmarks, Day};
+use crate::template::stats::{BenchConfig, Stat};
+use crate::template::timings::{Timing, Timings};
+use crate::template::{
+    all_days, readme_benchmarks, registry::Solution, Day, Year, ANSI_BOLD, ANSI_RESET,
+};
 
-pub fn handle(day: Option<Day>, run_all: bool, store: bool) {
-    let stored_timings = Timings::read_from_file();
+#[allow(clippy::too_many_arguments)]
+pub fn handle(
+    solutions: &[Solution],
+    year: Year,
+    day: Option<Day>,
+    run_all: bool,
+    store: bool,
+    isolated: bool,
+    jobs: usize,
+    limits: Limits,
+    bench: &BenchConfig,
+    columns: &[Stat],
+    memory: bool,
+    history_options: &HistoryOptions,
+) {
+    let stored_timings = Timings::read_from_file(year);
+
+    let history = match History::read_from_file(year) {
+        Ok(history) => history,
+        Err(e) => {
+            eprintln!("Failed to read benchmark history: {e}");
+            process::exit(1);
+        }
+    };
+
+    // resolve the baseline up front, so that an unknown name fails before benchmarking.
+    let baseline = history_options.baseline.as_ref().map(|name| {
+        history.named(name).unwrap_or_else(|| {
+            eprintln!("No benchmark run tagged `{name}` or at commit `{name}` in history.");
+            process::exit(1);
+        })
+    });
 
     let days_to_run = day.map_or_else(
         || {
             if run_all {
-                all_days().collect()
+                all_days(year).collect()
             } else {
                 // when the `--all` flag is not set, filter out days that are fully benched.
-                all_days()
-                    .filter(|day| !stored_timings.is_day_complete(*day))
+                all_days(year)
+                    .filter(|day| !stored_timings.is_day_complete(year, *day))
                     .collect()
             }
         },
         |day| HashSet::from([day]),
     );
 
-    let timings = run_multi(&days_to_run, true, true).unwrap();
+    if memory && !cfg!(feature = "alloc-stats") {
+        println!("Note: memory is only recorded with `--features alloc-stats`.\n");
+    }
+
+    if jobs > 1 {
+        println!("Note: days are benchmarked in parallel, their timings may be skewed.\n");
+    }
+
+    let results = run_multi(
+        solutions,
+        year,
+        &days_to_run,
+        true,
+        Some(bench),
+        isolated,
+        jobs,
+        limits,
+        Inputs::Default,
+    );
+    let timings = Timings::from_results(&results);
+
+    if !timings.data.is_empty() {
+        let entry = HistoryEntry::new(timings.clone(), history_options.tag.clone());
+        if let Err(e) = entry.record(year) {
+            eprintln!("Failed to record benchmark history: {e}");
+        }
+    }
 
     if store {
         let merged_timings = stored_timings.merge(&timings);
-        merged_timings.store_file().unwrap();
+        merged_timings.store_file(year).unwrap();
 
         println!();
-        match readme_benchmarks::update(merged_timings) {
+        match readme_benchmarks::update(year, merged_timings, columns, memory) {
             Ok(()) => {
                 println!("Stored updated benchmarks.");
             }
@@ -37,4 +101,75 @@ pub fn handle(day: Option<Day>, run_all: bool, store: bool) {
             }
         }
     }
+
+    if history_options.is_comparing() {
+        // NOTE: also compare days without any results, so that a day that broke counts as well.
+        let current = Timings {
+            data: results
+                .iter()
+                .map(|r| Timing::from_results(r.day, &r.parts))
+                .collect(),
+        };
+        let deltas = match baseline {
+            Some(baseline) => {
+                println!("\nCompared with {}:", baseline.describe());
+                compare(&current, |day| {
+                    baseline.timings.data.iter().find(|t| t.day == day)
+                })
+            }
+            None => {
+                println!("\nCompared with the previous run of every day:");
+                compare(&current, |day| history.previous(day).map(|(_, t)| t))
+            }
+        };
+
+        print_comparison(&deltas, history_options.fail_on_regression);
+
+        if let Some(threshold) = history_options.fail_on_regression {
+            let regressions = deltas
+                .iter()
+                .filter(|delta| delta.change_pct() > threshold)
+                .count();
+            if regressions > 0 {
+                eprintln!("\n{regressions} part(s) regressed by more than {threshold}%.");
+                process::exit(1);
+            }
+        }
+    }
+}
+
+/// Print the change of every part, highlighting regressions beyond `threshold` percent.
+fn print_comparison(deltas: &[Delta], threshold: Option<f64>) {
+    if deltas.is_empty() {
+        println!("No earlier timings to compare with.");
+        return;
+    }
+
+    println!();
+    println!("{ANSI_BOLD}Day | Part |     Before |      After |   Change{ANSI_RESET}");
+    println!("----|------|------------|------------|---------");
+
+    for delta in deltas {
+        let change = delta.change_pct();
+        let (after, change_str) = match delta.after_nanos {
+            Some(after_nanos) => (format_nanos(after_nanos), format!("{change:>+7.1}%")),
+            None => {
+                let label = delta.after_killed.and_then(PartStatus::killed_label);
+                (label.unwrap_or("-").to_string(), "  missing".to_string())
+            }
+        };
+        let line = format!(
+            " {} | {:>4} | {:>10} | {:>10} | {change_str}",
+            delta.day,
+            delta.part,
+            format_nanos(delta.before_nanos),
+            after,
+        );
+
+        if threshold.is_some_and(|threshold| change > threshold) {
+            println!("{ANSI_BOLD}{line}{ANSI_RESET}");
+        } else {
+            println!("{line}");
+        }
+    }
 }
@@ -0,0 +1,157 @@
+use std::{
+    collections::{BTreeMap, HashSet},
+    process,
+};
+
+use crate::template::answers::{Answers, Verdict};
+use crate::template::inputs::{named_inputs, Inputs};
+use crate::template::limits::Limits;
+use crate::template::{
+    all_days, registry::Solution, results::DayResults, run_multi::run_multi, Day, Year, ANSI_BOLD,
+    ANSI_RESET,
+};
+
+/// Run every day of a year and compare the answers with the known answers.
+/// Days with named inputs are run again for each of them, and checked against their own answers.
+/// With `accept`, answers of parts without a known answer are stored as the known answer.
+#[allow(clippy::too_many_arguments)]
+pub fn handle(
+    solutions: &[Solution],
+    year: Year,
+    is_release: bool,
+    isolated: bool,
+    jobs: usize,
+    limits: Limits,
+    accept: bool,
+) {
+    let mut answers = match Answers::read_from_file(year) {
+        Ok(answers) => answers,
+        Err(e) => {
+            eprintln!("Failed to read known answers: {e}");
+            process::exit(1);
+        }
+    };
+
+    let mut runs = vec![(
+        None,
+        run_multi(
+            solutions,
+            year,
+            &all_days(year).collect(),
+            is_release,
+            None,
+            isolated,
+            jobs,
+            limits,
+            Inputs::Default,
+        ),
+    )];
+
+    let mut days_by_input: BTreeMap<String, HashSet<Day>> = BTreeMap::new();
+    for day in all_days(year) {
+        for name in named_inputs(year, day) {
+            days_by_input.entry(name).or_default().insert(day);
+        }
+    }
+
+    for (name, days) in &days_by_input {
+        println!("\n{ANSI_BOLD}Input {name}{ANSI_RESET}\n");
+        let results = run_multi(
+            solutions,
+            year,
+            days,
+            is_release,
+            None,
+            isolated,
+            jobs,
+            limits,
+            Inputs::Named(name),
+        );
+        runs.push((Some(name.as_str()), results));
+    }
+
+    let mut rows: Vec<(Day, Option<&str>, &DayResults)> = runs
+        .iter()
+        .flat_map(|(input, results)| results.iter().map(|r| (r.day, *input, r)))
+        .collect();
+    rows.sort_by_key(|(day, input, _)| (*day, *input));
+
+    let has_named_inputs = !days_by_input.is_empty();
+
+    println!();
+    if has_named_inputs {
+        println!("{ANSI_BOLD}Day | Part 1 | Part 2 | Input{ANSI_RESET}");
+        println!("----|--------|--------|------");
+    } else {
+        println!("{ANSI_BOLD}Day | Part 1 | Part 2{ANSI_RESET}");
+        println!("----|--------|-------");
+    }
+
+    let mut failures = vec![];
+    let mut accepted = 0;
+
+    for (day, input, day_results) in rows {
+        // NOTE: the last day of a year has no second part, so there is nothing to verify.
+        let verdicts: Vec<_> = (1..=year.parts(day))
+            .map(|part| {
+                let answer = day_results.part(part).and_then(|r| r.answer.as_deref());
+                let verdict = answers.check_for_input(day, input, part, answer);
+                (part, answer.map(ToString::to_string), verdict)
+            })
+            .collect();
+
+        let part_2 = verdicts.get(1).map_or("", |v| format_verdict(&v.2));
+        if has_named_inputs {
+            println!(
+                " {day} | {:<6} | {part_2:<6} | {}",
+                format_verdict(&verdicts[0].2),
+                input.unwrap_or("default")
+            );
+        } else {
+            println!(" {day} | {:<6} | {part_2}", format_verdict(&verdicts[0].2));
+        }
+
+        for (part, answer, verdict) in verdicts {
+            match verdict {
+                Verdict::Fail { expected, actual } => {
+                    failures.push((day, input, part, expected, actual));
+                }
+                Verdict::Unknown if accept => {
+                    answers.set_for_input(day, input, part, &answer.unwrap_or_default());
+                    accepted += 1;
+                }
+                _ => {}
+            }
+        }
+    }
+
+    if accepted > 0 {
+        match answers.store_file(year) {
+            Ok(()) => println!("\nAccepted {accepted} new answer(s)."),
+            Err(e) => eprintln!("\nFailed to store known answers: {e}"),
+        }
+    }
+
+    if !failures.is_empty() {
+        println!();
+        for (day, input, part, expected, actual) in &failures {
+            let input = input.map_or(String::new(), |name| format!(" (input {name})"));
+            println!(
+                "Day {day} part {part}{input}: expected `{expected}`, got {}.",
+                actual
+                    .as_ref()
+                    .map_or("no answer".into(), |x| format!("`{x}`"))
+            );
+        }
+        process::exit(1);
+    }
+}
+
+fn format_verdict(verdict: &Verdict) -> &'static str {
+    match verdict {
+        Verdict::Pass => "✔",
+        Verdict::Fail { .. } => "✖",
+        Verdict::Unknown => "?",
+        Verdict::Unsolved => "-",
+    }
+}
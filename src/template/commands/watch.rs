@@ -0,0 +1,180 @@
+use std::{
+    path::Path,
+    process::{self, Command},
+    thread,
+    time::Duration,
+};
+
+use crate::template::answers::{Answers, Verdict};
+use crate::template::inputs::Inputs;
+use crate::template::run_multi::child_commands;
+use crate::template::watch::{format_summary, parse_test_output, Snapshot, SummaryLine};
+use crate::template::{Day, Year, ANSI_BOLD, ANSI_RESET};
+
+/// How often the watched files are checked for changes.
+const POLL_INTERVAL: Duration = Duration::from_millis(250);
+
+/// How long the files must stay unchanged before the action runs. Editors and formatters often
+/// write a file several times in a row.
+const DEBOUNCE: Duration = Duration::from_millis(300);
+
+/// What to run whenever the files of a day change.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum WatchAction {
+    /// Run the tests of the solution against the examples.
+    Test,
+    /// Run the solution against the input.
+    Solve,
+}
+
+/// Run `action` for `day` once, then again whenever its solution, examples or input change.
+pub fn handle(year: Year, day: Day, action: WatchAction, release: bool) {
+    println!("Watching day {day} of {year}, press Ctrl-C to stop.");
+
+    let mut snapshot = Snapshot::take(year, day);
+    let mut previous = run(year, day, action, release, None);
+
+    loop {
+        thread::sleep(POLL_INTERVAL);
+
+        let mut current = Snapshot::take(year, day);
+        if snapshot.changes(&current).is_empty() {
+            continue;
+        }
+
+        loop {
+            thread::sleep(DEBOUNCE);
+            let next = Snapshot::take(year, day);
+            if next == current {
+                break;
+            }
+            current = next;
+        }
+
+        let changes: Vec<String> = snapshot
+            .changes(&current)
+            .iter()
+            .map(|path| display_path(path))
+            .collect();
+        snapshot = current;
+
+        println!("\n{ANSI_BOLD}Changed:{ANSI_RESET} {}", changes.join(", "));
+        previous = run(year, day, action, release, Some(&previous));
+    }
+}
+
+fn display_path(path: &Path) -> String {
+    path.strip_prefix("./")
+        .unwrap_or(path)
+        .display()
+        .to_string()
+}
+
+/// Run `action` once and print its summary, compared with the `previous` run.
+fn run(
+    year: Year,
+    day: Day,
+    action: WatchAction,
+    release: bool,
+    previous: Option<&[SummaryLine]>,
+) -> Vec<SummaryLine> {
+    let summary = match action {
+        WatchAction::Test => run_tests(year, day, release),
+        WatchAction::Solve => run_solution(year, day, release),
+    };
+
+    println!();
+    for line in format_summary(&summary, previous) {
+        println!("{line}");
+    }
+
+    summary
+}
+
+fn run_tests(year: Year, day: Day, release: bool) -> Vec<SummaryLine> {
+    let mut args = vec![
+        "test".to_string(),
+        "--bin".to_string(),
+        format!("{year}-{day}"),
+    ];
+    if release {
+        args.push("--release".into());
+    }
+    args.extend(["--".into(), "--color".into(), "never".into()]);
+
+    let output = match Command::new("cargo")
+        .args(&args)
+        .env("RUST_BACKTRACE", "0")
+        .output()
+    {
+        Ok(output) => output,
+        Err(e) => {
+            eprintln!("Failed to run tests: {e}");
+            process::exit(1);
+        }
+    };
+
+    let summary = parse_test_output(&String::from_utf8_lossy(&output.stdout));
+    if !summary.is_empty() || output.status.success() {
+        return summary;
+    }
+
+    // NOTE: no test ran, so the solution most likely failed to compile.
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    vec![SummaryLine {
+        label: "build".into(),
+        value: "FAILED".into(),
+        passed: Some(false),
+        details: stderr
+            .lines()
+            .filter(|line| line.starts_with("error") || line.trim_start().starts_with("-->"))
+            .map(ToString::to_string)
+            .collect(),
+    }]
+}
+
+fn run_solution(year: Year, day: Day, release: bool) -> Vec<SummaryLine> {
+    let results = match child_commands::run_solution(year, day, None, release, Inputs::Default) {
+        Ok(results) => results,
+        Err(e) => {
+            eprintln!("Failed to run solution: {e:?}");
+            vec![]
+        }
+    };
+
+    let answers = Answers::read_from_file(year).unwrap_or_else(|e| {
+        eprintln!("Failed to read known answers: {e}");
+        Answers::default()
+    });
+
+    (1..=year.parts(day))
+        .map(|part| {
+            let result = results.iter().find(|r| r.part == part);
+            let answer = result.and_then(|r| r.answer.as_deref());
+
+            if let Some(error) = result.and_then(|r| r.error.as_ref()) {
+                return SummaryLine {
+                    label: format!("Part {part}"),
+                    value: "failed".into(),
+                    passed: Some(false),
+                    details: vec![error.clone()],
+                };
+            }
+
+            let (passed, details) = match answers.check(day, part, answer) {
+                Verdict::Pass => (Some(true), vec![]),
+                Verdict::Fail { expected, .. } => {
+                    (Some(false), vec![format!("expected {expected}")])
+                }
+                Verdict::Unknown | Verdict::Unsolved => (None, vec![]),
+            };
+
+            SummaryLine {
+                label: format!("Part {part}"),
+                value: answer.unwrap_or("-").to_string(),
+                passed,
+                details,
+            }
+        })
+        .collect()
+}
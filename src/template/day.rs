@@ -2,13 +2,23 @@ use std::error::Error;
 use std::fmt::Display;
 use std::str::FromStr;
 
+use crate::template::Year;
+
 #[cfg(feature = "today")]
-use chrono::{Datelike, FixedOffset, Utc};
+use chrono::{DateTime, Datelike, FixedOffset, Utc};
 
 #[cfg(feature = "today")]
 const SERVER_UTC_OFFSET: i32 = -5;
 
+/// The current time in the timezone of the puzzle server, where every puzzle unlocks at midnight.
+#[cfg(feature = "today")]
+pub(crate) fn server_now() -> Option<DateTime<FixedOffset>> {
+    let offset = FixedOffset::east_opt(SERVER_UTC_OFFSET * 3600)?;
+    Some(Utc::now().with_timezone(&offset))
+}
+
 /// A valid day number of advent (i.e. an integer in range 1 to 25).
+/// Not every year has 25 days, see [`Year::days`].
 ///
 /// # Display
 /// This value displays as a two digit number.
@@ -37,6 +47,12 @@ impl Day {
         Self(day)
     }
 
+    /// Creates a [`Day`] from the provided value if it's a day of advent in `year`,
+    /// returns [`None`] otherwise.
+    pub fn new_in(year: Year, day: u8) -> Option<Self> {
+        Self::new(day).filter(|day| year.contains(*day))
+    }
+
     /// Converts the [`Day`] into an [`u8`].
     pub fn into_inner(self) -> u8 {
         self.0
@@ -45,12 +61,12 @@ impl Day {
 
 #[cfg(feature = "today")]
 impl Day {
-    /// Returns the current day if it's between the 1st and the 25th of december, `None` otherwise.
+    /// Returns the current day if it's a day of this year's advent in december, `None` otherwise.
     pub fn today() -> Option<Self> {
-        let offset = FixedOffset::east_opt(SERVER_UTC_OFFSET * 3600)?;
-        let today = Utc::now().with_timezone(&offset);
-        if today.month() == 12 && today.day() <= 25 {
-            Self::new(u8::try_from(today.day()).ok()?)
+        let today = server_now()?;
+        let year = Year::new(u16::try_from(today.year()).ok()?)?;
+        if today.month() == 12 {
+            Self::new_in(year, u8::try_from(today.day()).ok()?)
         } else {
             None
         }
@@ -100,20 +116,23 @@ impl Display for DayFromStrError {
 
 /* -------------------------------------------------------------------------- */
 
-/// An iterator that yields every day of advent from the 1st to the 25th.
-pub fn all_days() -> AllDays {
-    AllDays::new()
+/// An iterator that yields every day of advent in `year`, from the 1st to the last day.
+pub fn all_days(year: Year) -> AllDays {
+    AllDays::new(year)
 }
 
-/// An iterator that yields every day of advent from the 1st to the 25th.
+/// An iterator that yields every day of advent in a year, from the 1st to the last day.
 pub struct AllDays {
     current: u8,
+    last: u8,
 }
 
 impl AllDays {
-    #[allow(clippy::new_without_default)]
-    pub fn new() -> Self {
-        Self { current: 1 }
+    pub fn new(year: Year) -> Self {
+        Self {
+            current: 1,
+            last: year.days(),
+        }
     }
 }
 
@@ -121,10 +140,10 @@ impl Iterator for AllDays {
     type Item = Day;
 
     fn next(&mut self) -> Option<Self::Item> {
-        if self.current > 25 {
+        if self.current > self.last {
             return None;
         }
-        // NOTE: the iterator starts at 1 and we have verified that the value is not above 25.
+        // NOTE: the iterator starts at 1 and we have verified that the value is not above the last day.
         let day = Day(self.current);
         self.current += 1;
 
@@ -135,6 +154,7 @@ impl Iterator for AllDays {
 /* -------------------------------------------------------------------------- */
 
 /// Creates a [`Day`] value in a const context.
+/// With a second parameter, the day is also checked against the days of that year.
 #[macro_export]
 macro_rules! day {
     ($day:expr) => {{
@@ -148,6 +168,19 @@ macro_rules! day {
         );
         $crate::template::Day::__new_unchecked($day)
     }};
+    ($day:expr, $year:expr) => {{
+        const _ASSERT: () = assert!(
+            $day != 0 && $day <= $crate::template::Year::__new_unchecked($year).days(),
+            concat!(
+                "invalid day number `",
+                $day,
+                "`, ",
+                $year,
+                " has fewer days"
+            ),
+        );
+        $crate::template::Day::__new_unchecked($day)
+    }};
 }
 
 /* -------------------------------------------------------------------------- */
@@ -155,10 +188,11 @@ macro_rules! day {
 #[cfg(feature = "test_lib")]
 mod tests {
     use super::{all_days, Day};
+    use crate::year;
 
     #[test]
     fn all_days_iterator() {
-        let mut iter = all_days();
+        let mut iter = all_days(year!(2024));
 
         assert_eq!(iter.next(), Some(Day(1)));
         assert_eq!(iter.next(), Some(Day(2)));
@@ -187,6 +221,20 @@ mod tests {
         assert_eq!(iter.next(), Some(Day(25)));
         assert_eq!(iter.next(), None);
     }
+
+    #[test]
+    fn all_days_follows_year() {
+        assert_eq!(all_days(year!(2025)).count(), 12);
+        assert_eq!(all_days(year!(2025)).last(), Some(Day(12)));
+        assert_eq!(all_days(year!(2015)).count(), 25);
+    }
+
+    #[test]
+    fn checks_days_of_year() {
+        assert_eq!(Day::new_in(year!(2025), 12), Some(Day(12)));
+        assert_eq!(Day::new_in(year!(2025), 13), None);
+        assert_eq!(Day::new_in(year!(2024), 25), Some(Day(25)));
+    }
 }
 
 /* -------------------------------------------------------------------------- */
@@ -0,0 +1,191 @@
+/// Table-driven tests for the examples of a day.
+///
+/// Every example `data/{year}/examples/{day}*.txt` can have a sidecar file with the same name and
+/// the expected answers of its parts, e.g. `01.json` next to `01.txt`: `{ "part_1": "11" }`.
+/// The build script generates a test for every example and part with an expected answer, and
+/// `solution!` includes them into the tests of the day.
+///
+/// This module is also compiled into the build script, so it may only depend on `std` and `tinyjson`.
+use std::{
+    collections::HashMap,
+    fs,
+    path::{Path, PathBuf},
+    str::FromStr,
+};
+
+use tinyjson::JsonValue;
+
+/// An example input with a sidecar file of expected answers.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Example {
+    /// File stem of the example, e.g. `03-2`.
+    pub name: String,
+    pub path: PathBuf,
+    /// Expected answers by part, or why the sidecar file could not be read.
+    pub answers: Result<Vec<(u8, String)>, String>,
+}
+
+/// Whether `path` is an example of `day` (zero-padded), e.g. `05.txt` or `05-2.txt`.
+pub fn is_example_of(path: &Path, day: &str) -> bool {
+    let Some(name) = path.file_name().and_then(|name| name.to_str()) else {
+        return false;
+    };
+    let Some(stem) = name.strip_suffix(".txt") else {
+        return false;
+    };
+    stem == day || stem.strip_prefix(day).is_some_and(|x| x.starts_with('-'))
+}
+
+/// Path of the sidecar file with the expected answers of the example at `path`.
+pub fn get_path_for_answers(path: &Path) -> PathBuf {
+    path.with_extension("json")
+}
+
+/// Find all examples of `day` in `dir` that have a sidecar file, in order.
+pub fn discover(dir: &Path, day: &str) -> Vec<Example> {
+    let Ok(entries) = fs::read_dir(dir) else {
+        return vec![];
+    };
+
+    let mut examples: Vec<Example> = entries
+        .flatten()
+        .map(|entry| entry.path())
+        .filter(|path| is_example_of(path, day))
+        .filter_map(|path| {
+            let content = fs::read_to_string(get_path_for_answers(&path)).ok()?;
+            Some(Example {
+                name: path.file_stem()?.to_str()?.to_string(),
+                answers: parse_answers(&content),
+                path,
+            })
+        })
+        .collect();
+
+    examples.sort_by(|a, b| a.name.cmp(&b.name));
+    examples
+}
+
+/// Parse a sidecar file, e.g. `{ "part_1": "11", "part_2": "31" }`.
+pub fn parse_answers(content: &str) -> Result<Vec<(u8, String)>, String> {
+    let json = JsonValue::from_str(content).or(Err("not valid JSON."))?;
+    let parts = json
+        .get::<HashMap<String, JsonValue>>()
+        .ok_or("expected JSON document to be an object.")?;
+
+    let mut answers = parts
+        .iter()
+        .map(|(key, answer)| {
+            let part = match key.as_str() {
+                "part_1" => 1,
+                "part_2" => 2,
+                x => return Err(format!("unknown key `{x}`.")),
+            };
+            let answer = answer
+                .get::<String>()
+                .ok_or(format!("expected answer of {key} to be a string."))?;
+            Ok((part, answer.clone()))
+        })
+        .collect::<Result<Vec<_>, String>>()?;
+
+    answers.sort();
+    Ok(answers)
+}
+
+/// Generate the tests of `examples`, one per example and part.
+/// An example whose sidecar file is invalid gets a single failing test instead.
+pub fn generate(examples: &[Example]) -> String {
+    let mut out = String::new();
+
+    for example in examples {
+        let name = example
+            .name
+            .replace(|c: char| !c.is_ascii_alphanumeric(), "_");
+        let path = example.path.to_string_lossy();
+
+        match &example.answers {
+            Ok(answers) => {
+                for (part, expected) in answers {
+                    let func = if *part == 1 { "part_one" } else { "part_two" };
+                    out.push_str(&format!(
+                        "#[test]
+fn example_{name}_part_{part}() {{
+    let input = std::fs::read_to_string({path:?}).expect(\"could not open example file\");
+    let result = advent_of_code::template::runner::PartOutput::into_outcome(super::{func}(&input))
+        .map(|answer| answer.map(|x| x.to_string()));
+    assert_eq!(result, Ok(Some({expected:?}.to_string())), \"example {{}}\", {path:?});
+}}
+
+"
+                    ));
+                }
+            }
+            Err(e) => {
+                let message = format!(
+                    "invalid answers file \"{}\": {e}",
+                    get_path_for_answers(&example.path).to_string_lossy()
+                );
+                out.push_str(&format!(
+                    "#[test]\nfn example_{name}() {{\n    panic!(\"{{}}\", {message:?});\n}}\n\n"
+                ));
+            }
+        }
+    }
+
+    out
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use std::path::{Path, PathBuf};
+
+    use super::{generate, is_example_of, parse_answers, Example};
+
+    #[test]
+    fn matches_examples_of_day() {
+        assert!(is_example_of(Path::new("data/2024/examples/05.txt"), "05"));
+        assert!(is_example_of(
+            Path::new("data/2024/examples/05-2.txt"),
+            "05"
+        ));
+        assert!(!is_example_of(Path::new("data/2024/examples/15.txt"), "05"));
+        assert!(!is_example_of(
+            Path::new("data/2024/examples/05.json"),
+            "05"
+        ));
+    }
+
+    #[test]
+    fn parses_answers() {
+        assert_eq!(
+            parse_answers(r#"{ "part_2": "a,b", "part_1": "11" }"#),
+            Ok(vec![(1, "11".into()), (2, "a,b".into())])
+        );
+        assert!(parse_answers(r#"{ "part_3": "1" }"#).is_err());
+        assert!(parse_answers(r#"{ "part_1": 11 }"#).is_err());
+        assert!(parse_answers("11").is_err());
+    }
+
+    #[test]
+    fn generates_a_test_per_part() {
+        let examples = [
+            Example {
+                name: "03-2".into(),
+                path: PathBuf::from("data/2024/examples/03-2.txt"),
+                answers: Ok(vec![(1, "161".into()), (2, "48".into())]),
+            },
+            Example {
+                name: "04".into(),
+                path: PathBuf::from("data/2024/examples/04.txt"),
+                answers: Err("not valid JSON.".into()),
+            },
+        ];
+        let out = generate(&examples);
+        assert!(out.contains("fn example_03_2_part_1() {"));
+        assert!(out.contains("super::part_two(&input)"));
+        assert!(out.contains(r#"Ok(Some("48".to_string()))"#));
+        assert!(out.contains("fn example_04() {"));
+        assert!(out.contains(r#"invalid answers file \"data/2024/examples/04.json\""#));
+    }
+}
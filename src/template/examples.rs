@@ -0,0 +1,182 @@
+// Extracts example inputs and their expected answers from downloaded puzzle descriptions.
+//
+// Example inputs are the code blocks introduced by a mention of "example". Expected answers are
+// highlighted as emphasized code, e.g. ``*`11`*``, and the last one of each part is taken.
+
+/// Example of one part of a puzzle.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct PartExample {
+    /// Likely example inputs, in order of appearance. Empty if the part has no code blocks.
+    pub inputs: Vec<String>,
+    /// Expected answer for the example, if highlighted.
+    pub answer: Option<String>,
+}
+
+/// Examples of both parts of a puzzle.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Examples {
+    pub part_1: PartExample,
+    pub part_2: PartExample,
+}
+
+impl Examples {
+    /// Example input for each part. Part two falls back to the input of part one.
+    pub fn input(&self, part: u8) -> Option<&str> {
+        match part {
+            1 => self.part_1.inputs.first(),
+            _ => self.part_2.inputs.first().or(self.part_1.inputs.first()),
+        }
+        .map(String::as_str)
+    }
+
+    /// Whether part two has an example input that differs from the one of part one.
+    pub fn has_separate_part_2(&self) -> bool {
+        matches!(
+            (self.part_1.inputs.first(), self.part_2.inputs.first()),
+            (Some(a), Some(b)) if a != b
+        ) || (self.part_1.inputs.is_empty() && !self.part_2.inputs.is_empty())
+    }
+}
+
+/// Extract the examples from the markdown of a puzzle description.
+pub fn extract(puzzle: &str) -> Examples {
+    let (part_1, part_2) = match puzzle.find("## --- Part Two ---") {
+        Some(index) => puzzle.split_at(index),
+        None => (puzzle, ""),
+    };
+
+    Examples {
+        part_1: extract_part(part_1),
+        part_2: extract_part(part_2),
+    }
+}
+
+fn extract_part(section: &str) -> PartExample {
+    let mut inputs: Vec<String> = vec![];
+    let mut text_before = String::new();
+    let mut paragraph_ended = false;
+    let mut block: Option<String> = None;
+
+    for line in section.lines() {
+        match block.as_mut() {
+            Some(content) if line == "```" => {
+                if text_before.to_lowercase().contains("example") && !inputs.contains(content) {
+                    inputs.push(content.clone());
+                }
+                block = None;
+                text_before.clear();
+            }
+            Some(content) => {
+                content.push_str(line);
+                content.push('\n');
+            }
+            None if line.starts_with("```") => block = Some(String::new()),
+            None => {
+                // only the paragraph right before a block introduces it.
+                if line.trim().is_empty() {
+                    paragraph_ended = true;
+                } else {
+                    if paragraph_ended {
+                        text_before.clear();
+                        paragraph_ended = false;
+                    }
+                    text_before.push_str(line);
+                }
+            }
+        }
+    }
+
+    PartExample {
+        inputs,
+        answer: highlighted(section).last().map(ToString::to_string),
+    }
+}
+
+/// All emphasized code spans, e.g. `11` for ``*`11`*``.
+fn highlighted(text: &str) -> Vec<&str> {
+    let mut spans = vec![];
+    let mut rest = text;
+
+    while let Some(start) = rest.find("*`") {
+        rest = &rest[start + 2..];
+        let Some(end) = rest.find("`*") else {
+            break;
+        };
+        spans.push(&rest[..end]);
+        rest = &rest[end + 2..];
+    }
+
+    spans
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{extract, Examples, PartExample};
+
+    const PUZZLE: &str = "## --- Day 1: Historian Hysteria ---
+
+Throughout the Chief's office, the historically significant locations are listed. *Not* by name.
+
+For example:
+
+```
+3   4
+4   3
+```
+
+Maybe the lists are only off by a small amount!
+
+```
+1 3
+```
+
+In the example list above, the pairs and distances would be as follows: the total distance is *`11`*.
+
+## --- Part Two ---
+
+This time, you'll need to figure out exactly how often each number from the left list appears.
+Here is a new example:
+
+```
+1   1
+2   2
+```
+
+So, for these example lists, the similarity score at the end of this process is *`31`*.";
+
+    #[test]
+    fn extracts_examples_of_both_parts() {
+        assert_eq!(
+            extract(PUZZLE),
+            Examples {
+                part_1: PartExample {
+                    inputs: vec!["3   4\n4   3\n".into()],
+                    answer: Some("11".into()),
+                },
+                part_2: PartExample {
+                    inputs: vec!["1   1\n2   2\n".into()],
+                    answer: Some("31".into()),
+                },
+            }
+        );
+    }
+
+    #[test]
+    fn falls_back_to_part_one_input() {
+        let examples = extract(&PUZZLE.replace("Here is a new example:", "Again."));
+        assert!(examples.part_2.inputs.is_empty());
+        assert!(!examples.has_separate_part_2());
+        assert_eq!(examples.input(2), Some("3   4\n4   3\n"));
+        assert_eq!(examples.part_2.answer, Some("31".into()));
+    }
+
+    #[test]
+    fn handles_puzzles_without_part_two() {
+        let examples = extract("For example:\n\n```\nabc\n```\n\nThe answer is *`a,b`*.");
+        assert_eq!(examples.input(1), Some("abc\n"));
+        assert_eq!(examples.part_1.answer, Some("a,b".into()));
+        assert_eq!(examples.part_2, PartExample::default());
+    }
+}
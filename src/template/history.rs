@@ -0,0 +1,465 @@
+/// Append-only history of benchmark runs, used to compare timings between runs.
+///
+/// Every `cargo time` run is appended to the file of its year as one JSON line, tagged with the
+/// time of the run, the git commit, the build profile and the CPU it ran on.
+use std::{
+    collections::HashMap,
+    fs::{self, OpenOptions},
+    io::{self, Write},
+    process::Command,
+    str::FromStr,
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+use tinyjson::JsonValue;
+
+use crate::template::results::PartStatus;
+use crate::template::timings::{Timing, Timings};
+use crate::template::{Day, Year};
+
+fn get_path_for_history(year: Year) -> String {
+    format!("./data/{year}/history.jsonl")
+}
+
+/// How a `cargo time` run is recorded and compared against earlier runs.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct HistoryOptions {
+    /// Name of this run, to use it as a baseline later.
+    pub tag: Option<String>,
+    /// Compare against the previous run of every day.
+    pub compare: bool,
+    /// Compare against the latest run with this tag or commit instead.
+    pub baseline: Option<String>,
+    /// Fail if a part got slower by more than this percentage.
+    pub fail_on_regression: Option<f64>,
+}
+
+impl HistoryOptions {
+    pub fn is_comparing(&self) -> bool {
+        self.compare || self.baseline.is_some() || self.fail_on_regression.is_some()
+    }
+}
+
+/// A single benchmark run.
+#[derive(Clone, Debug)]
+pub struct HistoryEntry {
+    /// Seconds since the unix epoch.
+    pub timestamp: u64,
+    /// Short hash of the checked out commit, with a `-dirty` suffix for uncommitted changes.
+    pub commit: Option<String>,
+    /// Build profile and features that affect timings, e.g. `release+alloc-stats`.
+    pub profile: String,
+    pub cpu: Option<String>,
+    pub tag: Option<String>,
+    pub timings: Timings,
+}
+
+impl HistoryEntry {
+    /// Tag `timings` with the current time, commit, profile and CPU.
+    pub fn new(timings: Timings, tag: Option<String>) -> Self {
+        HistoryEntry {
+            timestamp: SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .map_or(0, |d| d.as_secs()),
+            commit: git_commit(),
+            profile: profile(),
+            cpu: cpu(),
+            tag,
+            timings,
+        }
+    }
+
+    /// Append this run to the history file of `year`.
+    pub fn record(&self, year: Year) -> io::Result<()> {
+        let mut file = OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(get_path_for_history(year))?;
+        let line = JsonValue::from(self)
+            .stringify()
+            .map_err(io::Error::other)?;
+        writeln!(file, "{line}")
+    }
+
+    /// Short description of the run, e.g. `abc1234 (2024-12-05 13:04 UTC, release)`.
+    pub fn describe(&self) -> String {
+        let mut label = self
+            .commit
+            .clone()
+            .unwrap_or_else(|| "unknown commit".into());
+        if let Some(tag) = &self.tag {
+            label = format!("{tag} at {label}");
+        }
+        format!(
+            "{label} ({}, {})",
+            format_timestamp(self.timestamp),
+            self.profile
+        )
+    }
+}
+
+/// All benchmark runs of a year, oldest first.
+#[derive(Clone, Debug, Default)]
+pub struct History {
+    entries: Vec<HistoryEntry>,
+}
+
+impl History {
+    /// Rehydrate the history from the JSON-lines file. If not present, returns no runs.
+    pub fn read_from_file(year: Year) -> Result<Self, String> {
+        match fs::read_to_string(get_path_for_history(year)) {
+            Ok(content) => History::from_str(&content),
+            Err(_) => Ok(History::default()),
+        }
+    }
+
+    pub fn entries(&self) -> &[HistoryEntry] {
+        &self.entries
+    }
+
+    /// Latest run of `day`.
+    pub fn previous(&self, day: Day) -> Option<(&HistoryEntry, &Timing)> {
+        self.entries
+            .iter()
+            .rev()
+            .find_map(|entry| Some((entry, entry.timings.data.iter().find(|t| t.day == day)?)))
+    }
+
+    /// Latest run tagged `name`, or whose commit starts with `name`.
+    pub fn named(&self, name: &str) -> Option<&HistoryEntry> {
+        self.entries.iter().rev().find(|entry| {
+            entry.tag.as_deref() == Some(name)
+                || entry.commit.as_ref().is_some_and(|c| c.starts_with(name))
+        })
+    }
+}
+
+/// Change in the timing of a part between two runs.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Delta {
+    pub day: Day,
+    pub part: u8,
+    pub before_nanos: f64,
+    /// `None` if the part has no timing in the current run, e.g. because it failed.
+    pub after_nanos: Option<f64>,
+    /// Why the part was killed in the current run, if it was.
+    pub after_killed: Option<PartStatus>,
+}
+
+impl Delta {
+    /// Relative change in percent, positive if the part got slower.
+    /// Infinite if the part has no timing in the current run.
+    pub fn change_pct(&self) -> f64 {
+        match self.after_nanos {
+            Some(after_nanos) => (after_nanos - self.before_nanos) / self.before_nanos * 100.0,
+            None => f64::INFINITY,
+        }
+    }
+}
+
+/// Compare every part of `current` that has a timing in its `baseline`. A part that has no timing
+/// in `current`, because it was killed, failed or returned no answer, counts as a regression.
+pub fn compare<'a>(current: &Timings, baseline: impl Fn(Day) -> Option<&'a Timing>) -> Vec<Delta> {
+    current
+        .data
+        .iter()
+        .flat_map(|timing| {
+            let before = baseline(timing.day);
+            [1, 2].into_iter().filter_map(move |part| {
+                let before = before?.part(part)?;
+                (before.nanos > 0.0).then(|| Delta {
+                    day: timing.day,
+                    part,
+                    before_nanos: before.nanos,
+                    after_nanos: timing.part(part).map(|after| after.nanos),
+                    after_killed: timing.killed(part),
+                })
+            })
+        })
+        .collect()
+}
+
+fn git_commit() -> Option<String> {
+    let git = |args: &[&str]| {
+        Command::new("git")
+            .args(args)
+            .output()
+            .ok()
+            .filter(|output| output.status.success())
+            .map(|output| String::from_utf8_lossy(&output.stdout).trim().to_string())
+    };
+
+    let commit = git(&["rev-parse", "--short", "HEAD"])?;
+    match git(&["status", "--porcelain", "--untracked-files=no"]) {
+        Some(changes) if !changes.is_empty() => Some(format!("{commit}-dirty")),
+        _ => Some(commit),
+    }
+}
+
+fn profile() -> String {
+    let profile = if cfg!(debug_assertions) {
+        "debug"
+    } else {
+        "release"
+    };
+
+    if cfg!(feature = "alloc-stats") {
+        format!("{profile}+alloc-stats")
+    } else {
+        profile.into()
+    }
+}
+
+/// Model name of the CPU, if it can be determined, e.g. on Linux.
+fn cpu() -> Option<String> {
+    fs::read_to_string("/proc/cpuinfo")
+        .ok()?
+        .lines()
+        .find_map(|line| {
+            let (key, value) = line.split_once(':')?;
+            (key.trim() == "model name").then(|| value.trim().to_string())
+        })
+}
+
+/// Format seconds since the unix epoch as a UTC date and time, e.g. `2024-12-05 13:04 UTC`.
+pub fn format_timestamp(timestamp: u64) -> String {
+    let days = timestamp / 86400;
+    let minutes = timestamp % 86400 / 60;
+
+    // NOTE: converts days to a date in the proleptic Gregorian calendar, see
+    // http://howardhinnant.github.io/date_algorithms.html#civil_from_days
+    let z = days + 719_468;
+    let era = z / 146_097;
+    let day_of_era = z % 146_097;
+    let year_of_era =
+        (day_of_era - day_of_era / 1460 + day_of_era / 36524 - day_of_era / 146_096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let mp = (5 * day_of_year + 2) / 153;
+    let day = day_of_year - (153 * mp + 2) / 5 + 1;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 };
+    let year = year_of_era + era * 400 + u64::from(month <= 2);
+
+    format!(
+        "{year}-{month:02}-{day:02} {:02}:{:02} UTC",
+        minutes / 60,
+        minutes % 60
+    )
+}
+
+/* -------------------------------------------------------------------------- */
+
+impl From<&HistoryEntry> for JsonValue {
+    fn from(value: &HistoryEntry) -> Self {
+        let mut map: HashMap<String, JsonValue> = HashMap::new();
+
+        #[allow(clippy::cast_precision_loss)]
+        map.insert(
+            "timestamp".into(),
+            JsonValue::Number(value.timestamp as f64),
+        );
+        map.insert("profile".into(), JsonValue::String(value.profile.clone()));
+
+        for (key, text) in [
+            ("commit", &value.commit),
+            ("cpu", &value.cpu),
+            ("tag", &value.tag),
+        ] {
+            if let Some(text) = text {
+                map.insert(key.into(), JsonValue::String(text.clone()));
+            }
+        }
+
+        map.insert("timings".into(), JsonValue::from(value.timings.clone()));
+
+        JsonValue::Object(map)
+    }
+}
+
+impl TryFrom<&JsonValue> for HistoryEntry {
+    type Error = String;
+
+    fn try_from(value: &JsonValue) -> Result<Self, Self::Error> {
+        let json = value
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or("Expected history entry to be a JSON object.")?;
+
+        let text = |key: &str| json.get(key).and_then(|v| v.get::<String>()).cloned();
+
+        #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+        let timestamp = json
+            .get("timestamp")
+            .and_then(|v| v.get::<f64>())
+            .map(|x| *x as u64)
+            .ok_or("Expected history.timestamp to be a number.")?;
+
+        Ok(HistoryEntry {
+            timestamp,
+            commit: text("commit"),
+            profile: text("profile").ok_or("Expected history.profile to be a string.")?,
+            cpu: text("cpu"),
+            tag: text("tag"),
+            timings: Timings::try_from(
+                json.get("timings")
+                    .ok_or("Expected history entry to have key `timings`.")?,
+            )?,
+        })
+    }
+}
+
+impl FromStr for History {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let entries = s
+            .lines()
+            .filter(|line| !line.trim().is_empty())
+            .enumerate()
+            .map(|(index, line)| {
+                let json = JsonValue::from_str(line)
+                    .map_err(|_| format!("line {} is not valid JSON.", index + 1))?;
+                HistoryEntry::try_from(&json)
+            })
+            .collect::<Result<_, _>>()?;
+
+        Ok(History { entries })
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use std::str::FromStr;
+
+    use tinyjson::JsonValue;
+
+    use super::{compare, format_timestamp, History, HistoryEntry};
+    use crate::day;
+    use crate::template::results::PartStatus;
+    use crate::template::timings::{PartTiming, Timing, Timings};
+    use crate::template::Day;
+
+    fn timings(days: &[(Day, f64, Option<f64>)]) -> Timings {
+        let part = |nanos| PartTiming {
+            nanos,
+            samples: 10,
+            stats: None,
+            alloc: None,
+        };
+
+        Timings {
+            data: days
+                .iter()
+                .map(|&(day, part_1, part_2)| Timing {
+                    day,
+                    part_1: Some(part(part_1)),
+                    part_2: part_2.map(part),
+                    part_1_killed: None,
+                    part_2_killed: None,
+                })
+                .collect(),
+        }
+    }
+
+    fn entry(commit: &str, tag: Option<&str>, timings: Timings) -> HistoryEntry {
+        HistoryEntry {
+            timestamp: 1_733_403_840,
+            commit: Some(commit.into()),
+            profile: "release".into(),
+            cpu: None,
+            tag: tag.map(Into::into),
+            timings,
+        }
+    }
+
+    fn history() -> History {
+        let lines: Vec<String> = [
+            entry(
+                "aaaaaaa",
+                Some("v1"),
+                timings(&[(day!(1), 100.0, Some(200.0))]),
+            ),
+            entry("bbbbbbb", None, timings(&[(day!(2), 50.0, None)])),
+            entry("ccccccc", None, timings(&[(day!(1), 80.0, None)])),
+        ]
+        .iter()
+        .map(|entry| JsonValue::from(entry).stringify().unwrap())
+        .collect();
+        History::from_str(&lines.join("\n")).unwrap()
+    }
+
+    #[test]
+    fn round_trips_entries() {
+        let history = history();
+        assert_eq!(history.entries.len(), 3);
+        assert_eq!(history.entries[0].tag.as_deref(), Some("v1"));
+        assert_eq!(history.entries[0].timings.data[0].total_nanos(), 300.0);
+        assert_eq!(history.entries[1].tag, None);
+        assert!(History::from_str("{}\n").is_err());
+    }
+
+    #[test]
+    fn finds_previous_run_of_day() {
+        let history = history();
+        let (entry, timing) = history.previous(day!(1)).unwrap();
+        assert_eq!(entry.commit.as_deref(), Some("ccccccc"));
+        assert_eq!(timing.total_nanos(), 80.0);
+        assert_eq!(
+            history.previous(day!(2)).unwrap().0.commit.as_deref(),
+            Some("bbbbbbb")
+        );
+        assert!(history.previous(day!(3)).is_none());
+    }
+
+    #[test]
+    fn finds_named_runs() {
+        let history = history();
+        assert_eq!(
+            history.named("v1").unwrap().commit.as_deref(),
+            Some("aaaaaaa")
+        );
+        assert_eq!(
+            history.named("bbb").unwrap().commit.as_deref(),
+            Some("bbbbbbb")
+        );
+        assert!(history.named("v2").is_none());
+    }
+
+    #[test]
+    fn compares_parts_with_baseline() {
+        let history = history();
+        let baseline = history.named("v1").unwrap();
+        let current = timings(&[(day!(1), 150.0, Some(100.0)), (day!(2), 10.0, None)]);
+
+        let deltas = compare(&current, |day| {
+            baseline.timings.data.iter().find(|t| t.day == day)
+        });
+        assert_eq!(deltas.len(), 2);
+        assert_eq!(deltas[0].change_pct(), 50.0);
+        assert_eq!(deltas[1].part, 2);
+        assert_eq!(deltas[1].change_pct(), -50.0);
+    }
+
+    #[test]
+    fn counts_missing_parts_as_regressions() {
+        let history = history();
+        let baseline = history.named("v1").unwrap();
+        let mut current = timings(&[(day!(1), 100.0, None)]);
+        current.data[0].part_2_killed = Some(PartStatus::Timeout);
+
+        let deltas = compare(&current, |day| {
+            baseline.timings.data.iter().find(|t| t.day == day)
+        });
+        assert_eq!(deltas.len(), 2);
+        assert_eq!(deltas[1].after_nanos, None);
+        assert_eq!(deltas[1].after_killed, Some(PartStatus::Timeout));
+        assert!(deltas[1].change_pct() > 1000.0);
+    }
+
+    #[test]
+    fn formats_timestamps() {
+        assert_eq!(format_timestamp(0), "1970-01-01 00:00 UTC");
+        assert_eq!(format_timestamp(1_733_403_840), "2024-12-05 13:04 UTC");
+        assert_eq!(format_timestamp(951_782_400), "2000-02-29 00:00 UTC");
+    }
+}
@@ -0,0 +1,97 @@
+/// Locations of the puzzle inputs of a day.
+///
+/// Besides the default `data/{year}/inputs/{day}.txt`, a day can have named inputs in
+/// `data/{year}/inputs/{day}/{name}.txt`, e.g. the inputs of teammates. Their expected answers
+/// are kept in the answers file next to those of the default input.
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+
+use crate::template::{Day, Year};
+
+/// Which input the days of a run read.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Inputs<'a> {
+    /// The default input of every day, `data/{year}/inputs/{day}.txt`.
+    #[default]
+    Default,
+    /// A directory with a `{day}.txt` file per day.
+    Dir(&'a Path),
+    /// The named input of every day, `data/{year}/inputs/{day}/{name}.txt`.
+    Named(&'a str),
+}
+
+impl Inputs<'_> {
+    /// Path to the input of `day`.
+    #[must_use]
+    pub fn path(self, year: Year, day: Day) -> PathBuf {
+        match self {
+            Inputs::Default => PathBuf::from(format!("./data/{year}/inputs/{day}.txt")),
+            Inputs::Dir(dir) => dir.join(format!("{day}.txt")),
+            Inputs::Named(name) => get_dir_for_named_inputs(year, day).join(format!("{name}.txt")),
+        }
+    }
+}
+
+fn get_dir_for_named_inputs(year: Year, day: Day) -> PathBuf {
+    PathBuf::from(format!("./data/{year}/inputs/{day}"))
+}
+
+/// Names of the named inputs of `day`, in order.
+#[must_use]
+pub fn named_inputs(year: Year, day: Day) -> Vec<String> {
+    let Ok(entries) = fs::read_dir(get_dir_for_named_inputs(year, day)) else {
+        return vec![];
+    };
+
+    let mut names: Vec<String> = entries
+        .flatten()
+        .filter_map(|entry| input_name(&entry.path()))
+        .collect();
+    names.sort();
+    names
+}
+
+/// Name of the input at `path`, e.g. `alice` for `alice.txt`.
+fn input_name(path: &Path) -> Option<String> {
+    let name = path.file_name()?.to_str()?.strip_suffix(".txt")?;
+    (!name.is_empty() && !name.starts_with('.')).then(|| name.to_string())
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use std::path::{Path, PathBuf};
+
+    use super::{input_name, Inputs};
+    use crate::{day, year};
+
+    #[test]
+    fn resolves_input_paths() {
+        let (year, day) = (year!(2024), day!(5));
+        assert_eq!(
+            Inputs::Default.path(year, day),
+            PathBuf::from("./data/2024/inputs/05.txt")
+        );
+        assert_eq!(
+            Inputs::Dir(Path::new("other")).path(year, day),
+            PathBuf::from("other/05.txt")
+        );
+        assert_eq!(
+            Inputs::Named("alice").path(year, day),
+            PathBuf::from("./data/2024/inputs/05/alice.txt")
+        );
+    }
+
+    #[test]
+    fn names_inputs_by_file_stem() {
+        assert_eq!(
+            input_name(Path::new("data/2024/inputs/05/alice.txt")),
+            Some("alice".into())
+        );
+        assert_eq!(input_name(Path::new("data/2024/inputs/05/notes.md")), None);
+        assert_eq!(input_name(Path::new("data/2024/inputs/05/.txt")), None);
+    }
+}
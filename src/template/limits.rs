@@ -0,0 +1,180 @@
+/// Wall-clock and memory limits for days that run in their own process.
+///
+/// Global limits are passed on the command line with `--timeout` and `--memory`. Limits for the
+/// whole year and for single days are read from `data/{year}/limits.json`, e.g.
+/// `{ "timeout": 60, "days": { "23": { "timeout": 600, "memory": 4096 } } }`.
+/// Timeouts are in seconds, memory limits in megabytes.
+use std::{collections::HashMap, fs, str::FromStr, time::Duration};
+
+use tinyjson::JsonValue;
+
+use crate::template::{Day, Year};
+
+fn get_path_for_limits(year: Year) -> String {
+    format!("./data/{year}/limits.json")
+}
+
+/// Limits of a single run. Unset limits do not apply.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Limits {
+    pub timeout: Option<Duration>,
+    pub memory_mb: Option<u64>,
+}
+
+impl Limits {
+    pub fn is_empty(&self) -> bool {
+        self.timeout.is_none() && self.memory_mb.is_none()
+    }
+
+    /// Use the limits of `fallback` where `self` has none.
+    #[must_use]
+    pub fn or(self, fallback: Limits) -> Limits {
+        Limits {
+            timeout: self.timeout.or(fallback.timeout),
+            memory_mb: self.memory_mb.or(fallback.memory_mb),
+        }
+    }
+}
+
+/// Limits configured for a year.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct LimitsConfig {
+    pub year: Limits,
+    pub days: HashMap<Day, Limits>,
+}
+
+impl LimitsConfig {
+    /// Read the limits of `year`. If the file is not present, no limits are configured.
+    pub fn read_from_file(year: Year) -> Result<Self, String> {
+        match fs::read_to_string(get_path_for_limits(year)) {
+            Ok(content) => LimitsConfig::try_from(content),
+            Err(_) => Ok(LimitsConfig::default()),
+        }
+    }
+
+    /// Limits of `day`. Limits passed on the command line take precedence over those of the day,
+    /// which take precedence over those of the year.
+    pub fn for_day(&self, day: Day, overrides: Limits) -> Limits {
+        let day_limits = self.days.get(&day).copied().unwrap_or_default();
+        overrides.or(day_limits).or(self.year)
+    }
+
+    /// Whether any limits apply to a run with `overrides`.
+    pub fn is_empty(&self, overrides: Limits) -> bool {
+        overrides.is_empty() && self.year.is_empty() && self.days.values().all(Limits::is_empty)
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+impl TryFrom<&JsonValue> for Limits {
+    type Error = String;
+
+    fn try_from(value: &JsonValue) -> Result<Self, Self::Error> {
+        let json = value
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or("Expected limits to be a JSON object.")?;
+
+        let number = |key: &str| match json.get(key) {
+            Some(v) if !v.is_null() => v
+                .get::<f64>()
+                .copied()
+                .filter(|x| *x > 0.0)
+                .map(Some)
+                .ok_or(format!("Expected limits.{key} to be a positive number.")),
+            _ => Ok(None),
+        };
+
+        #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+        Ok(Limits {
+            timeout: number("timeout")?.map(Duration::from_secs_f64),
+            memory_mb: number("memory")?.map(|x| x as u64),
+        })
+    }
+}
+
+impl TryFrom<String> for LimitsConfig {
+    type Error = String;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        let json = JsonValue::from_str(&value).or(Err("not valid JSON file."))?;
+
+        let days = match json
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or("expected JSON document to be an object.")?
+            .get("days")
+        {
+            Some(days) => days
+                .get::<HashMap<String, JsonValue>>()
+                .ok_or("expected `json.days` to be an object.")?
+                .iter()
+                .map(|(day, limits)| {
+                    let day = Day::from_str(day).map_err(|_| format!("invalid day `{day}`."))?;
+                    Ok((day, Limits::try_from(limits)?))
+                })
+                .collect::<Result<_, String>>()?,
+            None => HashMap::new(),
+        };
+
+        Ok(LimitsConfig {
+            year: Limits::try_from(&json)?,
+            days,
+        })
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use std::time::Duration;
+
+    use super::{Limits, LimitsConfig};
+    use crate::day;
+
+    #[test]
+    fn handles_json_limits() {
+        let json = r#"{ "timeout": 60, "days": { "23": { "timeout": 0.5, "memory": 4096 } } }"#;
+        let config = LimitsConfig::try_from(json.to_string()).unwrap();
+        assert_eq!(config.year.timeout, Some(Duration::from_secs(60)));
+        assert_eq!(config.year.memory_mb, None);
+        assert_eq!(
+            config.days[&day!(23)],
+            Limits {
+                timeout: Some(Duration::from_millis(500)),
+                memory_mb: Some(4096),
+            }
+        );
+        assert!(LimitsConfig::try_from(r#"{ "timeout": -1 }"#.to_string()).is_err());
+        assert!(LimitsConfig::try_from(r#"{ "days": { "26": {} } }"#.to_string()).is_err());
+    }
+
+    #[test]
+    fn prefers_overrides_then_day_then_year() {
+        let config = LimitsConfig::try_from(
+            r#"{ "timeout": 60, "memory": 1024, "days": { "23": { "timeout": 600 } } }"#
+                .to_string(),
+        )
+        .unwrap();
+
+        let day_23 = config.for_day(day!(23), Limits::default());
+        assert_eq!(day_23.timeout, Some(Duration::from_secs(600)));
+        assert_eq!(day_23.memory_mb, Some(1024));
+
+        let overrides = Limits {
+            timeout: Some(Duration::from_secs(5)),
+            memory_mb: None,
+        };
+        assert_eq!(
+            config.for_day(day!(23), overrides).timeout,
+            Some(Duration::from_secs(5))
+        );
+        assert_eq!(
+            config.for_day(day!(1), Limits::default()).timeout,
+            Some(Duration::from_secs(60))
+        );
+
+        assert!(LimitsConfig::default().is_empty(Limits::default()));
+        assert!(!LimitsConfig::default().is_empty(overrides));
+    }
+}
@@ -0,0 +1,234 @@
+// Converts the HTML of puzzle pages to markdown.
+//
+// Only handles the small set of tags that appear in puzzle descriptions and submission responses.
+// Emphasized code, which is how puzzles highlight answers, is written as ``*`161`*``.
+
+/// Extract the contents of all `<article>` elements of a page and convert them to markdown.
+pub fn articles_to_markdown(html: &str) -> String {
+    let mut articles = vec![];
+    let mut rest = html;
+
+    while let Some(start) = rest.find("<article") {
+        let Some(content_start) = rest[start..].find('>').map(|i| start + i + 1) else {
+            break;
+        };
+        let Some(end) = rest[content_start..]
+            .find("</article>")
+            .map(|i| content_start + i)
+        else {
+            break;
+        };
+        articles.push(to_markdown(&rest[content_start..end]));
+        rest = &rest[end + "</article>".len()..];
+    }
+
+    articles.join("\n\n")
+}
+
+/// Convert an HTML fragment to markdown.
+pub fn to_markdown(html: &str) -> String {
+    let mut out = String::new();
+    // buffered text of the `<code>` element we are in, and whether it contained `<em>`.
+    let mut code: Option<(String, bool)> = None;
+    let mut in_pre = false;
+    let mut links: Vec<String> = vec![];
+
+    for token in tokenize(html) {
+        match token {
+            Token::Text(text) => {
+                let text = decode_entities(text);
+                match code.as_mut() {
+                    Some((buf, _)) => buf.push_str(&text),
+                    None if in_pre => out.push_str(&text),
+                    None => {
+                        let text = collapse_whitespace(&text);
+                        // drop whitespace between block elements.
+                        if out.is_empty() || out.ends_with(['\n', ' ']) {
+                            out.push_str(text.trim_start());
+                        } else {
+                            out.push_str(&text);
+                        }
+                    }
+                }
+            }
+            Token::Open(tag, attrs) => match tag.as_str() {
+                "h2" => out.push_str("## "),
+                "pre" => {
+                    in_pre = true;
+                    out.push_str("```\n");
+                }
+                "code" => code = Some((String::new(), false)),
+                "em" => match code.as_mut() {
+                    Some((_, em)) => *em = true,
+                    None if !in_pre => out.push('*'),
+                    None => {}
+                },
+                "li" => out.push_str("- "),
+                "a" => {
+                    links.push(attribute(attrs, "href").unwrap_or_default());
+                    out.push('[');
+                }
+                "br" => out.push('\n'),
+                _ => {}
+            },
+            Token::Close(tag) => match tag.as_str() {
+                "h2" | "p" => out.push_str("\n\n"),
+                "ul" | "li" => out.push('\n'),
+                "pre" => {
+                    in_pre = false;
+                    if !out.ends_with('\n') {
+                        out.push('\n');
+                    }
+                    out.push_str("```\n\n");
+                }
+                "code" => {
+                    if let Some((buf, em)) = code.take() {
+                        if in_pre {
+                            out.push_str(&buf);
+                        } else if em {
+                            out.push_str(&format!("*`{buf}`*"));
+                        } else {
+                            out.push_str(&format!("`{buf}`"));
+                        }
+                    }
+                }
+                "em" if code.is_none() && !in_pre => out.push('*'),
+                "a" => {
+                    let href = links.pop().unwrap_or_default();
+                    out.push_str(&format!("]({href})"));
+                }
+                _ => {}
+            },
+        }
+    }
+
+    out.trim().to_string()
+}
+
+enum Token<'a> {
+    Text(&'a str),
+    Open(String, &'a str),
+    Close(String),
+}
+
+fn tokenize(html: &str) -> Vec<Token<'_>> {
+    let mut tokens = vec![];
+    let mut rest = html;
+
+    while !rest.is_empty() {
+        match rest.find('<') {
+            Some(0) => {
+                let Some(end) = rest.find('>') else {
+                    tokens.push(Token::Text(rest));
+                    break;
+                };
+                let inner = &rest[1..end];
+                if let Some(name) = inner.strip_prefix('/') {
+                    tokens.push(Token::Close(name.trim().to_ascii_lowercase()));
+                } else {
+                    let inner = inner.trim_end_matches('/');
+                    let (name, attrs) =
+                        inner.split_once(char::is_whitespace).unwrap_or((inner, ""));
+                    tokens.push(Token::Open(name.to_ascii_lowercase(), attrs));
+                }
+                rest = &rest[end + 1..];
+            }
+            Some(i) => {
+                tokens.push(Token::Text(&rest[..i]));
+                rest = &rest[i..];
+            }
+            None => {
+                tokens.push(Token::Text(rest));
+                break;
+            }
+        }
+    }
+
+    tokens
+}
+
+fn attribute(attrs: &str, name: &str) -> Option<String> {
+    let start = attrs.find(&format!("{name}=\""))? + name.len() + 2;
+    let end = attrs[start..].find('"')? + start;
+    Some(decode_entities(&attrs[start..end]))
+}
+
+fn collapse_whitespace(text: &str) -> String {
+    let mut out = String::with_capacity(text.len());
+    let mut last_space = false;
+    for c in text.chars() {
+        if c.is_whitespace() {
+            if !last_space {
+                out.push(' ');
+            }
+            last_space = true;
+        } else {
+            out.push(c);
+            last_space = false;
+        }
+    }
+    out
+}
+
+fn decode_entities(text: &str) -> String {
+    text.replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&quot;", "\"")
+        .replace("&#39;", "'")
+        .replace("&apos;", "'")
+        .replace("&nbsp;", " ")
+        .replace("&amp;", "&")
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{articles_to_markdown, to_markdown};
+
+    #[test]
+    fn converts_puzzle_articles() {
+        let html = r#"<html><body><main>
+<article class="day-desc"><h2>--- Day 1: Historian Hysteria ---</h2><p>For example:</p>
+<pre><code>3   4
+4   3
+</code></pre>
+<p>The total distance is <code><em>11</em></code>, see <a href="/2024/about">here</a>.</p>
+<ul><li>Pair <code>1</code> &amp; <em>3</em>.</li></ul>
+</article>
+<p>Not part of the puzzle.</p>
+<article class="day-desc"><h2 id="part2">--- Part Two ---</h2><p>Answer: <code><em>31</em></code>.</p></article>
+</main></body></html>"#;
+
+        assert_eq!(
+            articles_to_markdown(html),
+            [
+                "## --- Day 1: Historian Hysteria ---",
+                "",
+                "For example:",
+                "",
+                "```",
+                "3   4",
+                "4   3",
+                "```",
+                "",
+                "The total distance is *`11`*, see [here](/2024/about).",
+                "",
+                "- Pair `1` & *3*.",
+                "",
+                "## --- Part Two ---",
+                "",
+                "Answer: *`31`*.",
+            ]
+            .join("\n")
+        );
+    }
+
+    #[test]
+    fn decodes_entities_in_code_blocks() {
+        assert_eq!(
+            to_markdown("<pre><code>a &lt;-&gt; b\n</code></pre>"),
+            "```\na <-> b\n```"
+        );
+    }
+}
@@ -1,15 +1,32 @@
-use std::{env, fs};
+use std::{env, fs, io};
 
-pub mod aoc_cli;
+pub mod alloc;
+pub mod aoc_client;
 pub mod commands;
+pub mod example_tests;
+pub mod history;
+pub mod limits;
+pub mod registry;
+pub mod results;
 pub mod runner;
+pub mod stats;
 
 pub use day::*;
+pub use year::*;
 
+mod answers;
+mod attempts;
 mod day;
+mod examples;
+mod inputs;
+mod markdown;
 mod readme_benchmarks;
+mod report;
 mod run_multi;
+mod templates;
 mod timings;
+mod watch;
+mod year;
 
 pub const ANSI_ITALIC: &str = "\x1b[3m";
 pub const ANSI_BOLD: &str = "\x1b[1m";
@@ -17,52 +34,109 @@ pub const ANSI_RESET: &str = "\x1b[0m";
 
 /// Helper function that reads a text file to a string.
 #[must_use]
-pub fn read_file(folder: &str, day: Day) -> String {
+pub fn read_file(folder: &str, year: Year, day: Day) -> String {
     let cwd = env::current_dir().unwrap();
-    let filepath = cwd.join("data").join(folder).join(format!("{day}.txt"));
+    let filepath = cwd
+        .join("data")
+        .join(year.to_string())
+        .join(folder)
+        .join(format!("{day}.txt"));
     let f = fs::read_to_string(filepath);
     f.expect("could not open input file")
 }
 
 /// Helper function that reads a text file to string, appending a part suffix. E.g. like `01-2.txt`.
 #[must_use]
-pub fn read_file_part(folder: &str, day: Day, part: u8) -> String {
+pub fn read_file_part(folder: &str, year: Year, day: Day, part: u8) -> String {
     let cwd = env::current_dir().unwrap();
     let filepath = cwd
         .join("data")
+        .join(year.to_string())
         .join(folder)
         .join(format!("{day}-{part}.txt"));
     let f = fs::read_to_string(filepath);
     f.expect("could not open input file")
 }
 
-/// Creates the constant `DAY` and sets up the input and runner for each part.
+/// Helper function that reads the input of a day: the file at `path`, stdin if `path` is `-`, or
+/// `data/{year}/inputs/{day}.txt` if no path is given.
+pub fn read_input(year: Year, day: Day, path: Option<&str>) -> io::Result<String> {
+    match path {
+        Some("-") => io::read_to_string(io::stdin()),
+        Some(path) => fs::read_to_string(path),
+        None => fs::read_to_string(
+            env::current_dir()?
+                .join("data")
+                .join(year.to_string())
+                .join("inputs")
+                .join(format!("{day}.txt")),
+        ),
+    }
+}
+
+/// Creates the constants `YEAR` and `DAY` and sets up the input and runner for each part.
 ///
-/// The optional, second parameter (1 or 2) allows you to only run a single part of the solution.
+/// The optional, third parameter (1 or 2) allows you to only run a single part of the solution.
+///
+/// Also emits the `SOLUTION` registry entry used to run the day in-process, and a test for every
+/// example of the day with expected answers (see `data/{year}/examples/{day}.json`).
 #[macro_export]
 macro_rules! solution {
-    ($day:expr) => {
-        $crate::solution!(@impl $day, [part_one, 1] [part_two, 2]);
+    ($year:expr, $day:expr, 1) => {
+        $crate::solution!(@impl $year, $day, [part_one, 1]);
     };
-    ($day:expr, 1) => {
-        $crate::solution!(@impl $day, [part_one, 1]);
+    ($year:expr, $day:expr, 2) => {
+        $crate::solution!(@impl $year, $day, [part_two, 2]);
     };
-    ($day:expr, 2) => {
-        $crate::solution!(@impl $day, [part_two, 2]);
+    ($year:expr, $day:expr) => {
+        $crate::solution!(@impl $year, $day, [part_one, 1] [part_two, 2]);
     };
 
-    (@impl $day:expr, $( [$func:expr, $part:expr] )*) => {
+    (@impl $year:expr, $day:expr, $( [$func:expr, $part:expr] )*) => {
+        /// The year of the current day.
+        const YEAR: $crate::template::Year = $crate::year!($year);
         /// The current day.
-        const DAY: $crate::template::Day = $crate::day!($day);
+        const DAY: $crate::template::Day = $crate::day!($day, $year);
+
+        /// Registry entry for the current day.
+        pub const SOLUTION: $crate::template::registry::Solution =
+            $crate::template::registry::Solution {
+                year: YEAR,
+                day: DAY,
+                parts: &[$(
+                    ($part, |input, year, day, part, options| {
+                        $crate::template::runner::run_part_with($func, input, year, day, part, options)
+                    }),
+                )*],
+            };
 
         #[cfg(feature = "dhat-heap")]
         #[global_allocator]
         static ALLOC: dhat::Alloc = dhat::Alloc;
 
+        // NOTE: unused when the day is compiled into the main binary's registry.
+        #[allow(dead_code)]
         fn main() {
-            use $crate::template::runner::*;
-            let input = $crate::template::read_file("inputs", DAY);
-            $( run_part($func, &input, DAY, $part); )*
+            let options = $crate::template::runner::RunOptions::from_args();
+            let input = $crate::template::read_input(YEAR, DAY, options.input.as_deref())
+                .unwrap_or_else(|e| {
+                    eprintln!("could not open input file: {e}");
+                    std::process::exit(1);
+                });
+            SOLUTION.run(&input, &options);
+        }
+
+        /// Tests of the examples with expected answers, generated by the build script.
+        #[cfg(test)]
+        mod example_tests {
+            include!(concat!(
+                env!("OUT_DIR"),
+                "/example_tests/",
+                stringify!($year),
+                "-",
+                stringify!($day),
+                ".rs"
+            ));
         }
     };
 }
@@ -1,11 +1,16 @@
 /// Module that updates the readme me with timing information.
 /// The approach taken is similar to how `aoc-readme-stars` handles this.
-use std::{fs, io};
+use std::{fs, io, time::Duration};
 
-use crate::template::timings::Timings;
-use crate::template::Day;
+use crate::template::alloc::{format_bytes, AllocStats};
+use crate::template::stats::{BenchStats, Stat};
+use crate::template::timings::{Timing, Timings};
+use crate::template::{Day, Year};
 
-static MARKER: &str = "<!--- benchmarking table --->";
+/// Marker around the benchmark table of a year. Every year has its own table.
+fn marker(year: Year) -> String {
+    format!("<!--- benchmarking table {year} --->")
+}
 
 #[allow(dead_code)]
 #[derive(Debug)]
@@ -26,158 +31,351 @@ pub struct TablePosition {
 }
 
 #[must_use]
-pub fn get_path_for_bin(day: Day) -> String {
-    format!("./src/bin/{day}.rs")
+pub fn get_path_for_bin(year: Year, day: Day) -> String {
+    format!("./src/bin/{year}-{day}.rs")
 }
 
-fn locate_table(readme: &str) -> Result<TablePosition, Error> {
-    let matches: Vec<_> = readme.match_indices(MARKER).collect();
+/// Locate the table of a year. Returns `None` if the README has no table for the year yet.
+fn locate_table(readme: &str, year: Year) -> Result<Option<TablePosition>, Error> {
+    let marker = marker(year);
+    let matches: Vec<_> = readme.match_indices(&marker).collect();
 
-    if matches.len() > 2 {
-        return Err(Error::Parser(
-            "{}: too many occurences of marker in README.".into(),
-        ));
+    if matches.is_empty() {
+        return Ok(None);
     }
 
-    let pos_start = matches
-        .first()
-        .map(|m| m.0)
-        .ok_or_else(|| Error::Parser("Could not find table start position.".into()))?;
+    if matches.len() != 2 {
+        return Err(Error::Parser(format!(
+            "{marker}: expected the marker exactly twice in README."
+        )));
+    }
+
+    Ok(Some(TablePosition {
+        pos_start: matches[0].0,
+        pos_end: matches[1].0 + matches[1].1.len(),
+    }))
+}
+
+/// Format the timing of one part, e.g. `74.1ms`, or why it was killed, e.g. `TIMEOUT`.
+fn timing_cell(timing: &Timing, part: u8) -> String {
+    if let Some(label) = timing.killed(part).and_then(|status| status.killed_label()) {
+        return label.into();
+    }
+
+    timing
+        .part(part)
+        .map_or_else(|| "-".into(), |part| format_nanos(part.nanos))
+}
+
+/// Format a duration in nanoseconds like the benchmark tables, e.g. `74.1ms`.
+pub fn format_nanos(nanos: f64) -> String {
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    let value = Duration::from_nanos(nanos as u64);
+    format!("{value:.1?}")
+}
 
-    let pos_end = matches
-        .last()
-        .map(|m| m.0 + m.1.len())
-        .ok_or_else(|| Error::Parser("Could not find table end position.".into()))?;
+/// Format the extra statistic columns of one part, e.g. ` `73.9ms` | `80.1ms` |`.
+fn stat_cells(stats: Option<&BenchStats>, columns: &[Stat]) -> String {
+    columns
+        .iter()
+        .map(|stat| match stats {
+            Some(stats) => format!(" `{}` |", format_nanos(stats.get(*stat))),
+            None => " `-` |".into(),
+        })
+        .collect()
+}
 
-    Ok(TablePosition { pos_start, pos_end })
+/// Format the memory column of one part, e.g. ` `1.5 MiB` |`.
+fn memory_cell(alloc: Option<&AllocStats>, memory: bool) -> String {
+    match (memory, alloc) {
+        (false, _) => String::new(),
+        (true, Some(alloc)) => format!(" `{}` |", format_bytes(alloc.peak_bytes)),
+        (true, None) => " `-` |".into(),
+    }
 }
 
-fn construct_table(prefix: &str, timings: Timings, total_millis: f64) -> String {
-    let header = format!("{prefix} Benchmarks");
+fn construct_table(
+    prefix: &str,
+    year: Year,
+    timings: Timings,
+    total_millis: f64,
+    columns: &[Stat],
+    memory: bool,
+) -> String {
+    let header = format!("{prefix} {year} Benchmarks");
+
+    let stat_headers = |part: u8| -> String {
+        let memory_header = if memory {
+            format!(" Part {part} (peak memory) |")
+        } else {
+            String::new()
+        };
+        columns
+            .iter()
+            .map(|stat| format!(" Part {part} ({stat}) |"))
+            .chain([memory_header])
+            .collect()
+    };
+    let stat_alignment = " :---: |".repeat(columns.len() + usize::from(memory));
 
     let mut lines: Vec<String> = vec![
-        MARKER.into(),
+        marker(year),
         header,
         String::new(),
-        "| Day | Part 1 | Part 2 |".into(),
-        "| :---: | :---: | :---:  |".into(),
+        format!(
+            "| Day | Part 1 |{} Part 2 |{}",
+            stat_headers(1),
+            stat_headers(2)
+        ),
+        format!("| :---: | :---: |{stat_alignment} :---:  |{stat_alignment}"),
     ];
 
     for timing in timings.data {
-        let path = get_path_for_bin(timing.day);
+        let path = get_path_for_bin(year, timing.day);
         lines.push(format!(
-            "| [Day {}]({}) | `{}` | `{}` |",
+            "| [Day {}]({}) | `{}` |{}{} `{}` |{}{}",
             timing.day.into_inner(),
             path,
-            timing.part_1.unwrap_or_else(|| "-".into()),
-            timing.part_2.unwrap_or_else(|| "-".into())
+            timing_cell(&timing, 1),
+            stat_cells(timing.part(1).and_then(|p| p.stats.as_ref()), columns),
+            memory_cell(timing.part(1).and_then(|p| p.alloc.as_ref()), memory),
+            timing_cell(&timing, 2),
+            stat_cells(timing.part(2).and_then(|p| p.stats.as_ref()), columns),
+            memory_cell(timing.part(2).and_then(|p| p.alloc.as_ref()), memory),
         ));
     }
 
     lines.push(String::new());
     lines.push(format!("**Total: {total_millis:.2}ms**"));
-    lines.push(MARKER.into());
+    lines.push(marker(year));
 
     lines.join("\n")
 }
 
-fn update_content(s: &mut String, timings: Timings, total_millis: f64) -> Result<(), Error> {
-    let positions = locate_table(s)?;
-    let table = construct_table("##", timings, total_millis);
-    s.replace_range(positions.pos_start..positions.pos_end, &table);
+/// Replace the table of `year`, or append it to the end if there is none yet.
+fn update_content(
+    s: &mut String,
+    year: Year,
+    timings: Timings,
+    total_millis: f64,
+    columns: &[Stat],
+    memory: bool,
+) -> Result<(), Error> {
+    let table = construct_table("##", year, timings, total_millis, columns, memory);
+    match locate_table(s, year)? {
+        Some(positions) => s.replace_range(positions.pos_start..positions.pos_end, &table),
+        None => {
+            let trimmed_len = s.trim_end().len();
+            s.truncate(trimmed_len);
+            s.push_str("\n\n");
+            s.push_str(&table);
+            s.push('\n');
+        }
+    }
     Ok(())
 }
 
-pub fn update(timings: Timings) -> Result<(), Error> {
+/// Update the benchmark table of `year` in the README. `columns` selects optional statistic columns,
+/// `memory` adds a column with the peak memory of each part.
+pub fn update(year: Year, timings: Timings, columns: &[Stat], memory: bool) -> Result<(), Error> {
     let path = "README.md";
     let mut readme = String::from_utf8_lossy(&fs::read(path)?).to_string();
     let total_millis = timings.total_millis();
-    update_content(&mut readme, timings, total_millis)?;
+    update_content(&mut readme, year, timings, total_millis, columns, memory)?;
     fs::write(path, &readme)?;
     Ok(())
 }
 
 #[cfg(feature = "test_lib")]
 mod tests {
-    use super::{update_content, MARKER};
-    use crate::{day, template::timings::Timing, template::timings::Timings};
+    use super::{marker, update_content};
+    use crate::template::alloc::AllocStats;
+    use crate::template::results::PartStatus;
+    use crate::template::stats::{BenchStats, Stat};
+    use crate::template::timings::{PartTiming, Timing, Timings};
+    use crate::template::Day;
+    use crate::{day, year};
+
+    fn timing(day: Day, part_1: f64, part_2: f64) -> Timing {
+        let part = |nanos| {
+            Some(PartTiming {
+                nanos,
+                samples: 10,
+                stats: None,
+                alloc: None,
+            })
+        };
+
+        Timing {
+            day,
+            part_1: part(part_1),
+            part_2: part(part_2),
+            part_1_killed: None,
+            part_2_killed: None,
+        }
+    }
 
     fn get_mock_timings() -> Timings {
         Timings {
             data: vec![
-                Timing {
-                    day: day!(1),
-                    part_1: Some("10ms".into()),
-                    part_2: Some("20ms".into()),
-                    total_nanos: 3e+10,
-                },
-                Timing {
-                    day: day!(2),
-                    part_1: Some("30ms".into()),
-                    part_2: Some("40ms".into()),
-                    total_nanos: 7e+10,
-                },
-                Timing {
-                    day: day!(4),
-                    part_1: Some("40ms".into()),
-                    part_2: Some("50ms".into()),
-                    total_nanos: 9e+10,
-                },
+                timing(day!(1), 10_000_000.0, 20_000_000.0),
+                timing(day!(2), 30_000_000.0, 40_000_000.0),
+                timing(day!(4), 40_000_000.0, 50_000_000.0),
             ],
         }
     }
 
     #[test]
-    #[should_panic]
-    fn errors_if_marker_not_present() {
-        let mut s = "# readme".to_string();
-        update_content(&mut s, get_mock_timings(), 190.0).unwrap();
+    fn appends_table_if_marker_not_present() {
+        let mut s = "# readme\n".to_string();
+        update_content(&mut s, year!(2024), get_mock_timings(), 190.0, &[], false).unwrap();
+        assert!(s.starts_with("# readme\n\n<!--- benchmarking table 2024 --->\n## 2024 Benchmarks"));
+        assert!(s.ends_with("<!--- benchmarking table 2024 --->\n"));
     }
 
     #[test]
     #[should_panic]
     fn errors_if_too_many_markers_present() {
-        let mut s = format!("{} {} {}", MARKER, MARKER, MARKER);
-        update_content(&mut s, get_mock_timings(), 190.0).unwrap();
+        let marker = marker(year!(2024));
+        let mut s = format!("{marker} {marker} {marker}");
+        update_content(&mut s, year!(2024), get_mock_timings(), 190.0, &[], false).unwrap();
     }
 
     #[test]
     fn updates_empty_benchmarks() {
-        let mut s = format!("foo\nbar\n{}{}\nbaz", MARKER, MARKER);
-        update_content(&mut s, get_mock_timings(), 190.0).unwrap();
-        assert_eq!(s.contains("## Benchmarks"), true);
+        let marker = marker(year!(2024));
+        let mut s = format!("foo\nbar\n{marker}{marker}\nbaz");
+        update_content(&mut s, year!(2024), get_mock_timings(), 190.0, &[], false).unwrap();
+        assert_eq!(s.contains("## 2024 Benchmarks"), true);
     }
 
     #[test]
     fn updates_existing_benchmarks() {
-        let mut s = format!("foo\nbar\n{}{}\nbaz", MARKER, MARKER);
-        update_content(&mut s, get_mock_timings(), 190.0).unwrap();
-        update_content(&mut s, get_mock_timings(), 190.0).unwrap();
-        assert_eq!(s.matches(MARKER).collect::<Vec<&str>>().len(), 2);
-        assert_eq!(s.matches("## Benchmarks").collect::<Vec<&str>>().len(), 1);
+        let marker = marker(year!(2024));
+        let mut s = format!("foo\nbar\n{marker}{marker}\nbaz");
+        update_content(&mut s, year!(2024), get_mock_timings(), 190.0, &[], false).unwrap();
+        update_content(&mut s, year!(2024), get_mock_timings(), 190.0, &[], false).unwrap();
+        assert_eq!(s.matches(&marker).collect::<Vec<&str>>().len(), 2);
+        assert_eq!(
+            s.matches("## 2024 Benchmarks").collect::<Vec<&str>>().len(),
+            1
+        );
+    }
+
+    #[test]
+    fn keeps_tables_per_year() {
+        let mut s = "foo".to_string();
+        update_content(&mut s, year!(2023), get_mock_timings(), 190.0, &[], false).unwrap();
+        update_content(&mut s, year!(2024), get_mock_timings(), 190.0, &[], false).unwrap();
+        update_content(&mut s, year!(2023), get_mock_timings(), 190.0, &[], false).unwrap();
+        assert_eq!(s.matches("## 2023 Benchmarks").count(), 1);
+        assert_eq!(s.matches("## 2024 Benchmarks").count(), 1);
+        assert!(s.find("## 2023").unwrap() < s.find("## 2024").unwrap());
     }
 
     #[test]
     fn format_benchmarks() {
-        let mut s = format!("foo\nbar\n{}\n{}\nbaz", MARKER, MARKER);
-        update_content(&mut s, get_mock_timings(), 190.0).unwrap();
+        let marker = marker(year!(2024));
+        let mut s = format!("foo\nbar\n{marker}\n{marker}\nbaz");
+        update_content(&mut s, year!(2024), get_mock_timings(), 190.0, &[], false).unwrap();
         let expected = [
             "foo",
             "bar",
-            "<!--- benchmarking table --->",
-            "## Benchmarks",
+            "<!--- benchmarking table 2024 --->",
+            "## 2024 Benchmarks",
             "",
             "| Day | Part 1 | Part 2 |",
             "| :---: | :---: | :---:  |",
-            "| [Day 1](./src/bin/01.rs) | `10ms` | `20ms` |",
-            "| [Day 2](./src/bin/02.rs) | `30ms` | `40ms` |",
-            "| [Day 4](./src/bin/04.rs) | `40ms` | `50ms` |",
+            "| [Day 1](./src/bin/2024-01.rs) | `10.0ms` | `20.0ms` |",
+            "| [Day 2](./src/bin/2024-02.rs) | `30.0ms` | `40.0ms` |",
+            "| [Day 4](./src/bin/2024-04.rs) | `40.0ms` | `50.0ms` |",
             "",
             "**Total: 190.00ms**",
-            "<!--- benchmarking table --->",
+            "<!--- benchmarking table 2024 --->",
             "baz",
         ]
         .join("\n");
         assert_eq!(s, expected);
     }
+
+    #[test]
+    fn format_benchmarks_with_stats() {
+        let mut timings = get_mock_timings();
+        timings.data.truncate(1);
+        timings.data[0].part_1.as_mut().unwrap().stats = Some(BenchStats {
+            mean: 10_000_000.0,
+            median: 9_500_000.0,
+            min: 9_000_000.0,
+            max: 12_000_000.0,
+            stddev: 500_000.0,
+            p95: 11_000_000.0,
+            outliers: 0,
+        });
+
+        let marker = marker(year!(2024));
+        let mut s = format!("{marker}\n{marker}");
+        update_content(
+            &mut s,
+            year!(2024),
+            timings,
+            30.0,
+            &[Stat::Median, Stat::P95],
+            false,
+        )
+        .unwrap();
+        let lines: Vec<&str> = s.lines().collect();
+        assert_eq!(
+            lines[3],
+            "| Day | Part 1 | Part 1 (median) | Part 1 (p95) | Part 2 | Part 2 (median) | Part 2 (p95) |"
+        );
+        assert_eq!(
+            lines[4],
+            "| :---: | :---: | :---: | :---: | :---:  | :---: | :---: |"
+        );
+        assert_eq!(
+            lines[5],
+            "| [Day 1](./src/bin/2024-01.rs) | `10.0ms` | `9.5ms` | `11.0ms` | `20.0ms` | `-` | `-` |"
+        );
+    }
+
+    #[test]
+    fn format_benchmarks_with_memory() {
+        let mut timings = get_mock_timings();
+        timings.data.truncate(1);
+        timings.data[0].part_2.as_mut().unwrap().alloc = Some(AllocStats {
+            peak_bytes: 3 * 1024 * 1024,
+            total_bytes: 4 * 1024 * 1024,
+            allocations: 10,
+        });
+
+        let marker = marker(year!(2024));
+        let mut s = format!("{marker}\n{marker}");
+        update_content(&mut s, year!(2024), timings, 30.0, &[Stat::Median], true).unwrap();
+        let lines: Vec<&str> = s.lines().collect();
+        assert_eq!(
+            lines[3],
+            "| Day | Part 1 | Part 1 (median) | Part 1 (peak memory) | Part 2 | Part 2 (median) | Part 2 (peak memory) |"
+        );
+        assert_eq!(
+            lines[5],
+            "| [Day 1](./src/bin/2024-01.rs) | `10.0ms` | `-` | `-` | `20.0ms` | `-` | `3.0 MiB` |"
+        );
+    }
+
+    #[test]
+    fn format_killed_parts() {
+        let mut timings = get_mock_timings();
+        timings.data.truncate(1);
+        timings.data[0].part_2 = None;
+        timings.data[0].part_2_killed = Some(PartStatus::Timeout);
+
+        let marker = marker(year!(2024));
+        let mut s = format!("{marker}\n{marker}");
+        update_content(&mut s, year!(2024), timings, 10.0, &[Stat::Median], false).unwrap();
+        let lines: Vec<&str> = s.lines().collect();
+        assert_eq!(
+            lines[5],
+            "| [Day 1](./src/bin/2024-01.rs) | `10.0ms` | `-` | `TIMEOUT` | `-` |"
+        );
+    }
 }
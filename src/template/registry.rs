@@ -0,0 +1,93 @@
+/// Registry of solutions that can be run in-process.
+///
+/// Every day registers itself through the `solution!` macro, which emits a `SOLUTION` constant.
+/// The main binary collects these constants (see `build.rs`) so that `all` and `time` can run
+/// every day from a single executable instead of invoking cargo once per day.
+use crate::template::results::PartResult;
+use crate::template::runner::RunOptions;
+use crate::template::{Day, Year};
+
+/// Runs a single part of a solution against the given input.
+pub type PartRunner = fn(&str, Year, Day, u8, &RunOptions) -> PartResult;
+
+/// The registered parts of a single day.
+pub struct Solution {
+    pub year: Year,
+    pub day: Day,
+    pub parts: &'static [(u8, PartRunner)],
+}
+
+impl Solution {
+    /// Run every registered part of this solution against `input`.
+    pub fn run(&self, input: &str, options: &RunOptions) -> Vec<PartResult> {
+        self.parts
+            .iter()
+            .map(|(part, runner)| runner(input, self.year, self.day, *part, options))
+            .collect()
+    }
+}
+
+/// Look up the registered solution for a day of a year.
+pub fn find(solutions: &[Solution], year: Year, day: Day) -> Option<&Solution> {
+    solutions.iter().find(|s| s.year == year && s.day == day)
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{find, Solution};
+    use crate::template::results::{PartResult, PartStatus};
+    use crate::template::runner::RunOptions;
+    use crate::template::{Day, Year};
+    use crate::{day, year};
+
+    fn echo(input: &str, _year: Year, _day: Day, part: u8, _options: &RunOptions) -> PartResult {
+        PartResult {
+            part,
+            answer: Some(input.to_string()),
+            nanos: 0.0,
+            samples: 1,
+            stats: None,
+            alloc: None,
+            status: PartStatus::Solved,
+            error: None,
+        }
+    }
+
+    const SOLUTIONS: &[Solution] = &[
+        Solution {
+            year: year!(2024),
+            day: day!(1),
+            parts: &[(1, echo), (2, echo)],
+        },
+        Solution {
+            year: year!(2024),
+            day: day!(3),
+            parts: &[(1, echo)],
+        },
+        Solution {
+            year: year!(2023),
+            day: day!(2),
+            parts: &[(1, echo)],
+        },
+    ];
+
+    #[test]
+    fn finds_registered_days() {
+        assert_eq!(
+            find(SOLUTIONS, year!(2024), day!(3)).map(|s| s.parts.len()),
+            Some(1)
+        );
+        assert!(find(SOLUTIONS, year!(2024), day!(2)).is_none());
+        assert!(find(SOLUTIONS, year!(2023), day!(2)).is_some());
+    }
+
+    #[test]
+    fn runs_all_parts() {
+        let results = find(SOLUTIONS, year!(2024), day!(1))
+            .unwrap()
+            .run("42", &RunOptions::default());
+        assert_eq!(results.len(), 2);
+        assert_eq!(results[1].part, 2);
+        assert_eq!(results[1].answer, Some("42".into()));
+    }
+}
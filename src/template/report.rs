@@ -0,0 +1,414 @@
+/// Standalone HTML report of the stored benchmarks, with inline SVG charts.
+///
+/// The report shows the timings of every day on a log scale, the total of the year over the
+/// benchmark history and the peak memory of every part where it was recorded.
+use std::{collections::BTreeMap, fmt::Write};
+
+use crate::template::alloc::format_bytes;
+use crate::template::history::{format_timestamp, History};
+use crate::template::readme_benchmarks::{format_nanos, get_path_for_bin};
+use crate::template::timings::{Timing, Timings};
+use crate::template::{Day, Year};
+
+const WIDTH: f64 = 800.0;
+const HEIGHT: f64 = 300.0;
+const MARGIN_LEFT: f64 = 80.0;
+const MARGIN_RIGHT: f64 = 20.0;
+const MARGIN_TOP: f64 = 20.0;
+const MARGIN_BOTTOM: f64 = 40.0;
+const COLORS: [&str; 2] = ["#4e79a7", "#f28e2b"];
+
+const STYLE: &str = "body { font-family: sans-serif; max-width: 860px; margin: 2em auto; color: #222; }
+svg { font-size: 12px; }
+table { border-collapse: collapse; }
+td, th { padding: 0.2em 0.8em; text-align: right; border-bottom: 1px solid #ddd; }
+.legend span { display: inline-block; width: 1em; height: 1em; margin: 0 0.3em 0 1em; vertical-align: middle; }";
+
+/// Render the report of `year` from its stored timings and their history.
+pub fn render(year: Year, timings: &Timings, history: &History) -> String {
+    let mut html = String::new();
+
+    let _ = writeln!(
+        html,
+        "<!DOCTYPE html>\n<html lang=\"en\">\n<head>\n<meta charset=\"utf-8\">\n<title>Advent of Code {year} benchmarks</title>\n<style>\n{STYLE}\n</style>\n</head>\n<body>\n<h1>Advent of Code {year} benchmarks</h1>"
+    );
+
+    if timings.data.is_empty() {
+        html.push_str(
+            "<p>No benchmarks stored yet, run <code>cargo time --all --store</code>.</p>\n",
+        );
+    } else {
+        let _ = writeln!(
+            html,
+            "<p>Total: {:.2}ms</p>\n<h2>Timings per day</h2>\n{}\n{}",
+            timings.total_millis(),
+            legend(),
+            log_bar_chart(
+                &part_values(timings, |t, part| t.part(part).map(|p| p.nanos)),
+                format_nanos
+            )
+        );
+    }
+
+    let totals = totals_over_time(history);
+    if totals.len() > 1 {
+        let _ = writeln!(html, "<h2>Total over time</h2>\n{}", line_chart(&totals));
+    }
+
+    #[allow(clippy::cast_precision_loss)]
+    let memory = part_values(timings, |t, part| {
+        t.part(part)
+            .and_then(|p| p.alloc)
+            .map(|alloc| alloc.peak_bytes as f64)
+    });
+    if memory
+        .iter()
+        .any(|(_, values)| values.iter().any(Option::is_some))
+    {
+        #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+        let chart = log_bar_chart(&memory, |bytes| format_bytes(bytes as u64));
+        let _ = writeln!(html, "<h2>Peak memory per day</h2>\n{}\n{chart}", legend());
+    }
+
+    if !timings.data.is_empty() {
+        html.push_str(&table(year, timings));
+    }
+
+    html.push_str("</body>\n</html>\n");
+    html
+}
+
+/// Values of both parts of every day, e.g. their timings.
+fn part_values(
+    timings: &Timings,
+    value: impl Fn(&Timing, u8) -> Option<f64>,
+) -> Vec<(Day, [Option<f64>; 2])> {
+    timings
+        .data
+        .iter()
+        .map(|t| (t.day, [value(t, 1), value(t, 2)]))
+        .collect()
+}
+
+/// Total of the year after every run in the history, with the time of the run and its label.
+/// A run only benches some days, so the latest timing of every other day is carried over.
+fn totals_over_time(history: &History) -> Vec<(String, f64)> {
+    let mut latest: BTreeMap<Day, f64> = BTreeMap::new();
+
+    history
+        .entries()
+        .iter()
+        .map(|entry| {
+            for timing in &entry.timings.data {
+                latest.insert(timing.day, timing.total_nanos());
+            }
+            let total_millis = latest.values().sum::<f64>() / 1_000_000_f64;
+            (
+                format!(
+                    "{} ({})",
+                    format_timestamp(entry.timestamp),
+                    entry.commit.as_deref().unwrap_or("unknown commit")
+                ),
+                total_millis,
+            )
+        })
+        .collect()
+}
+
+fn legend() -> String {
+    format!(
+        "<p class=\"legend\"><span style=\"background: {}\"></span>Part 1<span style=\"background: {}\"></span>Part 2</p>",
+        COLORS[0], COLORS[1]
+    )
+}
+
+/// Bar chart with a bar per part and day, on a log scale.
+fn log_bar_chart(rows: &[(Day, [Option<f64>; 2])], format_value: impl Fn(f64) -> String) -> String {
+    let values: Vec<f64> = rows
+        .iter()
+        .flat_map(|(_, values)| values.iter().flatten().copied())
+        .filter(|x| *x > 0.0)
+        .collect();
+
+    let low = values
+        .iter()
+        .copied()
+        .fold(f64::INFINITY, f64::min)
+        .log10()
+        .floor();
+    let high = values.iter().copied().fold(1.0, f64::max).log10().ceil();
+    let (low, high) = if low.is_finite() && low < high {
+        (low, high)
+    } else {
+        (0.0, 1.0)
+    };
+
+    let plot_width = WIDTH - MARGIN_LEFT - MARGIN_RIGHT;
+    let plot_height = HEIGHT - MARGIN_TOP - MARGIN_BOTTOM;
+    let bottom = MARGIN_TOP + plot_height;
+    let y = |value: f64| bottom - (value.log10() - low) / (high - low) * plot_height;
+
+    let mut svg = svg_open();
+
+    #[allow(clippy::cast_possible_truncation)]
+    for exponent in low as i32..=high as i32 {
+        let value = 10_f64.powi(exponent);
+        let _ = write!(
+            svg,
+            "<line x1=\"{MARGIN_LEFT}\" x2=\"{}\" y1=\"{y:.1}\" y2=\"{y:.1}\" stroke=\"#ddd\"/><text x=\"{}\" y=\"{:.1}\" text-anchor=\"end\">{}</text>",
+            WIDTH - MARGIN_RIGHT,
+            MARGIN_LEFT - 6.0,
+            y(value) + 4.0,
+            format_value(value),
+            y = y(value),
+        );
+    }
+
+    #[allow(clippy::cast_precision_loss)]
+    let group_width = plot_width / rows.len().max(1) as f64;
+    let bar_width = group_width * 0.4;
+
+    for (index, (day, values)) in rows.iter().enumerate() {
+        #[allow(clippy::cast_precision_loss)]
+        let group_x = MARGIN_LEFT + index as f64 * group_width;
+
+        for (part, value) in values.iter().enumerate() {
+            let Some(value) = value.filter(|x| *x > 0.0) else {
+                continue;
+            };
+            // NOTE: values at the lowest tick still get a sliver, so they remain visible.
+            let top = y(value).min(bottom - 1.0);
+            #[allow(clippy::cast_precision_loss)]
+            let _ = write!(
+                svg,
+                "<rect x=\"{:.1}\" y=\"{top:.1}\" width=\"{bar_width:.1}\" height=\"{:.1}\" fill=\"{}\"><title>Day {day} part {}: {}</title></rect>",
+                group_x + group_width * 0.1 + part as f64 * bar_width,
+                bottom - top,
+                COLORS[part],
+                part + 1,
+                format_value(value),
+            );
+        }
+
+        let _ = write!(
+            svg,
+            "<text x=\"{:.1}\" y=\"{:.1}\" text-anchor=\"middle\">{}</text>",
+            group_x + group_width / 2.0,
+            bottom + 16.0,
+            day.into_inner()
+        );
+    }
+
+    svg.push_str("</svg>");
+    svg
+}
+
+/// Line chart of the totals after every run, in milliseconds.
+fn line_chart(totals: &[(String, f64)]) -> String {
+    let max = totals.iter().map(|(_, x)| *x).fold(0.0, f64::max).max(1e-3) * 1.1;
+
+    let plot_width = WIDTH - MARGIN_LEFT - MARGIN_RIGHT;
+    let plot_height = HEIGHT - MARGIN_TOP - MARGIN_BOTTOM;
+    let bottom = MARGIN_TOP + plot_height;
+    #[allow(clippy::cast_precision_loss)]
+    let x =
+        |index: usize| MARGIN_LEFT + index as f64 / (totals.len() - 1).max(1) as f64 * plot_width;
+    let y = |value: f64| bottom - value / max * plot_height;
+
+    let mut svg = svg_open();
+
+    for tick in 0..=4 {
+        let value = max * f64::from(tick) / 4.0;
+        let _ = write!(
+            svg,
+            "<line x1=\"{MARGIN_LEFT}\" x2=\"{}\" y1=\"{y:.1}\" y2=\"{y:.1}\" stroke=\"#ddd\"/><text x=\"{}\" y=\"{:.1}\" text-anchor=\"end\">{value:.1}ms</text>",
+            WIDTH - MARGIN_RIGHT,
+            MARGIN_LEFT - 6.0,
+            y(value) + 4.0,
+            y = y(value),
+        );
+    }
+
+    let points: Vec<String> = totals
+        .iter()
+        .enumerate()
+        .map(|(index, (_, total))| format!("{:.1},{:.1}", x(index), y(*total)))
+        .collect();
+    let _ = write!(
+        svg,
+        "<polyline points=\"{}\" fill=\"none\" stroke=\"{}\" stroke-width=\"2\"/>",
+        points.join(" "),
+        COLORS[0]
+    );
+
+    for (index, (label, total)) in totals.iter().enumerate() {
+        let _ = write!(
+            svg,
+            "<circle cx=\"{:.1}\" cy=\"{:.1}\" r=\"4\" fill=\"{}\"><title>{}: {total:.2}ms</title></circle>",
+            x(index),
+            y(*total),
+            COLORS[0],
+            escape(label),
+        );
+    }
+
+    for (index, anchor) in [(0, "start"), (totals.len() - 1, "end")] {
+        let _ = write!(
+            svg,
+            "<text x=\"{:.1}\" y=\"{:.1}\" text-anchor=\"{anchor}\">{}</text>",
+            x(index),
+            bottom + 16.0,
+            escape(&totals[index].0),
+        );
+    }
+
+    svg.push_str("</svg>");
+    svg
+}
+
+fn svg_open() -> String {
+    format!(
+        "<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"{WIDTH}\" height=\"{HEIGHT}\" viewBox=\"0 0 {WIDTH} {HEIGHT}\">"
+    )
+}
+
+/// Table with the timings and peak memory of every part.
+fn table(year: Year, timings: &Timings) -> String {
+    let mut html = String::from(
+        "<h2>Details</h2>\n<table>\n<tr><th>Day</th><th>Part 1</th><th>Part 2</th><th>Peak memory</th></tr>\n",
+    );
+
+    for timing in &timings.data {
+        let cell = |part: u8| {
+            timing
+                .killed(part)
+                .and_then(|status| status.killed_label())
+                .map(Into::into)
+                .or_else(|| timing.part(part).map(|p| format_nanos(p.nanos)))
+                .unwrap_or_else(|| "-".into())
+        };
+        let memory: Vec<String> = [1, 2]
+            .into_iter()
+            .filter_map(|part| timing.part(part)?.alloc)
+            .map(|alloc| format_bytes(alloc.peak_bytes))
+            .collect();
+
+        let _ = writeln!(
+            html,
+            "<tr><td><a href=\"{}\">Day {}</a></td><td>{}</td><td>{}</td><td>{}</td></tr>",
+            get_path_for_bin(year, timing.day),
+            timing.day.into_inner(),
+            cell(1),
+            cell(2),
+            if memory.is_empty() {
+                "-".into()
+            } else {
+                memory.join(" / ")
+            }
+        );
+    }
+
+    html.push_str("</table>\n");
+    html
+}
+
+/// Escape text for use in HTML.
+fn escape(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use std::str::FromStr;
+
+    use super::{escape, render, totals_over_time};
+    use crate::template::alloc::AllocStats;
+    use crate::template::history::History;
+    use crate::template::timings::{PartTiming, Timing, Timings};
+    use crate::{day, year};
+
+    fn part(nanos: f64) -> Option<PartTiming> {
+        Some(PartTiming {
+            nanos,
+            samples: 10,
+            stats: None,
+            alloc: None,
+        })
+    }
+
+    fn get_mock_timings() -> Timings {
+        Timings {
+            data: vec![
+                Timing {
+                    day: day!(1),
+                    part_1: part(1_000.0),
+                    part_2: part(2_000_000.0),
+                    part_1_killed: None,
+                    part_2_killed: None,
+                },
+                Timing {
+                    day: day!(2),
+                    part_1: part(50_000.0),
+                    part_2: None,
+                    part_1_killed: None,
+                    part_2_killed: None,
+                },
+            ],
+        }
+    }
+
+    #[test]
+    fn renders_bars_per_part() {
+        let html = render(year!(2024), &get_mock_timings(), &History::default());
+        assert!(html.starts_with("<!DOCTYPE html>"));
+        assert_eq!(html.matches("<rect").count(), 3);
+        assert!(html.contains("<title>Day 01 part 2: 2.0ms</title>"));
+        assert!(html.contains("<a href=\"./src/bin/2024-01.rs\">Day 1</a>"));
+        assert!(!html.contains("Peak memory per day"));
+        assert!(!html.contains("Total over time"));
+    }
+
+    #[test]
+    fn renders_memory_if_recorded() {
+        let mut timings = get_mock_timings();
+        timings.data[0].part_1.as_mut().unwrap().alloc = Some(AllocStats {
+            peak_bytes: 2048,
+            total_bytes: 4096,
+            allocations: 2,
+        });
+        let html = render(year!(2024), &timings, &History::default());
+        assert!(html.contains("Peak memory per day"));
+        assert!(html.contains("<title>Day 01 part 1: 2.0 KiB</title>"));
+    }
+
+    #[test]
+    fn carries_over_totals_between_runs() {
+        let history = History::from_str(
+            [
+                r#"{"timestamp": 0, "profile": "release", "commit": "aaa", "timings": {"version": 2, "data": [{"day": "01", "part_1": {"nanos": 1000000, "samples": 1}, "part_2": null}]}}"#,
+                r#"{"timestamp": 86400, "profile": "release", "commit": "bbb", "timings": {"version": 2, "data": [{"day": "02", "part_1": {"nanos": 2000000, "samples": 1}, "part_2": null}]}}"#,
+            ]
+            .join("\n")
+            .as_str(),
+        )
+        .unwrap();
+
+        let totals = totals_over_time(&history);
+        assert_eq!(totals[0], ("1970-01-01 00:00 UTC (aaa)".into(), 1.0));
+        assert_eq!(totals[1], ("1970-01-02 00:00 UTC (bbb)".into(), 3.0));
+
+        let html = render(year!(2024), &get_mock_timings(), &history);
+        assert!(html.contains("Total over time"));
+        assert_eq!(html.matches("<circle").count(), 2);
+    }
+
+    #[test]
+    fn escapes_html() {
+        assert_eq!(escape("<a & \"b\">"), "&lt;a &amp; &quot;b&quot;&gt;");
+    }
+}
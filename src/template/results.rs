@@ -0,0 +1,340 @@
+/// Machine-readable record of a single solution part.
+///
+/// Solution binaries append one JSON line per part to the file named by [`RESULTS_FILE_ENV`].
+/// `run_multi` reads these records back instead of scraping the human-readable output.
+use std::{
+    collections::HashMap,
+    env,
+    fs::{self, OpenOptions},
+    io::{self, Write},
+    path::Path,
+    str::FromStr,
+};
+
+use tinyjson::JsonValue;
+
+use crate::template::alloc::AllocStats;
+use crate::template::stats::BenchStats;
+use crate::template::Day;
+
+/// Name of the environment variable that points child processes at the results file.
+pub const RESULTS_FILE_ENV: &str = "AOC_RESULTS_FILE";
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum PartStatus {
+    /// The part returned an answer.
+    Solved,
+    /// The part returned `None`.
+    Unsolved,
+    /// The part returned an error, e.g. because its input could not be parsed.
+    Failed,
+    /// The day was killed when it ran out of time before the part finished.
+    Timeout,
+    /// The day was killed when it ran out of memory before the part finished.
+    OutOfMemory,
+}
+
+impl PartStatus {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            PartStatus::Solved => "solved",
+            PartStatus::Unsolved => "unsolved",
+            PartStatus::Failed => "failed",
+            PartStatus::Timeout => "timeout",
+            PartStatus::OutOfMemory => "oom",
+        }
+    }
+
+    /// Label shown in place of the answer and timing of a killed part, e.g. `TIMEOUT`.
+    pub fn killed_label(self) -> Option<&'static str> {
+        match self {
+            PartStatus::Timeout => Some("TIMEOUT"),
+            PartStatus::OutOfMemory => Some("OOM"),
+            PartStatus::Solved | PartStatus::Unsolved | PartStatus::Failed => None,
+        }
+    }
+}
+
+impl FromStr for PartStatus {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "solved" => Ok(PartStatus::Solved),
+            "unsolved" => Ok(PartStatus::Unsolved),
+            "failed" => Ok(PartStatus::Failed),
+            "timeout" => Ok(PartStatus::Timeout),
+            "oom" => Ok(PartStatus::OutOfMemory),
+            x => Err(format!("unknown part status `{x}`.")),
+        }
+    }
+}
+
+/// Result of running one part of a solution.
+#[derive(Clone, Debug, PartialEq)]
+pub struct PartResult {
+    pub part: u8,
+    pub answer: Option<String>,
+    /// Execution time in nanoseconds (the mean when benched).
+    pub nanos: f64,
+    pub samples: u128,
+    /// Sample statistics, present when benched.
+    pub stats: Option<BenchStats>,
+    /// Allocations of a single run, present with the `alloc-stats` feature.
+    pub alloc: Option<AllocStats>,
+    pub status: PartStatus,
+    /// Report of the error of a failed part, with the day, part and input location.
+    pub error: Option<String>,
+}
+
+impl PartResult {
+    /// Record of a part that did not finish because its day was killed with `status`.
+    pub fn killed(part: u8, status: PartStatus) -> Self {
+        PartResult {
+            part,
+            answer: None,
+            nanos: 0.0,
+            samples: 0,
+            stats: None,
+            alloc: None,
+            status,
+            error: None,
+        }
+    }
+
+    /// Append this record to the results file if the parent process asked for one.
+    pub fn emit(&self) -> io::Result<()> {
+        match env::var_os(RESULTS_FILE_ENV) {
+            Some(path) => self.append_to(Path::new(&path)),
+            None => Ok(()),
+        }
+    }
+
+    fn append_to(&self, path: &Path) -> io::Result<()> {
+        let mut file = OpenOptions::new().create(true).append(true).open(path)?;
+        let line = JsonValue::from(self)
+            .stringify()
+            .map_err(io::Error::other)?;
+        writeln!(file, "{line}")
+    }
+}
+
+/// Results of all parts of a single day. Empty if the day was not run.
+#[derive(Clone, Debug)]
+pub struct DayResults {
+    pub day: Day,
+    pub parts: Vec<PartResult>,
+}
+
+impl DayResults {
+    pub fn part(&self, part: u8) -> Option<&PartResult> {
+        self.parts.iter().find(|r| r.part == part)
+    }
+}
+
+/// Read all records from a results file. A missing file yields no records.
+pub fn read_results(path: &Path) -> Result<Vec<PartResult>, String> {
+    let Ok(content) = fs::read_to_string(path) else {
+        return Ok(vec![]);
+    };
+    parse_results(&content)
+}
+
+/// Parse JSON-lines content into records, skipping blank lines.
+pub fn parse_results(content: &str) -> Result<Vec<PartResult>, String> {
+    content
+        .lines()
+        .filter(|l| !l.trim().is_empty())
+        .map(|l| {
+            let json = JsonValue::from_str(l).or(Err("result line is not valid JSON."))?;
+            PartResult::try_from(&json)
+        })
+        .collect()
+}
+
+/* -------------------------------------------------------------------------- */
+
+impl From<&PartResult> for JsonValue {
+    fn from(value: &PartResult) -> Self {
+        let mut map: HashMap<String, JsonValue> = HashMap::new();
+
+        map.insert("part".into(), JsonValue::Number(f64::from(value.part)));
+        map.insert(
+            "answer".into(),
+            match &value.answer {
+                Some(x) => JsonValue::String(x.clone()),
+                None => JsonValue::Null,
+            },
+        );
+        map.insert("nanos".into(), JsonValue::Number(value.nanos));
+        #[allow(clippy::cast_precision_loss)]
+        map.insert("samples".into(), JsonValue::Number(value.samples as f64));
+        map.insert(
+            "stats".into(),
+            match &value.stats {
+                Some(x) => JsonValue::from(x),
+                None => JsonValue::Null,
+            },
+        );
+        map.insert(
+            "alloc".into(),
+            match &value.alloc {
+                Some(x) => JsonValue::from(x),
+                None => JsonValue::Null,
+            },
+        );
+        map.insert(
+            "status".into(),
+            JsonValue::String(value.status.as_str().into()),
+        );
+        map.insert(
+            "error".into(),
+            match &value.error {
+                Some(x) => JsonValue::String(x.clone()),
+                None => JsonValue::Null,
+            },
+        );
+
+        JsonValue::Object(map)
+    }
+}
+
+impl TryFrom<&JsonValue> for PartResult {
+    type Error = String;
+
+    fn try_from(value: &JsonValue) -> Result<Self, Self::Error> {
+        let json = value
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or("Expected result to be a JSON object.")?;
+
+        let number = |key: &str| {
+            json.get(key)
+                .and_then(|v| v.get::<f64>().copied())
+                .ok_or(format!("Expected result.{key} to be a number."))
+        };
+
+        #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+        let part = number("part")? as u8;
+        let nanos = number("nanos")?;
+        #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+        let samples = number("samples")? as u128;
+
+        let answer = json
+            .get("answer")
+            .map(|v| if v.is_null() { None } else { v.get::<String>() })
+            .ok_or("Expected result.answer to be null or string.")?
+            .cloned();
+
+        let stats = match json.get("stats") {
+            Some(v) if !v.is_null() => Some(BenchStats::try_from(v)?),
+            _ => None,
+        };
+
+        let alloc = match json.get("alloc") {
+            Some(v) if !v.is_null() => Some(AllocStats::try_from(v)?),
+            _ => None,
+        };
+
+        let status = json
+            .get("status")
+            .and_then(|v| v.get::<String>())
+            .ok_or("Expected result.status to be a string.")?
+            .parse()?;
+
+        let error = json.get("error").and_then(|v| v.get::<String>()).cloned();
+
+        Ok(PartResult {
+            part,
+            answer,
+            nanos,
+            samples,
+            stats,
+            alloc,
+            status,
+            error,
+        })
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{parse_results, PartResult, PartStatus};
+    use crate::template::alloc::AllocStats;
+    use crate::template::stats::BenchStats;
+    use crate::template::Day;
+    use tinyjson::JsonValue;
+
+    #[test]
+    fn round_trips_records() {
+        let result = PartResult {
+            part: 2,
+            answer: Some("Part 1: (74.13ms @ 100 samples)".into()),
+            nanos: 74_130_000.0,
+            samples: 100,
+            stats: Some(BenchStats {
+                mean: 74_130_000.0,
+                median: 74_000_000.0,
+                min: 70_000_000.0,
+                max: 80_000_000.0,
+                stddev: 1_500_000.0,
+                p95: 79_000_000.0,
+                outliers: 2,
+            }),
+            alloc: Some(AllocStats {
+                peak_bytes: 2048,
+                total_bytes: 4096,
+                allocations: 3,
+            }),
+            status: PartStatus::Solved,
+            error: None,
+        };
+        let line = JsonValue::from(&result).stringify().unwrap();
+        assert_eq!(parse_results(&line).unwrap(), vec![result]);
+    }
+
+    #[test]
+    fn parses_multiple_lines() {
+        let content = [
+            r#"{"part":1,"answer":"42","nanos":1500,"samples":10,"status":"solved"}"#,
+            "",
+            r#"{"part":2,"answer":null,"nanos":10,"samples":1,"status":"unsolved"}"#,
+        ]
+        .join("\n");
+        let results = parse_results(&content).unwrap();
+        assert_eq!(results.len(), 2);
+        assert_eq!(results[0].answer, Some("42".into()));
+        assert_eq!(results[0].nanos, 1500.0);
+        assert_eq!(results[1].answer, None);
+        assert_eq!(results[1].status, PartStatus::Unsolved);
+    }
+
+    #[test]
+    fn round_trips_killed_records() {
+        let result = PartResult::killed(2, PartStatus::OutOfMemory);
+        let line = JsonValue::from(&result).stringify().unwrap();
+        assert!(line.contains(r#""status":"oom""#));
+        assert_eq!(parse_results(&line).unwrap(), vec![result]);
+        assert_eq!(PartStatus::Timeout.killed_label(), Some("TIMEOUT"));
+        assert_eq!(PartStatus::Solved.killed_label(), None);
+    }
+
+    #[test]
+    fn round_trips_failed_records() {
+        let result = PartResult {
+            error: Some("Day 06, part 1: 06.txt:2:2: parse error: unexpected \"x\"".into()),
+            ..PartResult::killed(1, PartStatus::Failed)
+        };
+        let line = JsonValue::from(&result).stringify().unwrap();
+        assert!(line.contains(r#""status":"failed""#));
+        assert_eq!(parse_results(&line).unwrap(), vec![result]);
+        assert_eq!(PartStatus::Failed.killed_label(), None);
+    }
+
+    #[test]
+    #[should_panic]
+    fn panics_for_malformed_records() {
+        parse_results(r#"{"part":1}"#).unwrap();
+    }
+}
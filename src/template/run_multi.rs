@@ -1,56 +1,267 @@
-use std::{collections::HashSet, io};
+use std::{
+    collections::{BTreeMap, HashSet},
+    fs, io,
+    panic::{self, AssertUnwindSafe},
+    process,
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        mpsc,
+    },
+    thread,
+};
 
-use crate::template::{Day, ANSI_BOLD, ANSI_ITALIC, ANSI_RESET};
+use crate::template::{Day, Year, ANSI_BOLD, ANSI_ITALIC, ANSI_RESET};
 
 use super::{
     all_days,
-    timings::{Timing, Timings},
+    inputs::Inputs,
+    limits::{Limits, LimitsConfig},
+    registry::{self, Solution},
+    results::{DayResults, PartResult},
+    runner::RunOptions,
+    stats::BenchConfig,
+    timings::Timings,
 };
 
-pub fn run_multi(days_to_run: &HashSet<Day>, is_release: bool, is_timed: bool) -> Option<Timings> {
-    let mut timings: Vec<Timing> = Vec::with_capacity(days_to_run.len());
-
-    let mut need_space = false;
-
+/// Run a set of days of a year and print their results.
+///
+/// Days found in `solutions` run in-process. Other days run through their own binary via
+/// `cargo run`. Days are benched if a `bench` config is passed.
+///
+/// With `isolated`, more than one job, with limits, or with `is_release` from a debug build, the
+/// solutions are built once and every day runs its executable on one of `jobs` workers. A failed build exits the process. Their output is buffered, so it is
+/// still printed in day order. A day that exceeds its limits is killed and its unfinished parts are reported as such.
+/// Parts that returned an error are listed with their error after all days ran.
+///
+/// Days read the input selected by `inputs`, see [`Inputs`].
+#[allow(clippy::too_many_arguments)]
+pub fn run_multi(
+    solutions: &[Solution],
+    year: Year,
+    days_to_run: &HashSet<Day>,
+    is_release: bool,
+    bench: Option<&BenchConfig>,
+    isolated: bool,
+    jobs: usize,
+    limits: Limits,
+    inputs: Inputs,
+) -> Vec<DayResults> {
     // NOTE: use non-duplicate, sorted day values.
-    all_days()
+    let days: Vec<Day> = all_days(year)
         .filter(|day| days_to_run.contains(day))
-        .for_each(|day| {
-            if need_space {
-                println!();
+        .collect();
+
+    let limits_config = match LimitsConfig::read_from_file(year) {
+        Ok(config) => config,
+        Err(e) => {
+            eprintln!("Failed to read limits: {e}");
+            process::exit(1);
+        }
+    };
+
+    // NOTE: in-process days run with the profile of this binary, so a debug binary has to build
+    // the solutions for a release run.
+    let needs_release = is_release && cfg!(debug_assertions);
+    let all_results = if isolated || jobs > 1 || needs_release || !limits_config.is_empty(limits) {
+        let limits: Vec<Limits> = days
+            .iter()
+            .map(|&day| limits_config.for_day(day, limits))
+            .collect();
+        let build = || child_commands::build(is_release);
+        match run_executables(year, &days, &limits, is_release, bench, jobs, inputs, build) {
+            Ok(results) => results,
+            Err(e) => {
+                eprintln!("Failed to build solutions: {e:?}");
+                process::exit(1);
             }
-            need_space = true;
+        }
+    } else {
+        days.iter()
+            .enumerate()
+            .map(|(index, &day)| {
+                print_header(day, index > 0);
+
+                let results = match registry::find(solutions, year, day) {
+                    Some(solution) => run_in_process(solution, bench, inputs),
+                    None => {
+                        child_commands::run_solution(year, day, bench, is_release, inputs).unwrap()
+                    }
+                };
 
-            println!("{ANSI_BOLD}Day {day}{ANSI_RESET}");
-            println!("------");
+                print_footer(&results);
+                DayResults {
+                    day,
+                    parts: results,
+                }
+            })
+            .collect()
+    };
 
-            let output = child_commands::run_solution(day, is_timed, is_release).unwrap();
+    let killed: Vec<String> = all_results
+        .iter()
+        .filter_map(|r| {
+            let label = r.parts.iter().find_map(|p| p.status.killed_label())?;
+            Some(format!("{} ({label})", r.day))
+        })
+        .collect();
 
-            if output.is_empty() {
-                println!("Not solved.");
-            } else {
-                let val = child_commands::parse_exec_time(&output, day);
-                timings.push(val);
-            }
-        });
+    if !killed.is_empty() {
+        println!("\n{ANSI_BOLD}Killed:{ANSI_RESET} {}", killed.join(", "));
+    }
 
-    if is_timed {
-        let timings = Timings { data: timings };
-        let total_millis = timings.total_millis();
+    let failed: Vec<&str> = all_results
+        .iter()
+        .flat_map(|r| &r.parts)
+        .filter_map(|p| p.error.as_deref())
+        .collect();
+
+    if !failed.is_empty() {
+        println!("\n{ANSI_BOLD}Failed:{ANSI_RESET}");
+        for error in failed {
+            println!("{error}");
+        }
+    }
+
+    if bench.is_some() {
+        let total_millis = Timings::from_results(&all_results).total_millis();
         println!(
             "\n{ANSI_BOLD}Total (Run):{ANSI_RESET} {ANSI_ITALIC}{total_millis:.2}ms{ANSI_RESET}"
         );
-        Some(timings)
-    } else {
-        None
     }
+
+    all_results
+}
+
+fn print_header(day: Day, need_space: bool) {
+    if need_space {
+        println!();
+    }
+    println!("{ANSI_BOLD}Day {day}{ANSI_RESET}");
+    println!("------");
+}
+
+fn print_footer(results: &[PartResult]) {
+    if results.is_empty() {
+        println!("Not solved.");
+    }
+
+    for result in results {
+        if let Some(label) = result.status.killed_label() {
+            println!("Part {}: {ANSI_BOLD}{label}{ANSI_RESET}", result.part);
+        }
+    }
+}
+
+/// Run the executables of `days` on `jobs` workers, each with its `limits`, once `build` built
+/// them. Each day is printed as soon as it and all days before it are done.
+#[allow(clippy::too_many_arguments)]
+fn run_executables(
+    year: Year,
+    days: &[Day],
+    limits: &[Limits],
+    is_release: bool,
+    bench: Option<&BenchConfig>,
+    jobs: usize,
+    inputs: Inputs,
+    build: impl FnOnce() -> Result<(), Error>,
+) -> Result<Vec<DayResults>, Error> {
+    build()?;
+
+    let next = AtomicUsize::new(0);
+    let (sender, receiver) = mpsc::channel();
+
+    Ok(thread::scope(|scope| {
+        for _ in 0..jobs.min(days.len()) {
+            let sender = sender.clone();
+            let next = &next;
+            scope.spawn(move || loop {
+                let index = next.fetch_add(1, Ordering::Relaxed);
+                let Some(&day) = days.get(index) else {
+                    break;
+                };
+                let output = child_commands::run_executable(
+                    year,
+                    day,
+                    bench,
+                    is_release,
+                    limits[index],
+                    inputs,
+                );
+                if sender.send((index, output)).is_err() {
+                    break;
+                }
+            });
+        }
+        drop(sender);
+
+        let mut done = BTreeMap::new();
+        let mut all_results: Vec<DayResults> = Vec::with_capacity(days.len());
+
+        for (index, output) in receiver {
+            done.insert(index, output);
+
+            while let Some(output) = done.remove(&all_results.len()) {
+                let day = days[all_results.len()];
+                print_header(day, !all_results.is_empty());
+
+                let results = match output {
+                    Ok(output) => {
+                        print!("{}", output.stdout);
+                        eprint!("{}", output.stderr);
+                        output.results
+                    }
+                    Err(e) => {
+                        eprintln!("Failed to run day {day}: {e:?}");
+                        vec![]
+                    }
+                };
+
+                print_footer(&results);
+                all_results.push(DayResults {
+                    day,
+                    parts: results,
+                });
+            }
+        }
+
+        all_results
+    }))
+}
+
+/// Run a registered solution in the current process.
+/// A panicking solution is reported and yields no results.
+fn run_in_process(
+    solution: &Solution,
+    bench: Option<&BenchConfig>,
+    inputs: Inputs,
+) -> Vec<PartResult> {
+    let path = inputs.path(solution.year, solution.day);
+    let Ok(input) = fs::read_to_string(&path) else {
+        println!("No input file.");
+        return vec![];
+    };
+
+    let options = RunOptions {
+        timed: bench.is_some(),
+        bench: bench.copied().unwrap_or_default(),
+        // NOTE: only names the input in error reports, it is already read.
+        input: Some(path.to_string_lossy().into_owned()),
+        ..RunOptions::default()
+    };
+
+    panic::catch_unwind(AssertUnwindSafe(|| solution.run(&input, &options))).unwrap_or_else(|_| {
+        eprintln!("Day {} panicked.", solution.day);
+        vec![]
+    })
 }
 
 #[allow(dead_code)]
 #[derive(Debug)]
 pub enum Error {
     BrokenPipe,
+    Build,
     IO(io::Error),
+    Results(String),
 }
 
 impl From<std::io::Error> for Error {
@@ -60,40 +271,256 @@ impl From<std::io::Error> for Error {
 }
 
 #[must_use]
-pub fn get_path_for_bin(day: Day) -> String {
-    format!("./src/bin/{day}.rs")
+pub fn get_path_for_bin(year: Year, day: Day) -> String {
+    format!("./src/bin/{year}-{day}.rs")
 }
 
 /// All solutions live in isolated binaries.
-/// This module encapsulates interaction with these binaries, both invoking them as well as parsing the timing output.
+/// This module encapsulates interaction with these binaries, both invoking them as well as collecting the
+/// result records they write to [`RESULTS_FILE_ENV`].
 pub mod child_commands {
     use super::{get_path_for_bin, Error};
-    use crate::template::Day;
+    use crate::template::alloc;
+    use crate::template::inputs::Inputs;
+    use crate::template::limits::Limits;
+    use crate::template::results::{read_results, PartResult, PartStatus, RESULTS_FILE_ENV};
+    use crate::template::stats::BenchConfig;
+    use crate::template::{Day, Year};
     use std::{
-        io::{BufRead, BufReader},
-        path::Path,
-        process::{Command, Stdio},
-        thread,
+        env, fs,
+        io::{self, BufRead, BufReader, Read},
+        path::{Path, PathBuf},
+        process::{self, Child, Command, ExitStatus, Stdio},
+        thread::{self, JoinHandle},
+        time::{Duration, Instant},
     };
 
-    /// Run the solution bin for a given day
-    pub fn run_solution(day: Day, is_timed: bool, is_release: bool) -> Result<Vec<String>, Error> {
+    /// Output of a solution executable, buffered so that it can be printed in order.
+    pub struct Output {
+        pub stdout: String,
+        pub stderr: String,
+        pub results: Vec<PartResult>,
+    }
+
+    fn get_path_for_results(year: Year, day: Day) -> PathBuf {
+        env::temp_dir().join(format!("aoc-results-{}-{year}-{day}.jsonl", process::id()))
+    }
+
+    #[must_use]
+    pub fn get_path_for_executable(year: Year, day: Day, is_release: bool) -> PathBuf {
+        let target_dir = env::var_os("CARGO_TARGET_DIR").unwrap_or_else(|| "target".into());
+        let profile = if is_release { "release" } else { "debug" };
+        Path::new(&target_dir)
+            .join(profile)
+            .join(format!("{year}-{day}{}", env::consts::EXE_SUFFIX))
+    }
+
+    /// Arguments passed on to the solution bin of `day`.
+    fn solution_args(
+        year: Year,
+        day: Day,
+        bench: Option<&BenchConfig>,
+        inputs: Inputs,
+    ) -> Vec<String> {
+        let mut args = vec![];
+        if let Some(bench) = bench {
+            // mirror `--time` flag and bench config to child invocations.
+            args.push("--time".into());
+            args.extend(bench.to_args());
+        }
+        if inputs != Inputs::Default {
+            args.push("--input".into());
+            args.push(inputs.path(year, day).to_string_lossy().into_owned());
+        }
+        args
+    }
+
+    /// Build all solution bins, so that their executables can be run directly.
+    pub fn build(is_release: bool) -> Result<(), Error> {
+        let mut args: Vec<String> = vec!["build".into(), "--quiet".into(), "--bins".into()];
+        if is_release {
+            args.push("--release".into());
+        }
+        args.extend(alloc::cargo_args());
+
+        if Command::new("cargo").args(&args).status()?.success() {
+            Ok(())
+        } else {
+            Err(Error::Build)
+        }
+    }
+
+    /// Run the built executable of a given day, buffering its output.
+    /// Expects the solutions to be built with [`build`] first.
+    ///
+    /// The executable is killed when it exceeds the timeout of `limits`. Parts that had not
+    /// finished by then, or when the executable ran out of memory, are reported as killed.
+    pub fn run_executable(
+        year: Year,
+        day: Day,
+        bench: Option<&BenchConfig>,
+        is_release: bool,
+        limits: Limits,
+        inputs: Inputs,
+    ) -> Result<Output, Error> {
+        // skip days that have not been scaffolded yet.
+        if !Path::new(&get_path_for_bin(year, day)).exists() {
+            return Ok(Output {
+                stdout: String::new(),
+                stderr: String::new(),
+                results: vec![],
+            });
+        }
+
+        let results_path = get_path_for_results(year, day);
+        let _ = fs::remove_file(&results_path);
+
+        let mut command = Command::new(get_path_for_executable(year, day, is_release));
+        command
+            .args(solution_args(year, day, bench, inputs))
+            .env(RESULTS_FILE_ENV, &results_path)
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped());
+
+        if let Some(memory_mb) = limits.memory_mb {
+            limit_memory(&mut command, memory_mb * 1024 * 1024);
+        }
+
+        let mut child = command.spawn()?;
+
+        // read both pipes while waiting, so that a chatty child does not block on a full pipe.
+        let stdout = read_to_end(child.stdout.take().ok_or(Error::BrokenPipe)?);
+        let stderr = read_to_end(child.stderr.take().ok_or(Error::BrokenPipe)?);
+
+        let status = match limits.timeout {
+            Some(timeout) => wait_with_timeout(&mut child, timeout)?,
+            None => Some(child.wait()?),
+        };
+
+        let stdout = stdout.join().map_err(|_| Error::BrokenPipe)?;
+        let stderr = stderr.join().map_err(|_| Error::BrokenPipe)?;
+
+        let killed = match status {
+            None => Some(PartStatus::Timeout),
+            Some(status) if is_out_of_memory(status, &stderr, limits.memory_mb.is_some()) => {
+                Some(PartStatus::OutOfMemory)
+            }
+            Some(_) => None,
+        };
+
+        let mut results = read_results(&results_path).map_err(Error::Results)?;
+        let _ = fs::remove_file(&results_path);
+
+        if let Some(killed) = killed {
+            for part in 1..=year.parts(day) {
+                if !results.iter().any(|r| r.part == part) {
+                    results.push(PartResult::killed(part, killed));
+                }
+            }
+        }
+
+        Ok(Output {
+            stdout,
+            stderr,
+            results,
+        })
+    }
+
+    fn read_to_end(mut pipe: impl Read + Send + 'static) -> JoinHandle<String> {
+        thread::spawn(move || {
+            let mut buffer = vec![];
+            let _ = pipe.read_to_end(&mut buffer);
+            String::from_utf8_lossy(&buffer).into_owned()
+        })
+    }
+
+    /// Wait for `child` to exit. Returns `None` if it had to be killed after `timeout`.
+    fn wait_with_timeout(child: &mut Child, timeout: Duration) -> io::Result<Option<ExitStatus>> {
+        let start = Instant::now();
+        loop {
+            if let Some(status) = child.try_wait()? {
+                return Ok(Some(status));
+            }
+            if start.elapsed() >= timeout {
+                child.kill()?;
+                child.wait()?;
+                return Ok(None);
+            }
+            thread::sleep(Duration::from_millis(10));
+        }
+    }
+
+    /// Whether the child failed because an allocation was refused, or it was killed by the OOM killer.
+    /// A `SIGKILL` only counts when the child ran with a memory limit, as anything can send one.
+    fn is_out_of_memory(status: ExitStatus, stderr: &str, memory_limited: bool) -> bool {
+        #[cfg(unix)]
+        {
+            use std::os::unix::process::ExitStatusExt;
+            if memory_limited && status.signal() == Some(libc::SIGKILL) {
+                return true;
+            }
+        }
+        !status.success() && stderr.contains("memory allocation of")
+    }
+
+    /// Limit the address space of the child to `bytes` with `RLIMIT_AS`.
+    #[cfg(target_os = "linux")]
+    fn limit_memory(command: &mut Command, bytes: u64) {
+        use std::os::unix::process::CommandExt;
+
+        let limit = libc::rlimit {
+            rlim_cur: bytes,
+            rlim_max: bytes,
+        };
+
+        // SAFETY: `setrlimit` is async-signal-safe, so it may run between `fork` and `exec`.
+        unsafe {
+            command.pre_exec(move || {
+                if libc::setrlimit(libc::RLIMIT_AS, &limit) == 0 {
+                    Ok(())
+                } else {
+                    Err(io::Error::last_os_error())
+                }
+            });
+        }
+    }
+
+    /// Memory limits rely on `RLIMIT_AS`, which is only enforced on Linux.
+    #[cfg(not(target_os = "linux"))]
+    fn limit_memory(_command: &mut Command, _bytes: u64) {}
+
+    /// Run the solution bin for a given day and return the result records of its parts.
+    pub fn run_solution(
+        year: Year,
+        day: Day,
+        bench: Option<&BenchConfig>,
+        is_release: bool,
+        inputs: Inputs,
+    ) -> Result<Vec<PartResult>, Error> {
         // skip command invocation for days that have not been scaffolded yet.
-        if !Path::new(&get_path_for_bin(day)).exists() {
+        if !Path::new(&get_path_for_bin(year, day)).exists() {
             return Ok(vec![]);
         }
 
-        let day_padded = day.to_string();
-        let mut args = vec!["run", "--quiet", "--bin", &day_padded];
+        let results_path = get_path_for_results(year, day);
+        let _ = fs::remove_file(&results_path);
+
+        let mut args: Vec<String> = vec![
+            "run".into(),
+            "--quiet".into(),
+            "--bin".into(),
+            format!("{year}-{day}"),
+        ];
 
         if is_release {
-            args.push("--release");
+            args.push("--release".into());
         }
+        args.extend(alloc::cargo_args());
 
-        if is_timed {
-            // mirror `--time` flag to child invocations.
-            args.push("--");
-            args.push("--time");
+        let solution_args = solution_args(year, day, bench, inputs);
+        if !solution_args.is_empty() {
+            args.push("--".into());
+            args.extend(solution_args);
         }
 
         // spawn child command with piped stdout/stderr.
@@ -101,6 +528,7 @@ pub mod child_commands {
 
         let mut cmd = Command::new("cargo")
             .args(&args)
+            .env(RESULTS_FILE_ENV, &results_path)
             .stdout(Stdio::piped())
             .stderr(Stdio::piped())
             .spawn()?;
@@ -108,8 +536,6 @@ pub mod child_commands {
         let stdout = BufReader::new(cmd.stdout.take().ok_or(super::Error::BrokenPipe)?);
         let stderr = BufReader::new(cmd.stderr.take().ok_or(super::Error::BrokenPipe)?);
 
-        let mut output = vec![];
-
         let thread = thread::spawn(move || {
             stderr.lines().for_each(|line| {
                 eprintln!("{}", line.unwrap());
@@ -117,141 +543,66 @@ pub mod child_commands {
         });
 
         for line in stdout.lines() {
-            let line = line.unwrap();
-            println!("{line}");
-            output.push(line);
+            println!("{}", line.unwrap());
         }
 
         thread.join().unwrap();
         cmd.wait()?;
 
-        Ok(output)
-    }
-
-    pub fn parse_exec_time(output: &[String], day: Day) -> super::Timing {
-        let mut timings = super::Timing {
-            day,
-            part_1: None,
-            part_2: None,
-            total_nanos: 0_f64,
-        };
-
-        output
-            .iter()
-            .filter_map(|l| {
-                if !l.contains(" samples)") {
-                    return None;
-                }
+        let results = read_results(&results_path).map_err(Error::Results)?;
+        let _ = fs::remove_file(&results_path);
 
-                let Some((timing_str, nanos)) = parse_time(l) else {
-                    eprintln!("Could not parse timings from line: {l}");
-                    return None;
-                };
-
-                let part = l.split(':').next()?;
-                Some((part, timing_str, nanos))
-            })
-            .for_each(|(part, timing_str, nanos)| {
-                if part.contains("Part 1") {
-                    timings.part_1 = Some(timing_str.into());
-                } else if part.contains("Part 2") {
-                    timings.part_2 = Some(timing_str.into());
-                }
-
-                timings.total_nanos += nanos;
-            });
-
-        timings
-    }
-
-    fn parse_to_float(s: &str, postfix: &str) -> Option<f64> {
-        s.split(postfix).next()?.parse().ok()
+        Ok(results)
     }
 
-    fn parse_time(line: &str) -> Option<(&str, f64)> {
-        // for possible time formats, see: https://github.com/rust-lang/rust/blob/1.64.0/library/core/src/time.rs#L1176-L1200
-        let str_timing = line
-            .split(" samples)")
-            .next()?
-            .split('(')
-            .last()?
-            .split('@')
-            .next()?
-            .trim();
-
-        let parsed_timing = match str_timing {
-            s if s.contains("ns") => s.split("ns").next()?.parse::<f64>().ok(),
-            s if s.contains("µs") => parse_to_float(s, "µs").map(|x| x * 1000_f64),
-            s if s.contains("ms") => parse_to_float(s, "ms").map(|x| x * 1_000_000_f64),
-            s => parse_to_float(s, "s").map(|x| x * 1_000_000_000_f64),
-        }?;
-
-        Some((str_timing, parsed_timing))
-    }
-
-    /// copied from: https://github.com/rust-lang/rust/blob/1.64.0/library/std/src/macros.rs#L328-L333
-    #[cfg(feature = "test_lib")]
-    macro_rules! assert_approx_eq {
-        ($a:expr, $b:expr) => {{
-            let (a, b) = (&$a, &$b);
-            assert!(
-                (*a - *b).abs() < 1.0e-6,
-                "{} is not approximately equal to {}",
-                *a,
-                *b
-            );
-        }};
-    }
+    /* -------------------------------------------------------------------------- */
 
-    #[cfg(feature = "test_lib")]
+    #[cfg(all(feature = "test_lib", unix))]
     mod tests {
-        use super::parse_exec_time;
+        use std::os::unix::process::ExitStatusExt;
+        use std::process::ExitStatus;
 
-        use crate::day;
+        use super::is_out_of_memory;
 
         #[test]
-        fn parses_execution_times() {
-            let res = parse_exec_time(
-                &[
-                    "Part 1: 0 (74.13ns @ 100000 samples)".into(),
-                    "Part 2: 10 (74.13ms @ 99999 samples)".into(),
-                    "".into(),
-                ],
-                day!(1),
-            );
-            assert_approx_eq!(res.total_nanos, 74130074.13_f64);
-            assert_eq!(res.part_1.unwrap(), "74.13ns");
-            assert_eq!(res.part_2.unwrap(), "74.13ms");
+        fn counts_sigkill_as_oom_only_with_memory_limit() {
+            let killed = ExitStatus::from_raw(libc::SIGKILL);
+            assert!(is_out_of_memory(killed, "", true));
+            assert!(!is_out_of_memory(killed, "", false));
         }
 
         #[test]
-        fn parses_with_patterns_in_input() {
-            let res = parse_exec_time(
-                &[
-                    "Part 1: @ @ @ ( ) ms (2s @ 5 samples)".into(),
-                    "Part 2: 10s (100ms @ 1 samples)".into(),
-                    "".into(),
-                ],
-                day!(1),
-            );
-            assert_approx_eq!(res.total_nanos, 2100000000_f64);
-            assert_eq!(res.part_1.unwrap(), "2s");
-            assert_eq!(res.part_2.unwrap(), "100ms");
+        fn detects_refused_allocations() {
+            let aborted = ExitStatus::from_raw(libc::SIGABRT);
+            let stderr = "memory allocation of 1048576 bytes failed\n";
+            assert!(is_out_of_memory(aborted, stderr, true));
+            assert!(is_out_of_memory(aborted, stderr, false));
+            assert!(!is_out_of_memory(aborted, "thread 'main' panicked", false));
+            assert!(!is_out_of_memory(ExitStatus::from_raw(0), stderr, false));
         }
+    }
+}
 
-        #[test]
-        fn parses_missing_parts() {
-            let res = parse_exec_time(
-                &[
-                    "Part 1: ✖        ".into(),
-                    "Part 2: ✖        ".into(),
-                    "".into(),
-                ],
-                day!(1),
-            );
-            assert_approx_eq!(res.total_nanos, 0_f64);
-            assert_eq!(res.part_1.is_none(), true);
-            assert_eq!(res.part_2.is_none(), true);
-        }
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{run_executables, Error};
+    use crate::template::{inputs::Inputs, limits::Limits};
+    use crate::{day, year};
+
+    #[test]
+    fn fails_when_the_build_fails() {
+        let result = run_executables(
+            year!(2024),
+            &[day!(1)],
+            &[Limits::default()],
+            false,
+            None,
+            2,
+            Inputs::Default,
+            || Err(Error::Build),
+        );
+        assert!(matches!(result, Err(Error::Build)));
     }
 }
@@ -2,64 +2,227 @@
 use std::fmt::Display;
 use std::hint::black_box;
 use std::io::{stdout, Write};
-use std::process::Output;
 use std::time::{Duration, Instant};
-use std::{cmp, env, process};
+use std::{env, process};
 
+use crate::error::AocError;
+use crate::template::alloc::{self, AllocStats};
+use crate::template::answers::Answers;
+use crate::template::aoc_client::SubmitOutcome;
+use crate::template::attempts::{Attempt, Attempts};
+use crate::template::inputs::Inputs;
+use crate::template::results::{PartResult, PartStatus};
+use crate::template::stats::{BenchConfig, BenchStats};
 use crate::template::ANSI_BOLD;
-use crate::template::{aoc_cli, Day, ANSI_ITALIC, ANSI_RESET};
+use crate::template::{aoc_client, Day, Year, ANSI_ITALIC, ANSI_RESET};
 
-pub fn run_part<I: Clone, T: Display>(func: impl Fn(I) -> Option<T>, input: I, day: Day, part: u8) {
+/// What a solution part returns: `Option<T>`, or `Result<T, E>` to explain why there is no
+/// answer. The error is reported by the runner, with the location in the input for parse errors.
+pub trait PartOutput {
+    type Answer: Display;
+
+    /// The answer of the part, `Ok(None)` if it is unsolved.
+    fn into_outcome(self) -> Result<Option<Self::Answer>, AocError>;
+}
+
+impl<T: Display> PartOutput for Option<T> {
+    type Answer = T;
+
+    fn into_outcome(self) -> Result<Option<T>, AocError> {
+        Ok(self)
+    }
+}
+
+impl<T: Display, E: Into<AocError>> PartOutput for Result<T, E> {
+    type Answer = T;
+
+    fn into_outcome(self) -> Result<Option<T>, AocError> {
+        self.map(Some).map_err(Into::into)
+    }
+}
+
+/// Options that control how a solution part is run.
+#[derive(Clone, Debug, Default)]
+pub struct RunOptions {
+    /// Bench the part instead of running it once.
+    pub timed: bool,
+    pub bench: BenchConfig,
+    /// Submit the answer of this part.
+    pub submit: Option<u8>,
+    /// Read the input from this file, or from stdin for `-`, instead of the input of the day.
+    pub input: Option<String>,
+}
+
+impl RunOptions {
+    /// Parse the options passed to a solution binary, e.g. `--time`, `--submit 1` or `--input -`.
+    pub fn from_args() -> Self {
+        let args: Vec<String> = env::args().collect();
+
+        let bench = BenchConfig::from_args(&args).unwrap_or_else(|e| {
+            eprintln!("Unexpected command-line input: {e}");
+            process::exit(1);
+        });
+
+        let submit = args.iter().position(|x| x == "--submit").map(|index| {
+            let Some(Ok(part)) = args.get(index + 1).map(|x| x.parse::<u8>()) else {
+                eprintln!("Unexpected command-line input. Format: cargo solve 1 --submit 1");
+                process::exit(1);
+            };
+            part
+        });
+
+        let input = args.iter().position(|x| x == "--input").map(|index| {
+            let Some(path) = args.get(index + 1) else {
+                eprintln!("Unexpected command-line input. Format: cargo solve 1 --input path");
+                process::exit(1);
+            };
+            path.clone()
+        });
+
+        RunOptions {
+            timed: args.iter().any(|x| x == "--time"),
+            bench,
+            submit,
+            input,
+        }
+    }
+}
+
+pub fn run_part<I: Clone, O: PartOutput>(
+    func: impl Fn(I) -> O,
+    input: I,
+    year: Year,
+    day: Day,
+    part: u8,
+) {
+    run_part_with(func, input, year, day, part, &RunOptions::from_args());
+}
+
+/// Run a solution part with explicit options and return its result record.
+/// An error of the part is printed with the day, the part and the location in the input.
+pub fn run_part_with<I: Clone, O: PartOutput>(
+    func: impl Fn(I) -> O,
+    input: I,
+    year: Year,
+    day: Day,
+    part: u8,
+    options: &RunOptions,
+) -> PartResult {
     let part_str = format!("Part {part}");
 
-    let (result, duration, samples) =
-        run_timed(func, input, |result| print_result(result, &part_str, ""));
+    let bench = options.timed.then_some(&options.bench);
+    let (outcome, duration, samples, stats, alloc) = run_timed(
+        |input| func(input).into_outcome(),
+        input,
+        bench,
+        |outcome| print_result(outcome.as_ref().map_err(|_| ""), &part_str, ""),
+    );
+
+    let (result, error) = match outcome {
+        Ok(result) => (result, None),
+        Err(e) => {
+            let source = match options.input.as_deref() {
+                Some("-") => "stdin".to_string(),
+                Some(path) => path.to_string(),
+                None => Inputs::Default
+                    .path(year, day)
+                    .to_string_lossy()
+                    .into_owned(),
+            };
+            (
+                None,
+                Some(format!("Day {day}, part {part}: {}", e.report(&source))),
+            )
+        }
+    };
 
-    print_result(&result, &part_str, &format_duration(&duration, samples));
+    print_result(
+        error.as_deref().map_or(Ok(&result), Err),
+        &part_str,
+        &format_duration(&duration, samples, stats.as_ref(), alloc.as_ref()),
+    );
+
+    let record = PartResult {
+        part,
+        answer: result.as_ref().map(ToString::to_string),
+        #[allow(clippy::cast_precision_loss)]
+        nanos: duration.as_nanos() as f64,
+        samples,
+        stats,
+        alloc,
+        status: match (&result, &error) {
+            (Some(_), _) => PartStatus::Solved,
+            (None, Some(_)) => PartStatus::Failed,
+            (None, None) => PartStatus::Unsolved,
+        },
+        error,
+    };
+
+    if let Err(e) = record.emit() {
+        eprintln!("Failed to write result record: {e}");
+    }
 
     if let Some(result) = result {
-        submit_result(result, day, part);
+        if options.submit == Some(part) {
+            let _ = submit_result(result, year, day, part);
+        }
     }
+
+    record
 }
 
 /// Run a solution part. The behavior differs depending on whether we are running a release or debug build:
 ///  1. in debug, the function is executed once.
-///  2. in release, the function is benched (approx. 1 second of execution time or 10 samples, whatever take longer.)
+///  2. in release, the function is benched according to `bench_config` (by default approx. 1 second of
+///     execution time or 10 samples, whatever take longer.)
+///
+/// Allocations are counted for the first run only.
 fn run_timed<I: Clone, T>(
     func: impl Fn(I) -> T,
     input: I,
+    bench_config: Option<&BenchConfig>,
     hook: impl Fn(&T),
-) -> (T, Duration, u128) {
+) -> (T, Duration, u128, Option<BenchStats>, Option<AllocStats>) {
     let timer = Instant::now();
-    let result = {
+    let (result, alloc) = {
         let input = input.clone();
 
         #[cfg(feature = "dhat-heap")]
         let _profiler = dhat::Profiler::new_heap();
 
-        func(input)
+        alloc::measure(|| func(input))
     };
     let base_time = timer.elapsed();
 
     hook(&result);
 
-    let run = if std::env::args().any(|x| x == "--time") {
-        bench(func, input, &base_time)
-    } else {
-        (base_time, 1)
-    };
-
-    (result, run.0, run.1)
+    match bench_config {
+        Some(config) => {
+            let (stats, samples) = bench(func, input, &base_time, config);
+            #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+            let mean = Duration::from_nanos(stats.mean as u64);
+            (result, mean, samples, Some(stats), alloc)
+        }
+        None => (result, base_time, 1, None, alloc),
+    }
 }
 
-fn bench<I: Clone, T>(func: impl Fn(I) -> T, input: I, base_time: &Duration) -> (Duration, u128) {
+fn bench<I: Clone, T>(
+    func: impl Fn(I) -> T,
+    input: I,
+    base_time: &Duration,
+    config: &BenchConfig,
+) -> (BenchStats, u128) {
     let mut stdout = stdout();
 
     print!(" > {ANSI_ITALIC}benching{ANSI_RESET}");
     let _ = stdout.flush();
 
-    let bench_iterations =
-        (Duration::from_secs(1).as_nanos() / cmp::max(base_time.as_nanos(), 10)).clamp(10, 10000);
+    for _ in 0..config.warmup {
+        black_box(func(black_box(input.clone())));
+    }
+
+    let bench_iterations = config.iterations(base_time);
 
     let mut timers: Vec<Duration> = vec![];
 
@@ -72,33 +235,37 @@ fn bench<I: Clone, T>(func: impl Fn(I) -> T, input: I, base_time: &Duration) ->
     }
 
     (
-        #[allow(clippy::cast_possible_truncation)]
-        Duration::from_nanos(average_duration(&timers) as u64),
+        BenchStats::from_samples(&timers).expect("at least one bench iteration"),
         bench_iterations,
     )
 }
 
-fn average_duration(numbers: &[Duration]) -> u128 {
-    numbers
-        .iter()
-        .map(std::time::Duration::as_nanos)
-        .sum::<u128>()
-        / numbers.len() as u128
-}
-
-fn format_duration(duration: &Duration, samples: u128) -> String {
-    if samples == 1 {
-        format!(" ({duration:.1?})")
-    } else {
-        format!(" ({duration:.1?} @ {samples} samples)")
+fn format_duration(
+    duration: &Duration,
+    samples: u128,
+    stats: Option<&BenchStats>,
+    alloc: Option<&AllocStats>,
+) -> String {
+    let alloc = alloc.map_or(String::new(), |alloc| format!(", {alloc}"));
+    match stats {
+        None => format!(" ({duration:.1?}{alloc})"),
+        Some(stats) => {
+            #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+            let [median, stddev] =
+                [stats.median, stats.stddev].map(|x| Duration::from_nanos(x as u64));
+            format!(
+                " ({duration:.1?} @ {samples} samples, median {median:.1?} ± {stddev:.1?}{alloc})"
+            )
+        }
     }
 }
 
-fn print_result<T: Display>(result: &Option<T>, part: &str, duration_str: &str) {
+/// Print the answer of a part, or the report of its error.
+fn print_result<T: Display>(result: Result<&Option<T>, &str>, part: &str, duration_str: &str) {
     let is_intermediate_result = duration_str.is_empty();
 
     match result {
-        Some(result) => {
+        Ok(Some(result)) => {
             if result.to_string().contains('\n') {
                 let str = format!("{part}: ▼ {duration_str}");
                 if is_intermediate_result {
@@ -118,7 +285,7 @@ fn print_result<T: Display>(result: &Option<T>, part: &str, duration_str: &str)
                 }
             }
         }
-        None => {
+        Ok(None) => {
             if is_intermediate_result {
                 print!("{part}: ✖");
             } else {
@@ -126,44 +293,84 @@ fn print_result<T: Display>(result: &Option<T>, part: &str, duration_str: &str)
                 println!("{part}: ✖             ");
             }
         }
+        Err(error) => {
+            if is_intermediate_result {
+                print!("{part}: ✖");
+            } else {
+                print!("\r");
+                println!("{part}: ✖ {error}");
+            }
+        }
     }
 }
 
-/// Parse the arguments passed to `solve` and try to submit one part of the solution if:
-///  1. we are in `--release` mode.
-///  2. aoc-cli is installed.
-fn submit_result<T: Display>(
-    result: T,
-    day: Day,
-    part: u8,
-) -> Option<Result<Output, aoc_cli::AocCommandError>> {
-    let args: Vec<String> = env::args().collect();
+/// Submit one part of the solution and record the attempt.
+/// A correct answer is also recorded in the known answers.
+///
+/// Answers that were already rejected, or that lie outside the bounds learned from earlier
+/// too-high and too-low hints, are not submitted.
+fn submit_result<T: Display>(result: T, year: Year, day: Day, part: u8) -> Option<SubmitOutcome> {
+    let answer = result.to_string();
 
-    if !args.contains(&"--submit".into()) {
+    if let Err(reason) = check_submission(Attempts::read_from_file(year), day, part, &answer) {
+        eprintln!("Not submitting result: {reason}");
         return None;
     }
 
-    if args.len() < 3 {
-        eprintln!("Unexpected command-line input. Format: cargo solve 1 --submit 1");
-        process::exit(1);
+    println!("Submitting result...");
+    let outcome = aoc_client::submit(year, day, part, &answer)
+        .inspect_err(|e| eprintln!("Failed to submit result: {e}"))
+        .ok()?;
+
+    if let Err(e) = Attempt::new(day, part, &answer, outcome.clone()).record(year) {
+        eprintln!("Failed to record attempt: {e}");
     }
 
-    let part_index = args.iter().position(|x| x == "--submit").unwrap() + 1;
+    if outcome == SubmitOutcome::Correct {
+        match Answers::record(year, day, part, &answer) {
+            Ok(()) => println!("Recorded answer in known answers."),
+            Err(e) => eprintln!("Failed to record answer: {e}"),
+        }
+    }
 
-    let Ok(part_submit) = args[part_index].parse::<u8>() else {
-        eprintln!("Unexpected command-line input. Format: cargo solve 1 --submit 1");
-        process::exit(1);
-    };
+    Some(outcome)
+}
 
-    if part_submit != part {
-        return None;
-    }
+/// Whether `answer` may be submitted, given the earlier `attempts` of the year.
+/// Earlier attempts that could not be read refuse the submission, as they may hold a rejection.
+fn check_submission(
+    attempts: Result<Attempts, String>,
+    day: Day,
+    part: u8,
+    answer: &str,
+) -> Result<(), String> {
+    let attempts = attempts.map_err(|e| format!("could not read earlier attempts: {e}"))?;
+    attempts
+        .check(day, part, answer)
+        .map_err(|refusal| refusal.to_string())
+}
 
-    if aoc_cli::check().is_err() {
-        eprintln!("command \"aoc\" not found or not callable. Try running \"cargo install aoc-cli\" to install it.");
-        process::exit(1);
-    }
+/* -------------------------------------------------------------------------- */
 
-    println!("Submitting result via aoc-cli...");
-    Some(aoc_cli::submit(day, part, &result.to_string()))
+#[cfg(feature = "test_lib")]
+mod tests {
+    use std::{env, fs, process};
+
+    use super::check_submission;
+    use crate::day;
+    use crate::template::attempts::Attempts;
+
+    #[test]
+    fn refuses_submission_for_malformed_attempts() {
+        let path = env::temp_dir().join(format!("aoc-attempts-{}.jsonl", process::id()));
+        fs::write(&path, "not json\n").unwrap();
+        let result = check_submission(Attempts::read_from_path(&path), day!(1), 1, "42");
+        fs::remove_file(&path).unwrap();
+        assert!(result
+            .unwrap_err()
+            .starts_with("could not read earlier attempts"));
+
+        let missing = Attempts::read_from_path(&path);
+        assert_eq!(check_submission(missing, day!(1), 1, "42"), Ok(()));
+    }
 }
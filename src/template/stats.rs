@@ -0,0 +1,302 @@
+/// Summary statistics for benchmark samples.
+use std::{collections::HashMap, fmt::Display, str::FromStr, time::Duration};
+
+use tinyjson::JsonValue;
+
+/// Controls how long and how often a solution part is benched.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct BenchConfig {
+    /// Approximate total time to spend on measured iterations.
+    pub budget: Duration,
+    pub min_samples: u128,
+    pub max_samples: u128,
+    /// Iterations run before measuring, to warm up caches and the branch predictor.
+    pub warmup: u128,
+}
+
+impl Default for BenchConfig {
+    fn default() -> Self {
+        BenchConfig {
+            budget: Duration::from_secs(1),
+            min_samples: 10,
+            max_samples: 10000,
+            warmup: 3,
+        }
+    }
+}
+
+impl BenchConfig {
+    /// Number of measured iterations for a part whose single run took `base_time`.
+    pub fn iterations(&self, base_time: &Duration) -> u128 {
+        let min_samples = self.min_samples.max(1);
+        (self.budget.as_nanos() / base_time.as_nanos().max(10))
+            .clamp(min_samples, self.max_samples.max(min_samples))
+    }
+
+    /// Command-line arguments that reproduce this config in a child process.
+    pub fn to_args(&self) -> Vec<String> {
+        vec![
+            "--budget".into(),
+            self.budget.as_millis().to_string(),
+            "--min-samples".into(),
+            self.min_samples.to_string(),
+            "--max-samples".into(),
+            self.max_samples.to_string(),
+            "--warmup".into(),
+            self.warmup.to_string(),
+        ]
+    }
+
+    /// Read the config from arguments like `--budget 500 --warmup 10`, defaulting missing values.
+    pub fn from_args(args: &[String]) -> Result<Self, String> {
+        let value = |flag: &str| -> Result<Option<u128>, String> {
+            match args.iter().position(|x| x == flag) {
+                Some(index) => args
+                    .get(index + 1)
+                    .and_then(|x| x.parse().ok())
+                    .map(Some)
+                    .ok_or(format!("expected a number after `{flag}`.")),
+                None => Ok(None),
+            }
+        };
+
+        let default = BenchConfig::default();
+
+        Ok(BenchConfig {
+            #[allow(clippy::cast_possible_truncation)]
+            budget: value("--budget")?
+                .map_or(default.budget, |ms| Duration::from_millis(ms as u64)),
+            min_samples: value("--min-samples")?.unwrap_or(default.min_samples),
+            max_samples: value("--max-samples")?.unwrap_or(default.max_samples),
+            warmup: value("--warmup")?.unwrap_or(default.warmup),
+        })
+    }
+}
+
+/// Statistics of a set of benchmark samples, in nanoseconds.
+/// Outliers are removed before computing everything but `outliers`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct BenchStats {
+    pub mean: f64,
+    pub median: f64,
+    pub min: f64,
+    pub max: f64,
+    pub stddev: f64,
+    pub p95: f64,
+    /// Number of samples rejected as outliers.
+    pub outliers: u128,
+}
+
+impl BenchStats {
+    /// Compute statistics from raw samples. Returns `None` for an empty sample set.
+    ///
+    /// Samples outside of Tukey's fences (1.5 times the interquartile range below the first or
+    /// above the third quartile) are rejected as outliers.
+    pub fn from_samples(samples: &[Duration]) -> Option<Self> {
+        #[allow(clippy::cast_precision_loss)]
+        let mut nanos: Vec<f64> = samples.iter().map(|d| d.as_nanos() as f64).collect();
+
+        if nanos.is_empty() {
+            return None;
+        }
+
+        nanos.sort_unstable_by(f64::total_cmp);
+
+        let q1 = percentile(&nanos, 25.0);
+        let q3 = percentile(&nanos, 75.0);
+        let fence = 1.5 * (q3 - q1);
+        let total = nanos.len();
+        nanos.retain(|x| *x >= q1 - fence && *x <= q3 + fence);
+
+        #[allow(clippy::cast_precision_loss)]
+        let len = nanos.len() as f64;
+        let mean = nanos.iter().sum::<f64>() / len;
+        let variance = nanos.iter().map(|x| (x - mean).powi(2)).sum::<f64>() / len;
+
+        Some(BenchStats {
+            mean,
+            median: percentile(&nanos, 50.0),
+            min: nanos[0],
+            max: nanos[nanos.len() - 1],
+            stddev: variance.sqrt(),
+            p95: percentile(&nanos, 95.0),
+            outliers: (total - nanos.len()) as u128,
+        })
+    }
+
+    pub fn get(&self, stat: Stat) -> f64 {
+        match stat {
+            Stat::Median => self.median,
+            Stat::Min => self.min,
+            Stat::Max => self.max,
+            Stat::StdDev => self.stddev,
+            Stat::P95 => self.p95,
+        }
+    }
+}
+
+/// Linearly interpolated percentile of sorted, non-empty values.
+fn percentile(sorted: &[f64], pct: f64) -> f64 {
+    #[allow(clippy::cast_precision_loss)]
+    let rank = pct / 100.0 * (sorted.len() - 1) as f64;
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    let (lo, hi) = (rank.floor() as usize, rank.ceil() as usize);
+    sorted[lo] + (sorted[hi] - sorted[lo]) * (rank - rank.floor())
+}
+
+/// A statistic that can be shown as an extra column in the benchmark table.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Stat {
+    Median,
+    Min,
+    Max,
+    StdDev,
+    P95,
+}
+
+impl Display for Stat {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
+            Stat::Median => "median",
+            Stat::Min => "min",
+            Stat::Max => "max",
+            Stat::StdDev => "stddev",
+            Stat::P95 => "p95",
+        })
+    }
+}
+
+impl FromStr for Stat {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "median" => Ok(Stat::Median),
+            "min" => Ok(Stat::Min),
+            "max" => Ok(Stat::Max),
+            "stddev" => Ok(Stat::StdDev),
+            "p95" => Ok(Stat::P95),
+            x => Err(format!(
+                "unknown statistic `{x}`, expecting one of median, min, max, stddev, p95."
+            )),
+        }
+    }
+}
+
+/// Parse a comma-separated list of statistics, e.g. `median,p95`.
+pub fn parse_stats(s: &str) -> Result<Vec<Stat>, String> {
+    s.split(',').map(|x| x.trim().parse()).collect()
+}
+
+/* -------------------------------------------------------------------------- */
+
+impl From<&BenchStats> for JsonValue {
+    fn from(value: &BenchStats) -> Self {
+        let mut map: HashMap<String, JsonValue> = HashMap::new();
+
+        map.insert("mean".into(), JsonValue::Number(value.mean));
+        map.insert("median".into(), JsonValue::Number(value.median));
+        map.insert("min".into(), JsonValue::Number(value.min));
+        map.insert("max".into(), JsonValue::Number(value.max));
+        map.insert("stddev".into(), JsonValue::Number(value.stddev));
+        map.insert("p95".into(), JsonValue::Number(value.p95));
+        #[allow(clippy::cast_precision_loss)]
+        map.insert("outliers".into(), JsonValue::Number(value.outliers as f64));
+
+        JsonValue::Object(map)
+    }
+}
+
+impl TryFrom<&JsonValue> for BenchStats {
+    type Error = String;
+
+    fn try_from(value: &JsonValue) -> Result<Self, Self::Error> {
+        let json = value
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or("Expected stats to be a JSON object.")?;
+
+        let number = |key: &str| {
+            json.get(key)
+                .and_then(|v| v.get::<f64>().copied())
+                .ok_or(format!("Expected stats.{key} to be a number."))
+        };
+
+        Ok(BenchStats {
+            mean: number("mean")?,
+            median: number("median")?,
+            min: number("min")?,
+            max: number("max")?,
+            stddev: number("stddev")?,
+            p95: number("p95")?,
+            #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+            outliers: number("outliers")? as u128,
+        })
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use std::time::Duration;
+
+    use super::{parse_stats, BenchConfig, BenchStats, Stat};
+
+    fn nanos(values: &[u64]) -> Vec<Duration> {
+        values.iter().map(|x| Duration::from_nanos(*x)).collect()
+    }
+
+    #[test]
+    fn computes_statistics() {
+        let stats = BenchStats::from_samples(&nanos(&[4, 2, 3, 1, 5])).unwrap();
+        assert_eq!(stats.mean, 3.0);
+        assert_eq!(stats.median, 3.0);
+        assert_eq!(stats.min, 1.0);
+        assert_eq!(stats.max, 5.0);
+        assert_eq!(stats.stddev, 2.0_f64.sqrt());
+        assert_eq!(stats.p95, 4.8);
+        assert_eq!(stats.outliers, 0);
+    }
+
+    #[test]
+    fn rejects_outliers() {
+        let stats = BenchStats::from_samples(&nanos(&[10, 11, 10, 12, 11, 10, 500])).unwrap();
+        assert_eq!(stats.outliers, 1);
+        assert_eq!(stats.max, 12.0);
+    }
+
+    #[test]
+    fn handles_empty_samples() {
+        assert!(BenchStats::from_samples(&[]).is_none());
+    }
+
+    #[test]
+    fn clamps_iterations() {
+        let config = BenchConfig::default();
+        assert_eq!(config.iterations(&Duration::from_secs(5)), 10);
+        assert_eq!(config.iterations(&Duration::from_nanos(1)), 10000);
+        assert_eq!(config.iterations(&Duration::from_millis(1)), 1000);
+    }
+
+    #[test]
+    fn round_trips_args() {
+        let config = BenchConfig {
+            budget: Duration::from_millis(250),
+            min_samples: 5,
+            max_samples: 50,
+            warmup: 0,
+        };
+        assert_eq!(BenchConfig::from_args(&config.to_args()), Ok(config));
+        assert_eq!(BenchConfig::from_args(&[]), Ok(BenchConfig::default()));
+        assert!(BenchConfig::from_args(&["--warmup".into()]).is_err());
+    }
+
+    #[test]
+    fn parses_stat_lists() {
+        assert_eq!(
+            parse_stats("median, p95"),
+            Ok(vec![Stat::Median, Stat::P95])
+        );
+        assert!(parse_stats("mode").is_err());
+    }
+}
@@ -0,0 +1,266 @@
+/// Scaffold templates that live in the `templates/` directory.
+///
+/// A template is a directory of files that are copied into the workspace by `scaffold`. Variables
+/// like `%YEAR%` are substituted in both the contents and the paths of the files, so a template
+/// can generate the solution as well as extra files such as a per-day module.
+///
+/// An optional `template.vars` file sets defaults for variables (`ANSWER_TYPE = u64`), and can name
+/// a `base` template whose files are used where the template does not have its own.
+use std::{
+    collections::{BTreeMap, HashMap},
+    fs,
+    path::{Path, PathBuf},
+};
+
+use regex::Regex;
+
+pub const TEMPLATES_DIR: &str = "templates";
+pub const DEFAULT_TEMPLATE: &str = "default";
+
+const VARIABLES_FILE: &str = "template.vars";
+
+/// A loaded template, with the files of its base templates merged in.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Template {
+    /// File contents by path relative to the workspace root, before substitution.
+    pub files: BTreeMap<String, String>,
+    /// Defaults for variables set by the template.
+    pub variables: HashMap<String, String>,
+}
+
+impl Template {
+    /// Load the template `name` from `dir`.
+    pub fn load(dir: &Path, name: &str) -> Result<Self, String> {
+        Template::load_with_bases(dir, name, &mut vec![])
+    }
+
+    fn load_with_bases(dir: &Path, name: &str, seen: &mut Vec<String>) -> Result<Self, String> {
+        if seen.iter().any(|x| x == name) {
+            return Err(format!("template `{name}` is its own base."));
+        }
+        seen.push(name.to_string());
+
+        let root = dir.join(name);
+        if !root.is_dir() {
+            return Err(format!(
+                "template `{name}` not found. Available templates: {}.",
+                available(dir).join(", ")
+            ));
+        }
+
+        let mut variables = match fs::read_to_string(root.join(VARIABLES_FILE)) {
+            Ok(content) => parse_variables(&content)?,
+            Err(_) => HashMap::new(),
+        };
+
+        let mut template = match variables.remove("base") {
+            Some(base) => Template::load_with_bases(dir, &base, seen)?,
+            None => Template::default(),
+        };
+
+        template.variables.extend(variables);
+
+        for path in list_files(&root).map_err(|e| e.to_string())? {
+            let relative = path
+                .strip_prefix(&root)
+                .map_err(|e| e.to_string())?
+                .to_string_lossy()
+                .replace('\\', "/");
+            if relative == VARIABLES_FILE {
+                continue;
+            }
+            let content = fs::read_to_string(&path).map_err(|e| format!("{relative}: {e}"))?;
+            template.files.insert(relative, content);
+        }
+
+        Ok(template)
+    }
+
+    /// Substitute `variables` into the paths and contents of all files.
+    /// Variables of the template are used where `variables` has no value.
+    pub fn render(&self, variables: &HashMap<String, String>) -> Vec<(String, String)> {
+        let mut all = self.variables.clone();
+        all.extend(variables.iter().map(|(k, v)| (k.clone(), v.clone())));
+
+        self.files
+            .iter()
+            .map(|(path, content)| (substitute(path, &all), substitute(content, &all)))
+            .collect()
+    }
+}
+
+/// Names of all templates in `dir`, sorted.
+pub fn available(dir: &Path) -> Vec<String> {
+    let mut names: Vec<String> = fs::read_dir(dir)
+        .map(|entries| {
+            entries
+                .filter_map(Result::ok)
+                .filter(|entry| entry.path().is_dir())
+                .map(|entry| entry.file_name().to_string_lossy().to_string())
+                .collect()
+        })
+        .unwrap_or_default();
+    names.sort();
+    names
+}
+
+/// Replace every `%NAME%` with the value of the variable `NAME`.
+pub fn substitute(text: &str, variables: &HashMap<String, String>) -> String {
+    variables
+        .iter()
+        .fold(text.to_string(), |text, (name, value)| {
+            text.replace(&format!("%{name}%"), value)
+        })
+}
+
+/// Find placeholders that were left after substitution, e.g. a misspelled `%YAER%`.
+pub fn unknown_variables(text: &str) -> Vec<&str> {
+    let placeholder = Regex::new("%([A-Z][A-Z0-9_]*)%").expect("valid regex");
+    placeholder
+        .captures_iter(text)
+        .map(|c| c.get(1).expect("group exists").as_str())
+        .collect()
+}
+
+/// Parse `KEY = value` lines. Blank lines and lines starting with `#` are ignored.
+pub fn parse_variables(content: &str) -> Result<HashMap<String, String>, String> {
+    content
+        .lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty() && !line.starts_with('#'))
+        .map(|line| {
+            line.split_once('=')
+                .map(|(key, value)| (key.trim().to_string(), value.trim().to_string()))
+                .ok_or(format!("expected `KEY = value`, got `{line}`."))
+        })
+        .collect()
+}
+
+fn list_files(dir: &Path) -> std::io::Result<Vec<PathBuf>> {
+    let mut files = vec![];
+    for entry in fs::read_dir(dir)? {
+        let path = entry?.path();
+        if path.is_dir() {
+            files.extend(list_files(&path)?);
+        } else {
+            files.push(path);
+        }
+    }
+    Ok(files)
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use std::{collections::HashMap, env, fs, path::PathBuf, process};
+
+    use super::{available, parse_variables, unknown_variables, Template};
+
+    /// Create a templates directory with the given files, relative to the directory.
+    fn templates_dir(name: &str, files: &[(&str, &str)]) -> PathBuf {
+        let dir = env::temp_dir().join(format!("aoc-templates-{}-{name}", process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        for (path, content) in files {
+            let path = dir.join(path);
+            fs::create_dir_all(path.parent().unwrap()).unwrap();
+            fs::write(path, content).unwrap();
+        }
+        dir
+    }
+
+    fn vars(pairs: &[(&str, &str)]) -> HashMap<String, String> {
+        pairs
+            .iter()
+            .map(|(k, v)| (k.to_string(), v.to_string()))
+            .collect()
+    }
+
+    #[test]
+    fn renders_paths_and_contents() {
+        let dir = templates_dir(
+            "render",
+            &[
+                (
+                    "default/src/bin/%YEAR%-%DAY%.rs",
+                    "solution!(%YEAR%, %DAY_NUMBER%);",
+                ),
+                ("default/data/%YEAR%/notes.md", "# Day %DAY_NUMBER%"),
+            ],
+        );
+        let template = Template::load(&dir, "default").unwrap();
+        let files = template.render(&vars(&[
+            ("YEAR", "2024"),
+            ("DAY", "05"),
+            ("DAY_NUMBER", "5"),
+        ]));
+        assert_eq!(
+            files,
+            vec![
+                ("data/2024/notes.md".into(), "# Day 5".into()),
+                ("src/bin/2024-05.rs".into(), "solution!(2024, 5);".into()),
+            ]
+        );
+        let _ = fs::remove_dir_all(dir);
+    }
+
+    #[test]
+    fn inherits_files_and_variables() {
+        let dir = templates_dir(
+            "inherit",
+            &[
+                ("default/a.rs", "%ANSWER_TYPE% %NAME%"),
+                ("default/b.rs", "b"),
+                (
+                    "u64/template.vars",
+                    "# comment\nbase = default\nANSWER_TYPE = u64\n",
+                ),
+                ("u64/b.rs", "own b"),
+            ],
+        );
+        let template = Template::load(&dir, "u64").unwrap();
+        let files = template.render(&vars(&[("NAME", "x")]));
+        assert_eq!(
+            files,
+            vec![
+                ("a.rs".into(), "u64 x".into()),
+                ("b.rs".into(), "own b".into())
+            ]
+        );
+
+        // variables passed when rendering take precedence over the template's defaults.
+        let files = template.render(&vars(&[("ANSWER_TYPE", "i64")]));
+        assert_eq!(files[0].1, "i64 %NAME%");
+
+        assert_eq!(available(&dir), vec!["default", "u64"]);
+        let _ = fs::remove_dir_all(dir);
+    }
+
+    #[test]
+    fn reports_missing_and_cyclic_templates() {
+        let dir = templates_dir(
+            "errors",
+            &[
+                ("a/template.vars", "base = b"),
+                ("b/template.vars", "base = a"),
+            ],
+        );
+        assert!(Template::load(&dir, "c").unwrap_err().contains("a, b"));
+        assert!(Template::load(&dir, "a").is_err());
+        let _ = fs::remove_dir_all(dir);
+    }
+
+    #[test]
+    fn parses_variables() {
+        assert_eq!(
+            parse_variables("A = 1\n\n# x\nB=two words").unwrap(),
+            vars(&[("A", "1"), ("B", "two words")])
+        );
+        assert!(parse_variables("A").is_err());
+    }
+
+    #[test]
+    fn finds_unknown_variables() {
+        assert_eq!(unknown_variables("50% of %YAER% and 10%"), vec!["YAER"]);
+    }
+}
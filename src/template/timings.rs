@@ -1,17 +1,100 @@
 use std::{collections::HashMap, fs, io::Error, str::FromStr};
 use tinyjson::JsonValue;
 
-use crate::template::Day;
+use crate::template::alloc::AllocStats;
+use crate::template::results::{DayResults, PartResult, PartStatus};
+use crate::template::stats::BenchStats;
+use crate::template::{Day, Year};
 
-static TIMINGS_FILE_PATH: &str = "./data/timings.json";
+/// Version of the `timings.json` format. Files without a version are version 1, which stored
+/// preformatted timings like `"74.1ms"`. They are migrated when read.
+pub const SCHEMA_VERSION: u32 = 2;
+
+fn get_path_for_timings(year: Year) -> String {
+    format!("./data/{year}/timings.json")
+}
+
+/// Benchmark results of a single part.
+#[derive(Clone, Debug, PartialEq)]
+pub struct PartTiming {
+    /// Execution time in nanoseconds, the mean when benched.
+    pub nanos: f64,
+    /// Number of measured runs. Unknown, and 0, for timings migrated from version 1.
+    pub samples: u128,
+    /// Spread of the samples, present when benched.
+    pub stats: Option<BenchStats>,
+    /// Allocations of a single run, recorded with the `alloc-stats` feature.
+    pub alloc: Option<AllocStats>,
+}
 
 /// Represents benchmark times for a single day.
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, PartialEq)]
 pub struct Timing {
     pub day: Day,
-    pub part_1: Option<String>,
-    pub part_2: Option<String>,
-    pub total_nanos: f64,
+    pub part_1: Option<PartTiming>,
+    pub part_2: Option<PartTiming>,
+    /// Why a part was killed before it finished. Killed parts have no timing.
+    pub part_1_killed: Option<PartStatus>,
+    pub part_2_killed: Option<PartStatus>,
+}
+
+impl Timing {
+    /// Build the timing for a day from the result records of its parts.
+    /// Parts without an answer are not counted.
+    pub fn from_results(day: Day, results: &[PartResult]) -> Self {
+        let mut timing = Timing {
+            day,
+            part_1: None,
+            part_2: None,
+            part_1_killed: None,
+            part_2_killed: None,
+        };
+
+        for result in results {
+            let (part, killed) = match result.part {
+                1 => (&mut timing.part_1, &mut timing.part_1_killed),
+                2 => (&mut timing.part_2, &mut timing.part_2_killed),
+                _ => continue,
+            };
+
+            match result.status {
+                PartStatus::Solved => {
+                    *part = Some(PartTiming {
+                        nanos: result.nanos,
+                        samples: result.samples,
+                        stats: result.stats.clone(),
+                        alloc: result.alloc,
+                    });
+                }
+                PartStatus::Timeout | PartStatus::OutOfMemory => *killed = Some(result.status),
+                PartStatus::Unsolved | PartStatus::Failed => {}
+            }
+        }
+
+        timing
+    }
+
+    pub fn part(&self, part: u8) -> Option<&PartTiming> {
+        match part {
+            1 => self.part_1.as_ref(),
+            2 => self.part_2.as_ref(),
+            _ => None,
+        }
+    }
+
+    pub fn killed(&self, part: u8) -> Option<PartStatus> {
+        match part {
+            1 => self.part_1_killed,
+            2 => self.part_2_killed,
+            _ => None,
+        }
+    }
+
+    /// Sum of the timings of both parts, in nanoseconds.
+    pub fn total_nanos(&self) -> f64 {
+        let nanos = |part: &Option<PartTiming>| part.as_ref().map_or(0.0, |p| p.nanos);
+        nanos(&self.part_1) + nanos(&self.part_2)
+    }
 }
 
 /// Represents benchmark times for a set of days.
@@ -22,16 +105,27 @@ pub struct Timings {
 }
 
 impl Timings {
-    /// Dehydrate timings to a JSON file.
-    pub fn store_file(&self) -> Result<(), Error> {
+    /// Collect the timings of all days that produced results.
+    pub fn from_results(results: &[DayResults]) -> Self {
+        Timings {
+            data: results
+                .iter()
+                .filter(|r| !r.parts.is_empty())
+                .map(|r| Timing::from_results(r.day, &r.parts))
+                .collect(),
+        }
+    }
+
+    /// Dehydrate timings to the JSON file of `year`, always in the current schema version.
+    pub fn store_file(&self, year: Year) -> Result<(), Error> {
         let json = JsonValue::from(self.clone());
-        let mut file = fs::File::create(TIMINGS_FILE_PATH)?;
+        let mut file = fs::File::create(get_path_for_timings(year))?;
         json.format_to(&mut file)
     }
 
-    /// Rehydrate timings from a JSON file. If not present, returns empty timings.
-    pub fn read_from_file() -> Self {
-        fs::read_to_string(TIMINGS_FILE_PATH)
+    /// Rehydrate timings from the JSON file of `year`. If not present, returns empty timings.
+    pub fn read_from_file(year: Year) -> Self {
+        fs::read_to_string(get_path_for_timings(year))
             .map_err(|x| x.to_string())
             .and_then(Timings::try_from)
             .unwrap_or_default()
@@ -51,28 +145,47 @@ impl Timings {
             }
         }
 
-        data.sort_unstable_by(|a, b| a.day.cmp(&b.day));
+        data.sort_unstable_by_key(|a| a.day);
         Timings { data }
     }
 
     /// Sum up total duration of timings as millis.
     pub fn total_millis(&self) -> f64 {
-        self.data.iter().map(|x| x.total_nanos).sum::<f64>() / 1_000_000_f64
+        self.data.iter().map(Timing::total_nanos).sum::<f64>() / 1_000_000_f64
     }
 
-    pub fn is_day_complete(&self, day: Day) -> bool {
-        self.data
-            .iter()
-            .any(|t| t.day == day && t.part_1.is_some() && t.part_2.is_some())
+    /// Whether every part of `day` has a timing. The last day of a year only has one part.
+    /// Killed parts have no timing.
+    pub fn is_day_complete(&self, year: Year, day: Day) -> bool {
+        self.data.iter().any(|t| {
+            t.day == day && t.part_1.is_some() && (t.part_2.is_some() || year.parts(day) == 1)
+        })
     }
 }
 
+/// Parse a timing formatted by version 1, e.g. `74.1ms` or `1.5µs`, into nanoseconds.
+fn parse_formatted_nanos(s: &str) -> Option<f64> {
+    let (value, unit) = s.split_at(s.find(char::is_alphabetic)?);
+    let factor = match unit {
+        "ns" => 1.0,
+        "µs" | "us" => 1e3,
+        "ms" => 1e6,
+        "s" => 1e9,
+        _ => return None,
+    };
+    value.trim().parse::<f64>().ok().map(|x| x * factor)
+}
+
 /* -------------------------------------------------------------------------- */
 
 impl From<Timings> for JsonValue {
     fn from(value: Timings) -> Self {
         let mut map: HashMap<String, JsonValue> = HashMap::new();
 
+        map.insert(
+            "version".into(),
+            JsonValue::Number(f64::from(SCHEMA_VERSION)),
+        );
         map.insert(
             "data".into(),
             JsonValue::Array(value.data.iter().map(JsonValue::from).collect()),
@@ -87,56 +200,160 @@ impl TryFrom<String> for Timings {
 
     fn try_from(value: String) -> Result<Self, Self::Error> {
         let json = JsonValue::from_str(&value).or(Err("not valid JSON file."))?;
+        Timings::try_from(&json)
+    }
+}
 
-        let json_data = json
+impl TryFrom<&JsonValue> for Timings {
+    type Error = String;
+
+    fn try_from(value: &JsonValue) -> Result<Self, Self::Error> {
+        let json = value
             .get::<HashMap<String, JsonValue>>()
-            .ok_or("expected JSON document to be an object.")?
+            .ok_or("expected JSON document to be an object.")?;
+
+        // NOTE: version 1 did not store a version.
+        #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+        let version = match json.get("version") {
+            Some(v) => v
+                .get::<f64>()
+                .map(|x| *x as u32)
+                .ok_or("expected `json.version` to be a number.")?,
+            None => 1,
+        };
+
+        if version > SCHEMA_VERSION {
+            return Err(format!(
+                "timings have version {version}, which is newer than the supported {SCHEMA_VERSION}."
+            ));
+        }
+
+        let json_data = json
             .get("data")
             .ok_or("expected JSON document to have key `data`.")?
             .get::<Vec<JsonValue>>()
             .ok_or("expected `json.data` to be an array.")?;
 
+        let parse: fn(&JsonValue) -> Result<Timing, String> = if version == 1 {
+            migrate_v1
+        } else {
+            |v| Timing::try_from(v)
+        };
+
         Ok(Timings {
-            data: json_data
-                .iter()
-                .map(Timing::try_from)
-                .collect::<Result<_, _>>()?,
+            data: json_data.iter().map(parse).collect::<Result<_, _>>()?,
         })
     }
 }
 
 /* -------------------------------------------------------------------------- */
 
+impl From<&PartTiming> for JsonValue {
+    fn from(value: &PartTiming) -> Self {
+        let mut map: HashMap<String, JsonValue> = HashMap::new();
+
+        map.insert("nanos".into(), JsonValue::Number(value.nanos));
+        #[allow(clippy::cast_precision_loss)]
+        map.insert("samples".into(), JsonValue::Number(value.samples as f64));
+
+        if let Some(stats) = &value.stats {
+            map.insert("stats".into(), JsonValue::from(stats));
+        }
+
+        if let Some(alloc) = &value.alloc {
+            map.insert("alloc".into(), JsonValue::from(alloc));
+        }
+
+        JsonValue::Object(map)
+    }
+}
+
+impl TryFrom<&JsonValue> for PartTiming {
+    type Error = String;
+
+    fn try_from(value: &JsonValue) -> Result<Self, Self::Error> {
+        let json = value
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or("Expected part timing to be a JSON object.")?;
+
+        let number = |key: &str| {
+            json.get(key)
+                .and_then(|v| v.get::<f64>().copied())
+                .ok_or(format!("Expected part.{key} to be a number."))
+        };
+
+        #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+        Ok(PartTiming {
+            nanos: number("nanos")?,
+            samples: number("samples")? as u128,
+            stats: optional(json, "stats", |v| BenchStats::try_from(v))?,
+            alloc: optional(json, "alloc", |v| AllocStats::try_from(v))?,
+        })
+    }
+}
+
+/// Read an optional, possibly null value of `json`.
+fn optional<T>(
+    json: &HashMap<String, JsonValue>,
+    key: &str,
+    parse: impl Fn(&JsonValue) -> Result<T, String>,
+) -> Result<Option<T>, String> {
+    match json.get(key) {
+        Some(v) if !v.is_null() => parse(v).map(Some),
+        _ => Ok(None),
+    }
+}
+
 impl From<&Timing> for JsonValue {
     fn from(value: &Timing) -> Self {
         let mut map: HashMap<String, JsonValue> = HashMap::new();
 
         map.insert("day".into(), JsonValue::String(value.day.to_string()));
-        map.insert("total_nanos".into(), JsonValue::Number(value.total_nanos));
-
-        let part_1 = value.part_1.clone().map(JsonValue::String);
-        let part_2 = value.part_2.clone().map(JsonValue::String);
 
-        map.insert(
-            "part_1".into(),
-            match part_1 {
-                Some(x) => x,
-                None => JsonValue::Null,
-            },
-        );
+        for (key, part) in [("part_1", &value.part_1), ("part_2", &value.part_2)] {
+            map.insert(
+                key.into(),
+                match part {
+                    Some(x) => JsonValue::from(x),
+                    None => JsonValue::Null,
+                },
+            );
+        }
 
-        map.insert(
-            "part_2".into(),
-            match part_2 {
-                Some(x) => x,
-                None => JsonValue::Null,
-            },
-        );
+        for (key, killed) in [
+            ("part_1_killed", value.part_1_killed),
+            ("part_2_killed", value.part_2_killed),
+        ] {
+            if let Some(status) = killed {
+                map.insert(key.into(), JsonValue::String(status.as_str().into()));
+            }
+        }
 
         JsonValue::Object(map)
     }
 }
 
+/// Read the day and the killed parts of a timing, which all schema versions share.
+fn day_and_killed(
+    json: &HashMap<String, JsonValue>,
+) -> Result<(Day, Option<PartStatus>, Option<PartStatus>), String> {
+    let day = json
+        .get("day")
+        .and_then(|v| v.get::<String>())
+        .and_then(|day| Day::from_str(day).ok())
+        .ok_or("Expected timing.day to be a Day struct.")?;
+
+    let killed = |key: &str| {
+        optional(json, key, |v| {
+            v.get::<String>()
+                .ok_or(format!("Expected timing.{key} to be a string."))?
+                .parse()
+        })
+    };
+
+    Ok((day, killed("part_1_killed")?, killed("part_2_killed")?))
+}
+
 impl TryFrom<&JsonValue> for Timing {
     type Error = String;
 
@@ -145,87 +362,140 @@ impl TryFrom<&JsonValue> for Timing {
             .get::<HashMap<String, JsonValue>>()
             .ok_or("Expected timing to be a JSON object.")?;
 
-        let day = json
-            .get("day")
-            .and_then(|v| v.get::<String>())
-            .and_then(|day| Day::from_str(day).ok())
-            .ok_or("Expected timing.day to be a Day struct.")?;
-
-        let part_1 = json
-            .get("part_1")
-            .map(|v| if v.is_null() { None } else { v.get::<String>() })
-            .ok_or("Expected timing.part_1 to be null or string.")?;
-
-        let part_2 = json
-            .get("part_2")
-            .map(|v| if v.is_null() { None } else { v.get::<String>() })
-            .ok_or("Expected timing.part_2 to be null or string.")?;
+        let (day, part_1_killed, part_2_killed) = day_and_killed(json)?;
 
-        let total_nanos = json
-            .get("total_nanos")
-            .and_then(|v| v.get::<f64>().copied())
-            .ok_or("Expected timing.total_nanos to be a number.")?;
+        let part = |key: &str| match json.get(key) {
+            Some(v) if v.is_null() => Ok(None),
+            Some(v) => PartTiming::try_from(v).map(Some),
+            None => Err(format!("Expected timing.{key} to be null or an object.")),
+        };
 
         Ok(Timing {
             day,
-            part_1: part_1.cloned(),
-            part_2: part_2.cloned(),
-            total_nanos,
+            part_1: part("part_1")?,
+            part_2: part("part_2")?,
+            part_1_killed,
+            part_2_killed,
         })
     }
 }
 
+/// Read a timing of version 1. Its parts are formatted strings, with optional stats and
+/// allocations next to them, e.g. `"part_1": "74.1ms", "part_1_stats": { "mean": ... }`.
+fn migrate_v1(value: &JsonValue) -> Result<Timing, String> {
+    let json = value
+        .get::<HashMap<String, JsonValue>>()
+        .ok_or("Expected timing to be a JSON object.")?;
+
+    let (day, part_1_killed, part_2_killed) = day_and_killed(json)?;
+
+    let part = |key: &str, killed: Option<PartStatus>| -> Result<Option<PartTiming>, String> {
+        let formatted = json
+            .get(key)
+            .map(|v| if v.is_null() { None } else { v.get::<String>() })
+            .ok_or(format!("Expected timing.{key} to be null or string."))?;
+
+        // NOTE: killed parts stored a label like `TIMEOUT` instead of a timing.
+        let Some(formatted) = formatted.filter(|_| killed.is_none()) else {
+            return Ok(None);
+        };
+
+        let stats = optional(json, &format!("{key}_stats"), |v| BenchStats::try_from(v))?;
+        let alloc = optional(json, &format!("{key}_alloc"), |v| AllocStats::try_from(v))?;
+
+        // the stats hold the exact mean, the formatted timing is rounded.
+        let nanos = match &stats {
+            Some(stats) => stats.mean,
+            None => parse_formatted_nanos(formatted).ok_or(format!(
+                "Expected timing.{key} to be a duration, got `{formatted}`."
+            ))?,
+        };
+
+        Ok(Some(PartTiming {
+            nanos,
+            samples: 0,
+            stats,
+            alloc,
+        }))
+    };
+
+    Ok(Timing {
+        day,
+        part_1: part("part_1", part_1_killed)?,
+        part_2: part("part_2", part_2_killed)?,
+        part_1_killed,
+        part_2_killed,
+    })
+}
+
 /* -------------------------------------------------------------------------- */
 
 #[cfg(feature = "test_lib")]
 mod tests {
     use crate::day;
+    use crate::template::Day;
+
+    use super::{PartTiming, Timing, Timings};
+
+    fn timing(day: Day, part_1: Option<f64>, part_2: Option<f64>) -> Timing {
+        let part = |nanos| PartTiming {
+            nanos,
+            samples: 10,
+            stats: None,
+            alloc: None,
+        };
 
-    use super::{Timing, Timings};
+        Timing {
+            day,
+            part_1: part_1.map(part),
+            part_2: part_2.map(part),
+            part_1_killed: None,
+            part_2_killed: None,
+        }
+    }
 
     fn get_mock_timings() -> Timings {
         Timings {
             data: vec![
-                Timing {
-                    day: day!(1),
-                    part_1: Some("10ms".into()),
-                    part_2: Some("20ms".into()),
-                    total_nanos: 3e+10,
-                },
-                Timing {
-                    day: day!(2),
-                    part_1: Some("30ms".into()),
-                    part_2: Some("40ms".into()),
-                    total_nanos: 7e+10,
-                },
-                Timing {
-                    day: day!(4),
-                    part_1: Some("40ms".into()),
-                    part_2: None,
-                    total_nanos: 4e+10,
-                },
+                timing(day!(1), Some(1e7), Some(2e7)),
+                timing(day!(2), Some(3e7), Some(4e7)),
+                timing(day!(4), Some(4e7), None),
             ],
         }
     }
 
     mod deserialization {
-        use crate::{day, template::timings::Timings};
+        use crate::{
+            day,
+            template::{results::PartStatus, timings::Timings},
+        };
 
         #[test]
         fn handles_json_timings() {
-            let json = r#"{ "data": [{ "day": "01", "part_1": "1ms", "part_2": null, "total_nanos": 1000000000 }] }"#.to_string();
+            let json = r#"{ "version": 2, "data": [{ "day": "01", "part_1": { "nanos": 1000000, "samples": 12 }, "part_2": null }] }"#.to_string();
             let timings = Timings::try_from(json).unwrap();
             assert_eq!(timings.data.len(), 1);
             let timing = timings.data.first().unwrap();
             assert_eq!(timing.day, day!(1));
-            assert_eq!(timing.part_1, Some("1ms".to_string()));
+            let part_1 = timing.part_1.as_ref().unwrap();
+            assert_eq!(part_1.nanos, 1_000_000_f64);
+            assert_eq!(part_1.samples, 12);
             assert_eq!(timing.part_2, None);
-            assert_eq!(timing.total_nanos, 1_000_000_000_f64);
+            assert_eq!(timing.total_nanos(), 1_000_000_f64);
+        }
+
+        #[test]
+        fn handles_killed_parts() {
+            let json = r#"{ "version": 2, "data": [{ "day": "23", "part_1": null, "part_2": null, "part_1_killed": "timeout" }] }"#.to_string();
+            let timings = Timings::try_from(json).unwrap();
+            let timing = timings.data.first().unwrap();
+            assert_eq!(timing.part_1_killed, Some(PartStatus::Timeout));
+            assert_eq!(timing.part_2_killed, None);
         }
 
         #[test]
         fn handles_empty_timings() {
-            let json = r#"{ "data": [] }"#.to_string();
+            let json = r#"{ "version": 2, "data": [] }"#.to_string();
             let timings = Timings::try_from(json).unwrap();
             assert_eq!(timings.data.len(), 0);
         }
@@ -240,101 +510,255 @@ mod tests {
         #[test]
         #[should_panic]
         fn panics_for_malformed_timings() {
-            let json = r#"{ "data": [{ "day": "01" }, { "day": "26" }, { "day": "02", "part_2": null, "total_nanos": 0 }] }"#.to_string();
+            let json = r#"{ "version": 2, "data": [{ "day": "01" }, { "day": "26" }, { "day": "02", "part_2": null }] }"#.to_string();
+            Timings::try_from(json).unwrap();
+        }
+
+        #[test]
+        #[should_panic]
+        fn panics_for_newer_versions() {
+            let json = r#"{ "version": 3, "data": [] }"#.to_string();
+            Timings::try_from(json).unwrap();
+        }
+    }
+
+    mod migration {
+        use crate::{
+            day,
+            template::{results::PartStatus, timings::Timings},
+        };
+
+        #[test]
+        fn migrates_formatted_timings() {
+            let json = r#"{ "data": [
+                { "day": "01", "part_1": "74.1ms", "part_2": "1.5µs", "total_nanos": 74101500 },
+                { "day": "02", "part_1": "2.0s", "part_2": null, "total_nanos": 2000000000 }
+            ] }"#
+                .to_string();
+            let timings = Timings::try_from(json).unwrap();
+            let part_1 = timings.data[0].part_1.as_ref().unwrap();
+            assert_eq!(part_1.nanos, 74_100_000.0);
+            assert_eq!(part_1.samples, 0);
+            assert_eq!(timings.data[0].part_2.as_ref().unwrap().nanos, 1_500.0);
+            assert_eq!(timings.data[1].day, day!(2));
+            assert_eq!(timings.data[1].part_1.as_ref().unwrap().nanos, 2e9);
+            assert_eq!(timings.data[1].part_2, None);
+        }
+
+        #[test]
+        fn prefers_mean_of_stats() {
+            let json = r#"{ "data": [{ "day": "01", "part_1": "74.1ms", "part_2": null, "total_nanos": 74130000,
+                "part_1_stats": { "mean": 74130000, "median": 74000000, "min": 70000000, "max": 80000000, "stddev": 1500000, "p95": 79000000, "outliers": 2 },
+                "part_1_alloc": { "peak_bytes": 1024, "total_bytes": 2048, "allocations": 3 } }] }"#
+                .to_string();
+            let timings = Timings::try_from(json).unwrap();
+            let part_1 = timings.data[0].part_1.as_ref().unwrap();
+            assert_eq!(part_1.nanos, 74_130_000.0);
+            assert_eq!(part_1.stats.as_ref().unwrap().median, 74_000_000.0);
+            assert_eq!(part_1.alloc.unwrap().peak_bytes, 1024);
+        }
+
+        #[test]
+        fn migrates_killed_parts() {
+            let json = r#"{ "data": [{ "day": "23", "part_1": "1.0ms", "part_2": "TIMEOUT", "total_nanos": 1000000, "part_2_killed": "timeout" }] }"#.to_string();
+            let timings = Timings::try_from(json).unwrap();
+            assert!(timings.data[0].part_1.is_some());
+            assert_eq!(timings.data[0].part_2, None);
+            assert_eq!(timings.data[0].part_2_killed, Some(PartStatus::Timeout));
+        }
+
+        #[test]
+        #[should_panic]
+        fn panics_for_unknown_durations() {
+            let json =
+                r#"{ "data": [{ "day": "01", "part_1": "fast", "part_2": null, "total_nanos": 0 }] }"#
+                    .to_string();
             Timings::try_from(json).unwrap();
         }
     }
 
     mod serialization {
         use super::get_mock_timings;
+        use crate::template::timings::{Timings, SCHEMA_VERSION};
         use std::collections::HashMap;
         use tinyjson::JsonValue;
 
         #[test]
         fn serializes_timings() {
             let timings = get_mock_timings();
-            let value = JsonValue::try_from(timings).unwrap();
+            let value = JsonValue::from(timings);
+            let map = value.get::<HashMap<String, JsonValue>>().unwrap();
             assert_eq!(
-                value
-                    .get::<HashMap<String, JsonValue>>()
-                    .unwrap()
-                    .get("data")
+                map.get("data")
                     .unwrap()
                     .get::<Vec<JsonValue>>()
                     .unwrap()
                     .len(),
                 3
             );
+            assert_eq!(
+                map.get("version").unwrap().get::<f64>(),
+                Some(&f64::from(SCHEMA_VERSION))
+            );
+        }
+
+        #[test]
+        fn round_trips_timings() {
+            let timings = get_mock_timings();
+            let json = JsonValue::from(timings.clone()).stringify().unwrap();
+            assert_eq!(Timings::try_from(json).unwrap().data, timings.data);
+        }
+    }
+
+    mod from_results {
+        use crate::{
+            day,
+            template::{
+                alloc::AllocStats,
+                results::{PartResult, PartStatus},
+                timings::Timing,
+            },
+        };
+
+        fn result(part: u8, answer: Option<&str>, nanos: f64) -> PartResult {
+            PartResult {
+                part,
+                answer: answer.map(Into::into),
+                nanos,
+                samples: 100,
+                stats: None,
+                alloc: None,
+                status: if answer.is_some() {
+                    PartStatus::Solved
+                } else {
+                    PartStatus::Unsolved
+                },
+                error: None,
+            }
+        }
+
+        #[test]
+        fn sums_solved_parts() {
+            let timing = Timing::from_results(
+                day!(1),
+                &[
+                    result(1, Some("0"), 74.13),
+                    result(2, Some("10"), 74_130_000.0),
+                ],
+            );
+            assert_eq!(timing.total_nanos(), 74_130_074.13);
+            assert_eq!(timing.part_1.unwrap().nanos, 74.13);
+            let part_2 = timing.part_2.unwrap();
+            assert_eq!(part_2.nanos, 74_130_000.0);
+            assert_eq!(part_2.samples, 100);
+        }
+
+        #[test]
+        fn keeps_alloc_stats() {
+            let alloc = AllocStats {
+                peak_bytes: 1024,
+                total_bytes: 2048,
+                allocations: 2,
+            };
+            let timing = Timing::from_results(
+                day!(1),
+                &[PartResult {
+                    alloc: Some(alloc),
+                    ..result(1, Some("1"), 10.0)
+                }],
+            );
+            assert_eq!(timing.part_1.unwrap().alloc, Some(alloc));
+            assert_eq!(timing.part_2, None);
+        }
+
+        #[test]
+        fn records_killed_parts() {
+            let timing = Timing::from_results(
+                day!(1),
+                &[
+                    result(1, Some("1"), 10.0),
+                    PartResult::killed(2, PartStatus::Timeout),
+                ],
+            );
+            assert_eq!(timing.total_nanos(), 10.0);
+            assert_eq!(timing.part(2), None);
+            assert_eq!(timing.killed(2), Some(PartStatus::Timeout));
+        }
+
+        #[test]
+        fn skips_unsolved_parts() {
+            let timing =
+                Timing::from_results(day!(1), &[result(1, None, 10.0), result(2, None, 10.0)]);
+            assert_eq!(timing.total_nanos(), 0_f64);
+            assert!(timing.part_1.is_none());
+            assert!(timing.part_2.is_none());
         }
     }
 
     mod is_day_complete {
+        use super::timing;
         use crate::{
             day,
-            template::timings::{Timing, Timings},
+            template::{results::PartStatus, timings::Timings},
+            year,
         };
 
         #[test]
         fn handles_completed_days() {
             let timings = Timings {
-                data: vec![Timing {
-                    day: day!(1),
-                    part_1: Some("1ms".into()),
-                    part_2: Some("2ms".into()),
-                    total_nanos: 3_000_000_000_f64,
-                }],
+                data: vec![timing(day!(1), Some(1e6), Some(2e6))],
             };
 
-            assert_eq!(timings.is_day_complete(&day!(1)), true);
+            assert_eq!(timings.is_day_complete(year!(2024), day!(1)), true);
         }
 
         #[test]
         fn handles_partial_days() {
             let timings = Timings {
-                data: vec![Timing {
-                    day: day!(1),
-                    part_1: Some("1ms".into()),
-                    part_2: None,
-                    total_nanos: 1_000_000_000_f64,
-                }],
+                data: vec![timing(day!(1), Some(1e6), None)],
             };
 
-            assert_eq!(timings.is_day_complete(&day!(1)), false);
+            assert_eq!(timings.is_day_complete(year!(2024), day!(1)), false);
+        }
+
+        #[test]
+        fn handles_last_day() {
+            let timings = Timings {
+                data: vec![timing(day!(12), Some(1e6), None)],
+            };
+
+            assert_eq!(timings.is_day_complete(year!(2025), day!(12)), true);
+            assert_eq!(timings.is_day_complete(year!(2024), day!(12)), false);
+        }
+
+        #[test]
+        fn handles_killed_days() {
+            let mut killed = timing(day!(1), Some(1e6), None);
+            killed.part_2_killed = Some(PartStatus::OutOfMemory);
+            let timings = Timings { data: vec![killed] };
+
+            assert_eq!(timings.is_day_complete(year!(2024), day!(1)), false);
         }
 
         #[test]
         fn handles_uncompleted_days() {
             let timings = Timings {
-                data: vec![Timing {
-                    day: day!(1),
-                    part_1: None,
-                    part_2: None,
-                    total_nanos: 0.0,
-                }],
+                data: vec![timing(day!(1), None, None)],
             };
 
-            assert_eq!(timings.is_day_complete(&day!(1)), false);
+            assert_eq!(timings.is_day_complete(year!(2024), day!(1)), false);
         }
     }
 
     mod merge {
-        use crate::{
-            day,
-            template::timings::{Timing, Timings},
-        };
-
-        use super::get_mock_timings;
+        use super::{get_mock_timings, timing};
+        use crate::{day, template::timings::Timings};
 
         #[test]
         fn handles_disjunct_timings() {
             let timings = get_mock_timings();
             let other = Timings {
-                data: vec![Timing {
-                    day: day!(3),
-                    part_1: None,
-                    part_2: None,
-                    total_nanos: 0_f64,
-                }],
+                data: vec![timing(day!(3), None, None)],
             };
             let merged = timings.merge(&other);
             assert_eq!(merged.data.len(), 4);
@@ -349,19 +773,14 @@ mod tests {
             let timings = get_mock_timings();
 
             let other = Timings {
-                data: vec![Timing {
-                    day: day!(2),
-                    part_1: None,
-                    part_2: None,
-                    total_nanos: 0_f64,
-                }],
+                data: vec![timing(day!(2), None, None)],
             };
             let merged = timings.merge(&other);
 
             assert_eq!(merged.data.len(), 3);
             assert_eq!(merged.data[0].day, day!(1));
             assert_eq!(merged.data[1].day, day!(2));
-            assert_eq!(merged.data[1].total_nanos, 0_f64);
+            assert_eq!(merged.data[1].total_nanos(), 0_f64);
             assert_eq!(merged.data[2].day, day!(4));
         }
 
@@ -380,5 +799,10 @@ mod tests {
             let merged = timings.merge(&other);
             assert_eq!(merged.data.len(), 3);
         }
+
+        #[test]
+        fn sums_total_millis() {
+            assert_eq!(get_mock_timings().total_millis(), 140.0);
+        }
     }
 }
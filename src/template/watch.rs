@@ -0,0 +1,261 @@
+/// Change detection and result summaries for `cargo watch`.
+///
+/// The files of a day are polled by their modification times, so no file system events are
+/// needed. Each run of the watched action is summarized as a few labelled lines, which are
+/// compared against the previous run.
+use std::{
+    collections::BTreeMap,
+    fs,
+    path::{Path, PathBuf},
+    time::SystemTime,
+};
+
+use crate::template::example_tests::is_example_of;
+use crate::template::{Day, Year};
+
+/// Modification times of the watched files of a day.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Snapshot {
+    files: BTreeMap<PathBuf, SystemTime>,
+}
+
+impl Snapshot {
+    /// Take a snapshot of the solution of `day`, its modules, examples and input.
+    pub fn take(year: Year, day: Day) -> Self {
+        let mut paths = vec![
+            PathBuf::from(format!("./src/bin/{year}-{day}.rs")),
+            PathBuf::from(format!("./data/{year}/inputs/{day}.txt")),
+        ];
+        collect_files(Path::new(&format!("./src/bin/{year}-{day}")), &mut paths);
+
+        if let Ok(entries) = fs::read_dir(format!("./data/{year}/examples")) {
+            paths.extend(entries.flatten().map(|entry| entry.path()).filter(|path| {
+                // NOTE: also watch the expected answers of the examples.
+                let day = day.to_string();
+                is_example_of(path, &day) || is_example_of(&path.with_extension("txt"), &day)
+            }));
+        }
+
+        Snapshot {
+            files: paths
+                .into_iter()
+                .filter_map(|path| {
+                    let modified = fs::metadata(&path).and_then(|m| m.modified()).ok()?;
+                    Some((path, modified))
+                })
+                .collect(),
+        }
+    }
+
+    /// Files that were added, modified or removed in `newer`.
+    pub fn changes(&self, newer: &Snapshot) -> Vec<PathBuf> {
+        let mut changes: Vec<PathBuf> = newer
+            .files
+            .iter()
+            .filter(|(path, modified)| self.files.get(*path) != Some(modified))
+            .map(|(path, _)| path.clone())
+            .collect();
+
+        changes.extend(
+            self.files
+                .keys()
+                .filter(|path| !newer.files.contains_key(*path))
+                .cloned(),
+        );
+
+        changes
+    }
+}
+
+/// Collect all files in `dir` and its subdirectories.
+fn collect_files(dir: &Path, paths: &mut Vec<PathBuf>) {
+    let Ok(entries) = fs::read_dir(dir) else {
+        return;
+    };
+
+    for path in entries.flatten().map(|entry| entry.path()) {
+        if path.is_dir() {
+            collect_files(&path, paths);
+        } else {
+            paths.push(path);
+        }
+    }
+}
+
+/// One line of a run summary, e.g. the answer of a part or the outcome of a test.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SummaryLine {
+    pub label: String,
+    pub value: String,
+    /// Whether the line is a pass or a failure. `None` if it cannot be told, e.g. for an answer
+    /// that is not known yet.
+    pub passed: Option<bool>,
+    /// Extra lines shown below a failure, e.g. the failed assertion.
+    pub details: Vec<String>,
+}
+
+/// Summarize the output of `cargo test`, with a line per test and the message of failed tests.
+pub fn parse_test_output(stdout: &str) -> Vec<SummaryLine> {
+    let mut lines: Vec<SummaryLine> = stdout
+        .lines()
+        .filter_map(|line| {
+            let (name, outcome) = line.strip_prefix("test ")?.split_once(" ... ")?;
+            let name = name.strip_prefix("tests::").unwrap_or(name);
+            let passed = match outcome.trim() {
+                "ok" => Some(true),
+                "FAILED" => Some(false),
+                _ => None,
+            };
+            Some(SummaryLine {
+                label: name.to_string(),
+                value: outcome.trim().to_string(),
+                passed,
+                details: vec![],
+            })
+        })
+        .collect();
+
+    // NOTE: the output of a failed test is printed after all tests ran, e.g.
+    // `---- tests::test_part_one stdout ----`, followed by its panic message.
+    let mut current: Option<usize> = None;
+    for line in stdout.lines() {
+        if let Some(name) = line
+            .strip_prefix("---- ")
+            .and_then(|x| x.strip_suffix(" stdout ----"))
+        {
+            let name = name.strip_prefix("tests::").unwrap_or(name);
+            current = lines.iter().position(|l| l.label == name);
+            continue;
+        }
+
+        let Some(index) = current else {
+            continue;
+        };
+
+        if line.trim().is_empty() {
+            if !lines[index].details.is_empty() {
+                current = None;
+            }
+        } else if line.starts_with("stack backtrace:") || line.starts_with("failures:") {
+            current = None;
+        } else if !line.starts_with("thread '") && !line.starts_with("note:") {
+            lines[index].details.push(line.trim_end().to_string());
+        }
+    }
+
+    lines
+}
+
+/// Format `current`, marking lines whose value changed since `previous`.
+pub fn format_summary(current: &[SummaryLine], previous: Option<&[SummaryLine]>) -> Vec<String> {
+    let mut output = vec![];
+
+    for line in current {
+        let mark = match line.passed {
+            Some(true) => "✔",
+            Some(false) => "✖",
+            None => "?",
+        };
+
+        let before = previous
+            .and_then(|previous| previous.iter().find(|l| l.label == line.label))
+            .map(|l| l.value.as_str());
+
+        let change = match before {
+            Some(before) if before != line.value => format!(" (was {before})"),
+            None if previous.is_some() => " (new)".into(),
+            _ => String::new(),
+        };
+
+        output.push(format!("{mark} {}: {}{change}", line.label, line.value));
+        output.extend(line.details.iter().map(|detail| format!("    {detail}")));
+    }
+
+    output
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use std::{
+        collections::BTreeMap,
+        path::PathBuf,
+        time::{Duration, SystemTime},
+    };
+
+    use super::{format_summary, parse_test_output, Snapshot};
+
+    const TEST_OUTPUT: &str = "
+running 2 tests
+test tests::test_part_one ... ok
+test tests::test_part_two ... FAILED
+
+failures:
+
+---- tests::test_part_two stdout ----
+
+thread 'tests::test_part_two' panicked at src/bin/2024-01.rs:40:9:
+assertion `left == right` failed
+  left: Some(30)
+ right: Some(31)
+note: run with `RUST_BACKTRACE=1` environment variable to display a backtrace
+
+
+failures:
+    tests::test_part_two
+
+test result: FAILED. 1 passed; 1 failed; 0 ignored; 0 measured; 0 filtered out; finished in 0.00s
+";
+
+    #[test]
+    fn detects_changed_files() {
+        let time = SystemTime::UNIX_EPOCH;
+        let snapshot = |files: &[(&str, u64)]| Snapshot {
+            files: files
+                .iter()
+                .map(|(path, secs)| (PathBuf::from(path), time + Duration::from_secs(*secs)))
+                .collect::<BTreeMap<_, _>>(),
+        };
+
+        let before = snapshot(&[("a.rs", 1), ("b.txt", 1), ("c.txt", 1)]);
+        let after = snapshot(&[("a.rs", 2), ("b.txt", 1), ("d.txt", 1)]);
+        assert_eq!(
+            before.changes(&after),
+            vec![
+                PathBuf::from("a.rs"),
+                PathBuf::from("d.txt"),
+                PathBuf::from("c.txt")
+            ]
+        );
+        assert!(before.changes(&before).is_empty());
+    }
+
+    #[test]
+    fn parses_test_output() {
+        let lines = parse_test_output(TEST_OUTPUT);
+        assert_eq!(lines.len(), 2);
+        assert_eq!(lines[0].label, "test_part_one");
+        assert_eq!(lines[0].passed, Some(true));
+        assert_eq!(lines[1].value, "FAILED");
+        assert_eq!(
+            lines[1].details,
+            vec![
+                "assertion `left == right` failed",
+                "  left: Some(30)",
+                " right: Some(31)"
+            ]
+        );
+    }
+
+    #[test]
+    fn formats_changes_since_previous_run() {
+        let previous = parse_test_output(&TEST_OUTPUT.replace("FAILED\n", "ok\n"));
+        let current = parse_test_output(TEST_OUTPUT);
+        let output = format_summary(&current, Some(&previous));
+        assert_eq!(output[0], "✔ test_part_one: ok");
+        assert_eq!(output[1], "✖ test_part_two: FAILED (was ok)");
+        assert_eq!(output[4], "     right: Some(31)");
+        assert_eq!(format_summary(&current, None)[1], "✖ test_part_two: FAILED");
+    }
+}
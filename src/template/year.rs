@@ -0,0 +1,159 @@
+use std::env;
+use std::error::Error;
+use std::fmt::Display;
+use std::str::FromStr;
+
+use crate::template::Day;
+
+#[cfg(feature = "today")]
+use crate::template::day::server_now;
+#[cfg(feature = "today")]
+use chrono::Datelike;
+
+/// Environment variable that holds the year used when no `--year` is passed.
+pub const YEAR_ENV: &str = "AOC_YEAR";
+
+/// A valid year of advent (i.e. 2015 or later).
+///
+/// # Display
+/// This value displays as a four digit number.
+///
+/// ```
+/// # use advent_of_code::template::Year;
+/// let year = Year::new(2024).unwrap();
+/// assert_eq!(year.to_string(), "2024")
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Year(u16);
+
+impl Year {
+    /// Creates a [`Year`] from the provided value if it's a year of advent,
+    /// returns [`None`] otherwise.
+    pub fn new(year: u16) -> Option<Self> {
+        if !(2015..=9999).contains(&year) {
+            return None;
+        }
+        Some(Self(year))
+    }
+
+    // Not part of the public API
+    #[doc(hidden)]
+    pub const fn __new_unchecked(year: u16) -> Self {
+        Self(year)
+    }
+
+    /// Converts the [`Year`] into an [`u16`].
+    pub fn into_inner(self) -> u16 {
+        self.0
+    }
+
+    /// Number of puzzle days. Events before 2025 have 25 days, later events have 12.
+    pub const fn days(self) -> u8 {
+        if self.0 >= 2025 {
+            12
+        } else {
+            25
+        }
+    }
+
+    /// Whether `day` is a puzzle day of this year.
+    pub fn contains(self, day: Day) -> bool {
+        day <= self.days()
+    }
+
+    /// Number of parts of a day. The last day has no second puzzle.
+    pub fn parts(self, day: Day) -> u8 {
+        if day == self.days() {
+            1
+        } else {
+            2
+        }
+    }
+
+    /// Reads the default year from `AOC_YEAR`, which is set in `.cargo/config.toml`.
+    pub fn from_env() -> Option<Self> {
+        env::var(YEAR_ENV).ok()?.trim().parse().ok()
+    }
+}
+
+#[cfg(feature = "today")]
+impl Year {
+    /// Returns the current year in the timezone of the puzzle server.
+    pub fn today() -> Option<Self> {
+        Self::new(u16::try_from(server_now()?.year()).ok()?)
+    }
+}
+
+impl Display for Year {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:04}", self.0)
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+impl FromStr for Year {
+    type Err = YearFromStrError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let year = s.parse().map_err(|_| YearFromStrError)?;
+        Self::new(year).ok_or(YearFromStrError)
+    }
+}
+
+/// An error which can be returned when parsing a [`Year`].
+#[derive(Debug)]
+pub struct YearFromStrError;
+
+impl Error for YearFromStrError {}
+
+impl Display for YearFromStrError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("expecting a year of advent, 2015 or later")
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// Creates a [`Year`] value in a const context.
+#[macro_export]
+macro_rules! year {
+    ($year:expr) => {{
+        const _ASSERT: () = assert!(
+            $year >= 2015 && $year <= 9999,
+            concat!(
+                "invalid year `",
+                $year,
+                "`, expecting a year of advent, 2015 or later"
+            ),
+        );
+        $crate::template::Year::__new_unchecked($year)
+    }};
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::Year;
+    use crate::{day, year};
+
+    #[test]
+    fn parses_years() {
+        assert_eq!("2024".parse::<Year>().unwrap(), year!(2024));
+        assert_eq!(year!(2015).to_string(), "2015");
+        assert!("2014".parse::<Year>().is_err());
+        assert!("24".parse::<Year>().is_err());
+    }
+
+    #[test]
+    fn knows_days_per_year() {
+        assert_eq!(year!(2024).days(), 25);
+        assert_eq!(year!(2025).days(), 12);
+        assert!(year!(2025).contains(day!(12)));
+        assert!(!year!(2025).contains(day!(13)));
+        assert_eq!(year!(2024).parts(day!(24)), 2);
+        assert_eq!(year!(2024).parts(day!(25)), 1);
+        assert_eq!(year!(2025).parts(day!(12)), 1);
+    }
+}
@@ -0,0 +1,9 @@
+advent_of_code::solution!(%YEAR%, %DAY_NUMBER%);
+
+pub fn part_one(input: &str) -> Option<%ANSWER_TYPE%> {
+    None
+}
+
+pub fn part_two(input: &str) -> Option<%ANSWER_TYPE%> {
+    None
+}
@@ -0,0 +1,24 @@
+advent_of_code::solution!(%YEAR%, %DAY_NUMBER%);
+
+use std::collections::HashMap;
+
+type Graph<'a> = HashMap<&'a str, Vec<&'a str>>;
+
+fn parse(input: &str) -> Graph<'_> {
+    let mut graph = Graph::new();
+    for (a, b) in input.lines().filter_map(|line| line.split_once('-')) {
+        graph.entry(a).or_default().push(b);
+        graph.entry(b).or_default().push(a);
+    }
+    graph
+}
+
+pub fn part_one(input: &str) -> Option<%ANSWER_TYPE%> {
+    let _graph = parse(input);
+    None
+}
+
+pub fn part_two(input: &str) -> Option<%ANSWER_TYPE%> {
+    let _graph = parse(input);
+    None
+}
@@ -0,0 +1,17 @@
+advent_of_code::solution!(%YEAR%, %DAY_NUMBER%);
+
+use advent_of_code::grid::Grid;
+
+fn parse(input: &str) -> Grid<char> {
+    Grid::parse(input, |c| c)
+}
+
+pub fn part_one(input: &str) -> Option<%ANSWER_TYPE%> {
+    let _grid = parse(input);
+    None
+}
+
+pub fn part_two(input: &str) -> Option<%ANSWER_TYPE%> {
+    let _grid = parse(input);
+    None
+}
@@ -0,0 +1,16 @@
+advent_of_code::solution!(%YEAR%, %DAY_NUMBER%);
+
+#[path = "%YEAR%-%DAY%/parse.rs"]
+mod parse;
+
+use parse::{parse, Input};
+
+pub fn part_one(input: &str) -> Option<%ANSWER_TYPE%> {
+    let input: Input = parse(input);
+    None
+}
+
+pub fn part_two(input: &str) -> Option<%ANSWER_TYPE%> {
+    let input: Input = parse(input);
+    None
+}
@@ -0,0 +1,6 @@
+/// The parsed puzzle input of day %DAY_NUMBER%, shared by both parts.
+pub type Input = Vec<String>;
+
+pub fn parse(input: &str) -> Input {
+    input.lines().map(String::from).collect()
+}